@@ -0,0 +1,134 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsString,
+    io,
+    path::{Path, PathBuf},
+};
+
+/// Recursively inlines the file-based `source <path>`/`use <path>` dependencies of `entry` into
+/// a single, self-contained script.
+///
+/// Only lines that start (after leading whitespace) with `source` or `use` followed by a path
+/// ending in `.nu` are recognized; anything else (directory modules, dependencies built up in a
+/// variable) is left untouched for nushell to parse normally, since inlining those correctly
+/// would require the full module resolver rather than a text-level pass. Each dependency is
+/// inlined at most once, at the place it's first reached, so the same entry script always
+/// produces the same bundle. Two distinct files that would collide under the same file name are
+/// reported as an error rather than silently shadowing one another.
+pub fn bundle_script(entry: &Path) -> io::Result<String> {
+    let mut visited = HashSet::new();
+    let mut seen_names: HashMap<OsString, PathBuf> = HashMap::new();
+    let mut output = String::new();
+    inline_file(entry, &mut visited, &mut seen_names, &mut output)?;
+    Ok(output)
+}
+
+fn inline_file(
+    path: &Path,
+    visited: &mut HashSet<PathBuf>,
+    seen_names: &mut HashMap<OsString, PathBuf>,
+    output: &mut String,
+) -> io::Result<()> {
+    let canonical = path.canonicalize()?;
+
+    if let Some(file_name) = canonical.file_name() {
+        match seen_names.get(file_name) {
+            Some(existing) if existing != &canonical => {
+                return Err(io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!(
+                        "two different files named `{}` are both sourced/used ({} and {}); \
+                            bundling would collide",
+                        file_name.to_string_lossy(),
+                        existing.display(),
+                        canonical.display()
+                    ),
+                ));
+            }
+            Some(_) => return Ok(()),
+            None => {
+                seen_names.insert(file_name.to_os_string(), canonical.clone());
+            }
+        }
+    }
+
+    if !visited.insert(canonical.clone()) {
+        return Ok(());
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    output.push_str(&format!("# --- begin {} ---\n", path.display()));
+    for line in contents.lines() {
+        match dependency_path(line) {
+            Some(dep) => inline_file(&dir.join(dep), visited, seen_names, output)?,
+            None => {
+                output.push_str(line);
+                output.push('\n');
+            }
+        }
+    }
+    output.push_str(&format!("# --- end {} ---\n", path.display()));
+
+    Ok(())
+}
+
+/// Recognizes a bare `source <path>` or `use <path>` line and returns the path argument, if any.
+fn dependency_path(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let rest = line
+        .strip_prefix("source ")
+        .or_else(|| line.strip_prefix("use "))?;
+    let path = rest.trim().split_whitespace().next()?;
+    let path = path.trim_matches('"').trim_matches('\'');
+    path.ends_with(".nu").then_some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inlines_sourced_dependency_once() {
+        let dir = tempfile::tempdir().expect("failed to create tempdir");
+
+        let lib_path = dir.path().join("lib.nu");
+        std::fs::write(&lib_path, "def greet [] { \"hi\" }\n").unwrap();
+
+        let entry_path = dir.path().join("entry.nu");
+        std::fs::write(
+            &entry_path,
+            "source lib.nu\nsource lib.nu\ngreet\n",
+        )
+        .unwrap();
+
+        let bundled = bundle_script(&entry_path).expect("bundling should succeed");
+
+        assert_eq!(bundled.matches("def greet").count(), 1);
+        // The entry's own `greet` call is its last line, right before its end marker.
+        assert_eq!(bundled.lines().rev().nth(1), Some("greet"));
+    }
+
+    #[test]
+    fn rejects_colliding_file_names() {
+        let dir = tempfile::tempdir().expect("failed to create tempdir");
+
+        let sub_a = dir.path().join("a");
+        let sub_b = dir.path().join("b");
+        std::fs::create_dir(&sub_a).unwrap();
+        std::fs::create_dir(&sub_b).unwrap();
+        std::fs::write(sub_a.join("lib.nu"), "def foo [] {}\n").unwrap();
+        std::fs::write(sub_b.join("lib.nu"), "def bar [] {}\n").unwrap();
+
+        let entry_path = dir.path().join("entry.nu");
+        std::fs::write(
+            &entry_path,
+            "source a/lib.nu\nsource b/lib.nu\n",
+        )
+        .unwrap();
+
+        let err = bundle_script(&entry_path).expect_err("colliding names should error");
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+    }
+}
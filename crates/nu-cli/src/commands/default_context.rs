@@ -23,6 +23,13 @@ pub fn add_cli_context(mut engine_state: EngineState) -> EngineState {
             KeybindingsDefault,
             KeybindingsList,
             KeybindingsListen,
+            KeybindingsReload,
+            SessionReplay,
+        };
+
+        #[cfg(any(feature = "sqlite", feature = "sqlite-dynlib"))]
+        bind_command! {
+            HistoryQuery,
         };
 
         working_set.render()
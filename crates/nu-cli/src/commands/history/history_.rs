@@ -163,7 +163,12 @@ impl Command for History {
 }
 
 #[cfg(any(feature = "sqlite", feature = "sqlite-dynlib"))]
-fn create_history_record(idx: usize, entry: HistoryItem, long: bool, head: Span) -> Value {
+pub(super) fn create_history_record(
+    idx: usize,
+    entry: HistoryItem,
+    long: bool,
+    head: Span,
+) -> Value {
     //1. Format all the values
     //2. Create a record of either short or long columns and values
 
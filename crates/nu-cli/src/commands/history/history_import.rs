@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Utc};
 use nu_engine::command_prelude::*;
 use nu_protocol::{
     shell_error::{self, io::IoError},
@@ -30,6 +31,8 @@ impl Command for HistoryImport {
 
 If no input is provided, will import all history items from existing history in the other format: if current history is stored in sqlite, it will store it in plain text and vice versa.
 
+Given `--file` and `--format`, imports the command lines out of a bash, zsh, or fish history file instead.
+
 Note that history item IDs are ignored when importing from file."#
     }
 
@@ -42,6 +45,18 @@ Note that history item IDs are ignored when importing from file."#
                 (Type::List(Box::new(Type::String)), Type::Nothing),
                 (Type::table(), Type::Nothing),
             ])
+            .named(
+                "file",
+                SyntaxShape::Filepath,
+                "Path to a foreign shell history file to import",
+                None,
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "Format of the file given by --file: \"bash\", \"zsh\", or \"fish\"",
+                None,
+            )
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
@@ -62,13 +77,18 @@ Note that history item IDs are ignored when importing from file."#
                 description: "Append `foo` ran from `/home` to the current history",
                 result: None,
             },
+            Example {
+                example: "history import --file ~/.bash_history --format bash",
+                description: "Append all commands from a bash history file",
+                result: None,
+            },
         ]
     }
 
     fn run(
         &self,
         engine_state: &EngineState,
-        _stack: &mut Stack,
+        stack: &mut Stack,
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
@@ -84,8 +104,30 @@ Note that history item IDs are ignored when importing from file."#
         if let Some(bak_path) = backup(&current_history_path, span)? {
             println!("Backed history to {}", bak_path.display());
         }
-        match input {
-            PipelineData::Empty => {
+
+        let foreign_file: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "file")?;
+        let foreign_format: Option<Spanned<String>> =
+            call.get_flag(engine_state, stack, "format")?;
+
+        match (foreign_file, input) {
+            (Some(file), _) => {
+                let Some(format) = foreign_format else {
+                    return Err(ShellError::MissingParameter {
+                        param_name: "format".into(),
+                        span,
+                    });
+                };
+                let contents = std::fs::read_to_string(&file.item).map_err(|err| {
+                    IoError::new(err.kind(), file.span, PathBuf::from(&file.item))
+                })?;
+                let items = parse_foreign_history(&format.item, &contents, format.span)?;
+                import(
+                    new_backend(history.file_format, Some(current_history_path))?.as_mut(),
+                    items.into_iter().map(Ok),
+                )
+            }
+            (None, PipelineData::Empty) => {
                 let other_format = match history.file_format {
                     HistoryFileFormat::Sqlite => HistoryFileFormat::Plaintext,
                     HistoryFileFormat::Plaintext => HistoryFileFormat::Sqlite,
@@ -102,7 +144,7 @@ Note that history item IDs are ignored when importing from file."#
                     .map(Ok);
                 import(dst.as_mut(), items)
             }
-            _ => {
+            (None, input) => {
                 let input = input.into_iter().map(item_from_value);
                 import(
                     new_backend(history.file_format, Some(current_history_path))?.as_mut(),
@@ -115,6 +157,112 @@ Note that history item IDs are ignored when importing from file."#
     }
 }
 
+/// Parses the command lines out of a foreign shell's history file. Only `command_line` (and,
+/// where the format has it, `start_timestamp`) are populated; the rest of `HistoryItem`'s fields
+/// aren't recorded by these shells.
+fn parse_foreign_history(
+    format: &str,
+    contents: &str,
+    format_span: Span,
+) -> Result<Vec<HistoryItem>, ShellError> {
+    match format {
+        "bash" => Ok(parse_bash_history(contents)),
+        "zsh" => Ok(parse_zsh_history(contents)),
+        "fish" => Ok(parse_fish_history(contents)),
+        _ => Err(ShellError::InvalidValue {
+            valid: "\"bash\", \"zsh\", or \"fish\"".into(),
+            actual: format.into(),
+            span: format_span,
+        }),
+    }
+}
+
+fn new_history_item(
+    command_line: String,
+    start_timestamp: Option<DateTime<Utc>>,
+) -> HistoryItem {
+    HistoryItem {
+        command_line,
+        id: None,
+        start_timestamp,
+        session_id: None,
+        hostname: None,
+        cwd: None,
+        duration: None,
+        exit_status: None,
+        more_info: None,
+    }
+}
+
+/// Bash history is one command per line, save that with `HISTTIMEFORMAT` set, each command is
+/// preceded by a `#<unix timestamp>` comment line.
+fn parse_bash_history(contents: &str) -> Vec<HistoryItem> {
+    let mut items = Vec::new();
+    let mut pending_timestamp = None;
+    for line in contents.lines() {
+        if let Some(timestamp) = line.strip_prefix('#').and_then(|rest| rest.parse().ok()) {
+            pending_timestamp = DateTime::from_timestamp(timestamp, 0);
+            continue;
+        }
+        if !line.is_empty() {
+            items.push(new_history_item(
+                line.to_string(),
+                pending_timestamp.take(),
+            ));
+        }
+    }
+    items
+}
+
+/// Zsh's "extended history" format (`setopt EXTENDED_HISTORY`) prefixes each command with
+/// `: <start timestamp>:<elapsed seconds>;`. Without that option, it's one command per line, same
+/// as bash.
+fn parse_zsh_history(contents: &str) -> Vec<HistoryItem> {
+    fn extended_entry(line: &str) -> Option<(Option<i64>, &str)> {
+        let rest = line.strip_prefix(": ")?;
+        let (timestamp, command) = rest.split_once(':')?;
+        let (_elapsed, command) = command.split_once(';')?;
+        Some((timestamp.trim().parse().ok(), command))
+    }
+
+    contents
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| match extended_entry(line) {
+            Some((timestamp, command)) => new_history_item(
+                command.to_string(),
+                timestamp.and_then(|t| DateTime::from_timestamp(t, 0)),
+            ),
+            None => new_history_item(line.to_string(), None),
+        })
+        .collect()
+}
+
+/// Fish stores history as a YAML-like document, one entry per command:
+/// ```yaml
+/// - cmd: some command
+///   when: 1610000000
+/// ```
+/// with optional extra `paths:` keys we don't care about.
+fn parse_fish_history(contents: &str) -> Vec<HistoryItem> {
+    let mut items: Vec<HistoryItem> = Vec::new();
+    for line in contents.lines() {
+        if let Some(cmd) = line.strip_prefix("- cmd: ") {
+            items.push(new_history_item(unescape_fish_command(cmd), None));
+        } else if let Some(when) = line.trim_start().strip_prefix("when: ") {
+            if let (Some(item), Ok(timestamp)) = (items.last_mut(), when.trim().parse()) {
+                item.start_timestamp = DateTime::from_timestamp(timestamp, 0);
+            }
+        }
+    }
+    items
+}
+
+/// Fish escapes `\`, `\n`, and a couple other characters in stored commands.
+fn unescape_fish_command(cmd: &str) -> String {
+    cmd.replace("\\\\", "\\").replace("\\n", "\n")
+}
+
 fn new_backend(
     format: HistoryFileFormat,
     path: Option<PathBuf>,
@@ -167,7 +315,7 @@ fn import(
     Ok(())
 }
 
-fn error_from_reedline(e: ReedlineError) -> ShellError {
+pub(super) fn error_from_reedline(e: ReedlineError) -> ShellError {
     // TODO: Should we add a new ShellError variant?
     ShellError::GenericError {
         error: "Reedline error".to_owned(),
@@ -415,6 +563,56 @@ mod tests {
         Value::record(rec, span)
     }
 
+    #[test]
+    fn test_parse_bash_history() {
+        let items = parse_bash_history("ls -la\n#1610000000\ngit status\n");
+        assert_eq!(
+            items,
+            vec![
+                new_history_item("ls -la".to_string(), None),
+                new_history_item(
+                    "git status".to_string(),
+                    DateTime::from_timestamp(1610000000, 0)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_zsh_history_extended() {
+        let items = parse_zsh_history(": 1610000000:0;git status\nls -la\n");
+        assert_eq!(
+            items,
+            vec![
+                new_history_item(
+                    "git status".to_string(),
+                    DateTime::from_timestamp(1610000000, 0)
+                ),
+                new_history_item("ls -la".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_fish_history() {
+        let items = parse_fish_history(
+            "- cmd: ls -la\n  when: 1610000000\n- cmd: echo one\\ntwo\n  when: 1610000001\n",
+        );
+        assert_eq!(
+            items,
+            vec![
+                new_history_item(
+                    "ls -la".to_string(),
+                    DateTime::from_timestamp(1610000000, 0)
+                ),
+                new_history_item(
+                    "echo one\ntwo".to_string(),
+                    DateTime::from_timestamp(1610000001, 0)
+                ),
+            ]
+        );
+    }
+
     #[rstest]
     #[case::no_backup(&["history.dat"], "history.dat.bak")]
     #[case::backup_exists(&["history.dat", "history.dat.bak"], "history.dat.bak.1")]
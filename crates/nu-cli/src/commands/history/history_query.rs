@@ -0,0 +1,136 @@
+use chrono::{DateTime, FixedOffset};
+use nu_engine::command_prelude::*;
+use nu_protocol::HistoryFileFormat;
+use reedline::{History as ReedlineHistory, SearchDirection, SearchQuery, SqliteBackedHistory};
+
+use super::history_::create_history_record;
+use super::history_import::error_from_reedline;
+
+#[derive(Clone)]
+pub struct HistoryQuery;
+
+impl Command for HistoryQuery {
+    fn name(&self) -> &str {
+        "history query"
+    }
+
+    fn description(&self) -> &str {
+        "Query the command history with structured filters."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Only available when history is stored in sqlite, since the plaintext format does not record exit status, duration, cwd, or session id."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("history query")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .named(
+                "cwd-prefix",
+                SyntaxShape::String,
+                "Only show entries run from a directory starting with this prefix",
+                None,
+            )
+            .switch(
+                "failed",
+                "Only show entries with a non-zero exit status",
+                None,
+            )
+            .named(
+                "since",
+                SyntaxShape::DateTime,
+                "Only show entries run at or after this date/time",
+                None,
+            )
+            .category(Category::History)
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                example: "history query --failed",
+                description: "Show only the commands that exited with a non-zero status",
+                result: None,
+            },
+            Example {
+                example: "history query --cwd-prefix ~/projects",
+                description: "Show commands run from a directory under ~/projects",
+                result: None,
+            },
+            Example {
+                example: "history query --since (date now) - 1day",
+                description: "Show commands run in the last day",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let Some(history) = engine_state.history_config() else {
+            return Ok(PipelineData::empty());
+        };
+        let Some(history_path) = history.file_path() else {
+            return Err(ShellError::ConfigDirNotFound { span: Some(head) });
+        };
+        if !matches!(history.file_format, HistoryFileFormat::Sqlite) {
+            return Err(ShellError::GenericError {
+                error: "history query requires sqlite history".into(),
+                msg: "the plaintext history format doesn't record the fields needed to query"
+                    .into(),
+                span: Some(head),
+                help: Some("set $env.config.history.file_format to \"sqlite\"".into()),
+                inner: Vec::new(),
+            });
+        }
+
+        let cwd_prefix: Option<String> = call.get_flag(engine_state, stack, "cwd-prefix")?;
+        let failed = call.has_flag(engine_state, stack, "failed")?;
+        let since: Option<DateTime<FixedOffset>> = call
+            .get_flag::<Value>(engine_state, stack, "since")?
+            .map(|val| val.as_date())
+            .transpose()?;
+
+        let reader = SqliteBackedHistory::with_file(history_path, None, None)
+            .map_err(error_from_reedline)?;
+        let entries = reader
+            .search(SearchQuery::everything(SearchDirection::Forward, None))
+            .map_err(error_from_reedline)?;
+
+        let signals = engine_state.signals().clone();
+        let records = entries
+            .into_iter()
+            .enumerate()
+            .filter(move |(_, entry)| {
+                if failed && entry.exit_status.unwrap_or(0) == 0 {
+                    return false;
+                }
+                if let Some(prefix) = &cwd_prefix {
+                    if !entry
+                        .cwd
+                        .as_deref()
+                        .is_some_and(|cwd| cwd.starts_with(prefix.as_str()))
+                    {
+                        return false;
+                    }
+                }
+                if let Some(since) = since {
+                    if !entry.start_timestamp.is_some_and(|ts| ts >= since) {
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(move |(idx, entry)| create_history_record(idx, entry, true, head))
+            .collect::<Vec<_>>();
+
+        Ok(records.into_pipeline_data(head, signals))
+    }
+}
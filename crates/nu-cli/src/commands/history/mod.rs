@@ -1,8 +1,12 @@
 mod fields;
 mod history_;
 mod history_import;
+#[cfg(any(feature = "sqlite", feature = "sqlite-dynlib"))]
+mod history_query;
 mod history_session;
 
 pub use history_::History;
 pub use history_import::HistoryImport;
+#[cfg(any(feature = "sqlite", feature = "sqlite-dynlib"))]
+pub use history_query::HistoryQuery;
 pub use history_session::HistorySession;
@@ -1,7 +1,8 @@
 use nu_engine::command_prelude::*;
 use reedline::{
-    get_reedline_edit_commands, get_reedline_keybinding_modifiers, get_reedline_keycodes,
-    get_reedline_prompt_edit_modes, get_reedline_reedline_events,
+    get_reedline_default_keybindings, get_reedline_edit_commands,
+    get_reedline_keybinding_modifiers, get_reedline_keycodes, get_reedline_prompt_edit_modes,
+    get_reedline_reedline_events,
 };
 
 #[derive(Clone)]
@@ -20,6 +21,11 @@ impl Command for KeybindingsList {
             .switch("modes", "list of edit modes", Some('o'))
             .switch("events", "list of reedline event", Some('e'))
             .switch("edits", "list of edit commands", Some('d'))
+            .switch(
+                "resolved",
+                "list the effective keybindings: reedline's defaults plus the overrides from $env.config.keybindings",
+                None,
+            )
             .category(Category::Platform)
     }
 
@@ -44,6 +50,11 @@ impl Command for KeybindingsList {
                 example: "keybindings list",
                 result: None,
             },
+            Example {
+                description: "See the effective keybindings, defaults and config overrides together",
+                example: "keybindings list --resolved",
+                result: None,
+            },
         ]
     }
 
@@ -54,6 +65,13 @@ impl Command for KeybindingsList {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        if call.has_flag(engine_state, stack, "resolved")? {
+            return Ok(
+                Value::list(get_resolved_records(engine_state, call.head), call.head)
+                    .into_pipeline_data(),
+            );
+        }
+
         let all_options = ["modifiers", "keycodes", "edits", "modes", "events"];
 
         let presence = all_options
@@ -74,6 +92,45 @@ impl Command for KeybindingsList {
     }
 }
 
+// Builds the effective keybinding table: reedline's built-in defaults, plus every keybinding
+// from $env.config.keybindings tagged with its own row. This doesn't attempt to resolve which
+// config entries shadow which default (that would require re-deriving reedline's internal
+// key-modifier/keycode representation), so a key with a config override shows up twice, once as
+// "default" and once as "config" — the config row is what reedline actually uses.
+fn get_resolved_records(engine_state: &EngineState, span: Span) -> Vec<Value> {
+    let config = engine_state.get_config();
+
+    let defaults = get_reedline_default_keybindings().into_iter().map(
+        |(mode, modifier, code, event)| {
+            Value::record(
+                record! {
+                    "mode" => Value::string(mode, span),
+                    "modifier" => Value::string(modifier, span),
+                    "code" => Value::string(code, span),
+                    "event" => Value::string(event, span),
+                    "source" => Value::string("default", span),
+                },
+                span,
+            )
+        },
+    );
+
+    let overrides = config.keybindings.iter().map(|keybinding| {
+        Value::record(
+            record! {
+                "mode" => keybinding.mode.clone(),
+                "modifier" => keybinding.modifier.clone(),
+                "code" => keybinding.keycode.clone(),
+                "event" => keybinding.event.clone(),
+                "source" => Value::string("config", span),
+            },
+            span,
+        )
+    });
+
+    defaults.chain(overrides).collect()
+}
+
 fn get_records(entry_type: &str, span: Span) -> Vec<Value> {
     let values = match entry_type {
         "modifiers" => get_reedline_keybinding_modifiers().sorted(),
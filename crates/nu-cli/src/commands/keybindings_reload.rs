@@ -0,0 +1,47 @@
+use crate::reedline_config::create_keybindings;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct KeybindingsReload;
+
+impl Command for KeybindingsReload {
+    fn name(&self) -> &str {
+        "keybindings reload"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .category(Category::Platform)
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+    }
+
+    fn description(&self) -> &str {
+        "Validate $env.config.keybindings so changes take effect on the next prompt."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Nushell already rebuilds its keybindings from $env.config.keybindings before every \
+prompt, so config changes are picked up without restarting the shell. This command exists to \
+check that the current $env.config.keybindings parses correctly, surfacing errors immediately \
+instead of waiting for the next prompt draw."
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Check that the configured keybindings are valid",
+            example: "keybindings reload",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        create_keybindings(engine_state.get_config())?;
+        Ok(PipelineData::empty())
+    }
+}
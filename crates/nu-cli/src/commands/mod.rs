@@ -5,12 +5,18 @@ mod keybindings;
 mod keybindings_default;
 mod keybindings_list;
 mod keybindings_listen;
+mod keybindings_reload;
+mod session_replay;
 
 pub use commandline::{Commandline, CommandlineEdit, CommandlineGetCursor, CommandlineSetCursor};
 pub use history::{History, HistoryImport, HistorySession};
+#[cfg(any(feature = "sqlite", feature = "sqlite-dynlib"))]
+pub use history::HistoryQuery;
 pub use keybindings::Keybindings;
 pub use keybindings_default::KeybindingsDefault;
 pub use keybindings_list::KeybindingsList;
 pub use keybindings_listen::KeybindingsListen;
+pub use keybindings_reload::KeybindingsReload;
+pub use session_replay::SessionReplay;
 
 pub use default_context::add_cli_context;
@@ -0,0 +1,115 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::shell_error::io::IoError;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct SessionReplay;
+
+impl Command for SessionReplay {
+    fn name(&self) -> &str {
+        "session replay"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "path",
+                SyntaxShape::Filepath,
+                "path to a session file recorded with `nu --record`",
+            )
+            .switch(
+                "no-delay",
+                "run the recorded commands back-to-back instead of waiting between them to match \
+                    the original timing",
+                None,
+            )
+            .category(Category::History)
+    }
+
+    fn description(&self) -> &str {
+        "Replay a session recorded with `nu --record`."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each recorded command is run as its own `nu -c` invocation, in the order and (unless \
+            --no-delay is given) with roughly the timing it was originally recorded with. This \
+            reproduces the commands, not the exact rendered terminal output or the interactive \
+            session's variable state, since `--record` doesn't capture either of those."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["record", "history", "demo"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let path: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let no_delay = call.has_flag(engine_state, stack, "no-delay")?;
+
+        let contents = std::fs::read_to_string(&path.item).map_err(|err| {
+            IoError::new(err.kind(), path.span, std::path::PathBuf::from(&path.item))
+        })?;
+
+        let current_exe = std::env::current_exe().map_err(|err| {
+            IoError::new_internal(
+                err.kind(),
+                "could not determine the current nu executable",
+                nu_protocol::location!(),
+            )
+        })?;
+
+        let signals = engine_state.signals();
+        let mut previous_offset_ms: u128 = 0;
+
+        for line in contents.lines() {
+            if signals.interrupted() {
+                break;
+            }
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let Ok(entry) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            let Some(command) = entry.get("command").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let offset_ms = entry
+                .get("offset_ms")
+                .and_then(|v| v.as_u64())
+                .map(u128::from)
+                .unwrap_or(previous_offset_ms);
+
+            if !no_delay {
+                let wait_ms = offset_ms.saturating_sub(previous_offset_ms);
+                std::thread::sleep(Duration::from_millis(wait_ms.min(u64::MAX as u128) as u64));
+            }
+            previous_offset_ms = offset_ms;
+
+            println!("> {command}");
+            let _ = std::process::Command::new(&current_exe)
+                .arg("-c")
+                .arg(command)
+                .status();
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Replay a previously recorded session",
+            example: "session replay session.json",
+            result: None,
+        }]
+    }
+}
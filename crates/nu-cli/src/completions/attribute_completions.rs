@@ -42,6 +42,7 @@ impl Completer for AttributeCompletion {
                     append_whitespace: false,
                 },
                 kind: Some(SuggestionKind::Command(ty, Some(decl_id))),
+                match_indices: None,
             });
         }
 
@@ -79,6 +80,7 @@ impl Completer for AttributableCompletion {
                     append_whitespace: false,
                 },
                 kind: Some(SuggestionKind::Command(cmd.command_type(), None)),
+                match_indices: None,
             });
         }
 
@@ -23,6 +23,10 @@ pub trait Completer {
 pub struct SemanticSuggestion {
     pub suggestion: Suggestion,
     pub kind: Option<SuggestionKind>,
+    /// Character (not byte) indices into `suggestion.value`, with any surrounding quotes
+    /// trimmed, that matched the fuzzy search pattern, used to highlight the matched characters
+    /// in the completion menu.
+    pub match_indices: Option<Vec<usize>>,
 }
 
 // TODO: think about name: maybe suggestion context?
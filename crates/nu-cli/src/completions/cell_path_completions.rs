@@ -127,6 +127,7 @@ fn get_suggestions_by_value(
                 ..Suggestion::default()
             },
             kind: Some(SuggestionKind::CellPath),
+            match_indices: None,
         }
     };
     match value {
@@ -79,6 +79,7 @@ impl CommandCompletion {
                                             CommandType::External,
                                             None,
                                         )),
+                                        match_indices: None,
                                     },
                                 );
                             }
@@ -128,6 +129,7 @@ impl Completer for CommandCompletion {
                             ..Suggestion::default()
                         },
                         kind: Some(SuggestionKind::Command(typ, Some(decl_id))),
+                        match_indices: None,
                     },
                 );
             }
@@ -440,9 +440,19 @@ impl NuCompleter {
                                 }
                             }
                         }
-                        // resort to external completer set in config
+                        // resort to external completer set in config, preferring a
+                        // per-command override (`completions.external.completers`) over
+                        // the global fallback (`completions.external.completer`)
                         let config = self.engine_state.get_config();
-                        if let Some(closure) = config.completions.external.completer.as_ref() {
+                        let command_name =
+                            String::from_utf8_lossy(working_set.get_span_contents(head.span));
+                        let closure = config
+                            .completions
+                            .external
+                            .completers
+                            .get(command_name.as_ref())
+                            .or(config.completions.external.completer.as_ref());
+                        if let Some(closure) = closure {
                             let mut text_spans: Vec<String> =
                                 flatten_expression(working_set, element_expression)
                                     .iter()
@@ -646,6 +656,7 @@ impl NuCompleter {
             case_sensitive: config.completions.case_sensitive,
             match_algorithm: config.completions.algorithm.into(),
             sort: config.completions.sort,
+            preview: config.completions.preview,
         };
 
         completer.fetch(
@@ -742,6 +753,7 @@ pub fn map_value_completions<'a>(
                     ..Suggestion::default()
                 },
                 kind: Some(SuggestionKind::Value(x.get_type())),
+                match_indices: None,
             });
         }
 
@@ -790,6 +802,7 @@ pub fn map_value_completions<'a>(
             return Some(SemanticSuggestion {
                 suggestion,
                 kind: Some(SuggestionKind::Value(value_type)),
+                match_indices: None,
             });
         }
 
@@ -217,8 +217,22 @@ impl<T> NuMatcher<'_, T> {
 }
 
 impl NuMatcher<'_, SemanticSuggestion> {
-    pub fn add_semantic_suggestion(&mut self, sugg: SemanticSuggestion) -> bool {
+    pub fn add_semantic_suggestion(&mut self, mut sugg: SemanticSuggestion) -> bool {
         let value = sugg.suggestion.value.to_string();
+
+        // Record which characters of the value matched, so the completion menu can highlight
+        // them (most useful for fuzzy matches, where they aren't just a contiguous prefix).
+        if let State::Fuzzy { matcher, atom, .. } = &mut self.state {
+            let mut haystack_buf = Vec::new();
+            let haystack_utf32 = Utf32Str::new(trim_quotes_str(&value), &mut haystack_buf);
+            let mut indices = Vec::new();
+            if atom.indices(haystack_utf32, matcher, &mut indices).is_some() {
+                indices.sort_unstable();
+                indices.dedup();
+                sugg.match_indices = Some(indices.into_iter().map(|i| i as usize).collect());
+            }
+        }
+
         self.add(value, sugg)
     }
 }
@@ -266,6 +280,9 @@ pub struct CompletionOptions {
     pub case_sensitive: bool,
     pub match_algorithm: MatchAlgorithm,
     pub sort: CompletionSort,
+    /// Whether file/directory suggestions should include a content preview in their
+    /// description. See `$env.config.completions.preview`.
+    pub preview: bool,
 }
 
 impl Default for CompletionOptions {
@@ -274,6 +291,7 @@ impl Default for CompletionOptions {
             case_sensitive: true,
             match_algorithm: MatchAlgorithm::Prefix,
             sort: Default::default(),
+            preview: false,
         }
     }
 }
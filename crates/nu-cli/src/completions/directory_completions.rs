@@ -1,5 +1,6 @@
 use crate::completions::{
     completion_common::{adjust_if_intermediate, complete_item, AdjustView},
+    file_completions::preview_path,
     Completer, CompletionOptions,
 };
 use nu_protocol::{
@@ -26,28 +27,39 @@ impl Completer for DirectoryCompletion {
         let AdjustView { prefix, span, .. } =
             adjust_if_intermediate(prefix.as_ref(), working_set, span);
 
+        let cwd = working_set.permanent_state.current_work_dir();
+
         // Filter only the folders
         #[allow(deprecated)]
         let items: Vec<_> = directory_completion(
             span,
             &prefix,
-            &working_set.permanent_state.current_work_dir(),
+            &cwd,
             options,
             working_set.permanent_state,
             stack,
         )
         .into_iter()
-        .map(move |x| SemanticSuggestion {
-            suggestion: Suggestion {
-                value: x.path,
-                style: x.style,
-                span: reedline::Span {
-                    start: x.span.start - offset,
-                    end: x.span.end - offset,
+        .map(move |x| {
+            let description = if options.preview {
+                preview_path(Path::new(&cwd), &x.path, true)
+            } else {
+                None
+            };
+            SemanticSuggestion {
+                suggestion: Suggestion {
+                    value: x.path,
+                    description,
+                    style: x.style,
+                    span: reedline::Span {
+                        start: x.span.start - offset,
+                        end: x.span.end - offset,
+                    },
+                    ..Suggestion::default()
                 },
-                ..Suggestion::default()
-            },
-            kind: Some(SuggestionKind::Directory),
+                kind: Some(SuggestionKind::Directory),
+                match_indices: None,
+            }
         })
         .collect();
 
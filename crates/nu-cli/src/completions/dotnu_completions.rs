@@ -201,6 +201,7 @@ impl Completer for DotNuCompletion {
                         ..Suggestion::default()
                     },
                     kind: Some(SuggestionKind::Module),
+                    match_indices: None,
                 }
             })
             .collect::<Vec<_>>()
@@ -55,6 +55,7 @@ impl Completer for ExportableCompletion<'_> {
                     ..Suggestion::default()
                 },
                 kind: Some(kind),
+                match_indices: None,
             });
         };
 
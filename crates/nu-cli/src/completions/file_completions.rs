@@ -29,32 +29,43 @@ impl Completer for FileCompletion {
             readjusted,
         } = adjust_if_intermediate(prefix.as_ref(), working_set, span);
 
+        let cwd = working_set.permanent_state.current_work_dir();
+
         #[allow(deprecated)]
         let items: Vec<_> = complete_item(
             readjusted,
             span,
             &prefix,
-            &[&working_set.permanent_state.current_work_dir()],
+            &[&cwd],
             options,
             working_set.permanent_state,
             stack,
         )
         .into_iter()
-        .map(move |x| SemanticSuggestion {
-            suggestion: Suggestion {
-                value: x.path,
-                style: x.style,
-                span: reedline::Span {
-                    start: x.span.start - offset,
-                    end: x.span.end - offset,
-                },
-                ..Suggestion::default()
-            },
-            kind: Some(if x.is_dir {
-                SuggestionKind::Directory
+        .map(move |x| {
+            let description = if options.preview {
+                preview_path(Path::new(&cwd), &x.path, x.is_dir)
             } else {
-                SuggestionKind::File
-            }),
+                None
+            };
+            SemanticSuggestion {
+                suggestion: Suggestion {
+                    value: x.path,
+                    description,
+                    style: x.style,
+                    span: reedline::Span {
+                        start: x.span.start - offset,
+                        end: x.span.end - offset,
+                    },
+                    ..Suggestion::default()
+                },
+                kind: Some(if x.is_dir {
+                    SuggestionKind::Directory
+                } else {
+                    SuggestionKind::File
+                }),
+                match_indices: None,
+            }
         })
         .collect();
 
@@ -85,6 +96,40 @@ impl Completer for FileCompletion {
     }
 }
 
+const PREVIEW_MAX_LINES: usize = 10;
+const PREVIEW_MAX_BYTES: usize = 4096;
+
+/// Builds a short preview of a completed path: a directory listing for directories, or the
+/// first few lines for (likely-text) files. Best-effort — returns `None` if the path can't be
+/// read or doesn't look like text.
+pub(super) fn preview_path(cwd: &Path, path: &str, is_dir: bool) -> Option<String> {
+    let trimmed = nu_parser::trim_quotes_str(path);
+    let full_path = if Path::new(trimmed).is_absolute() {
+        Path::new(trimmed).to_path_buf()
+    } else {
+        cwd.join(trimmed)
+    };
+
+    if is_dir {
+        let mut entries: Vec<String> = std::fs::read_dir(&full_path)
+            .ok()?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        entries.sort();
+        Some(entries.join("\n"))
+    } else {
+        let bytes = std::fs::read(&full_path).ok()?;
+        let text = std::str::from_utf8(&bytes[..bytes.len().min(PREVIEW_MAX_BYTES)]).ok()?;
+        Some(
+            text.lines()
+                .take(PREVIEW_MAX_LINES)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+    }
+}
+
 pub fn file_path_completion(
     span: nu_protocol::Span,
     partial: &str,
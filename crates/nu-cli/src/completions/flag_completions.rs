@@ -36,6 +36,7 @@ impl Completer for FlagCompletion {
                     ..Suggestion::default()
                 },
                 kind: Some(SuggestionKind::Flag),
+                match_indices: None,
             });
         };
 
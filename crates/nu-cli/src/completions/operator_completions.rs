@@ -270,6 +270,7 @@ impl Completer for OperatorCompletion<'_> {
                     ..Suggestion::default()
                 },
                 kind: Some(SuggestionKind::Operator),
+                match_indices: None,
             });
         }
         matcher.results()
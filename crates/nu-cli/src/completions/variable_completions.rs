@@ -36,6 +36,7 @@ impl Completer for VariableCompletion {
                     ..Suggestion::default()
                 },
                 kind: Some(SuggestionKind::Variable),
+                match_indices: None,
             });
         }
 
@@ -48,6 +49,7 @@ impl Completer for VariableCompletion {
                     ..Suggestion::default()
                 },
                 kind: Some(SuggestionKind::Variable),
+                match_indices: None,
             })
         };
 
@@ -59,7 +59,14 @@ pub fn evaluate_commands(
 
         let mut working_set = StateWorkingSet::new(engine_state);
 
-        let output = parse(&mut working_set, None, commands.item.as_bytes(), false);
+        // Name the source so error reports point back at "-c" rather than the generic
+        // "source" name `parse` falls back to for anonymous input.
+        let output = parse(
+            &mut working_set,
+            Some("<commandline>"),
+            commands.item.as_bytes(),
+            false,
+        );
         if let Some(warning) = working_set.parse_warnings.first() {
             report_parse_warning(&working_set, warning);
         }
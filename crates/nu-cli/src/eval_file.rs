@@ -141,6 +141,13 @@ pub fn evaluate_file(
             true,
         )
     } else {
+        if !args.is_empty() {
+            eprintln!(
+                "warning: {file_path_str} has no `main` command, so the arguments passed to it \
+                    ({}) are ignored",
+                args.join(" ")
+            );
+        }
         eval_source(engine_state, stack, &file, file_path_str, input, true)
     };
 
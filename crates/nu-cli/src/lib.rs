@@ -1,4 +1,5 @@
 #![doc = include_str!("../README.md")]
+mod bundle;
 mod commands;
 mod completions;
 mod config_files;
@@ -11,10 +12,12 @@ mod prompt;
 mod prompt_update;
 mod reedline_config;
 mod repl;
+mod session_record;
 mod syntax_highlight;
 mod util;
 mod validation;
 
+pub use bundle::bundle_script;
 pub use commands::add_cli_context;
 pub use completions::{FileCompletion, NuCompleter, SemanticSuggestion, SuggestionKind};
 pub use config_files::eval_config_contents;
@@ -14,7 +14,16 @@ impl Command for NuHighlight {
     fn signature(&self) -> Signature {
         Signature::build("nu-highlight")
             .category(Category::Strings)
-            .input_output_types(vec![(Type::String, Type::String)])
+            .switch(
+                "json",
+                "Emit the token spans making up the line as structured data, instead of \
+                    ANSI-styled text. Useful for editor integrations.",
+                None,
+            )
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::String, Type::table()),
+            ])
     }
 
     fn description(&self) -> &str {
@@ -33,6 +42,7 @@ impl Command for NuHighlight {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
+        let json = call.has_flag(engine_state, stack, "json")?;
 
         let signals = engine_state.signals();
 
@@ -41,24 +51,58 @@ impl Command for NuHighlight {
             stack: Arc::new(stack.clone()),
         };
 
-        input.map(
-            move |x| match x.coerce_into_string() {
-                Ok(line) => {
-                    let highlights = highlighter.highlight(&line, line.len());
-                    Value::string(highlights.render_simple(), head)
-                }
-                Err(err) => Value::error(err, head),
-            },
-            signals,
-        )
+        if json {
+            input.map(
+                move |x| match x.coerce_into_string() {
+                    Ok(line) => {
+                        let spans = highlighter.highlight_spans(&line);
+                        let records = spans
+                            .into_iter()
+                            .map(|(span, shape)| {
+                                Value::record(
+                                    record! {
+                                        "start" => Value::int(span.start as i64, head),
+                                        "end" => Value::int(span.end as i64, head),
+                                        "text" => Value::string(line[span.start..span.end].to_string(), head),
+                                        "shape" => Value::string(shape.as_str(), head),
+                                    },
+                                    head,
+                                )
+                            })
+                            .collect();
+                        Value::list(records, head)
+                    }
+                    Err(err) => Value::error(err, head),
+                },
+                signals,
+            )
+        } else {
+            input.map(
+                move |x| match x.coerce_into_string() {
+                    Ok(line) => {
+                        let highlights = highlighter.highlight(&line, line.len());
+                        Value::string(highlights.render_simple(), head)
+                    }
+                    Err(err) => Value::error(err, head),
+                },
+                signals,
+            )
+        }
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Describe the type of a string",
-            example: "'let x = 3' | nu-highlight",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Describe the type of a string",
+                example: "'let x = 3' | nu-highlight",
+                result: None,
+            },
+            Example {
+                description: "Get the token spans of a string as structured data",
+                example: "'let x = 3' | nu-highlight --json",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -8,7 +8,26 @@ use reedline::{
     DefaultPrompt, Prompt, PromptEditMode, PromptHistorySearch, PromptHistorySearchStatus,
     PromptViMode,
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// The outcome of a `PROMPT_COMMAND`-style closure that's being evaluated on a background
+/// thread because it ran past `$env.config.prompt.async_timeout`.
+#[derive(Clone)]
+pub(crate) enum PromptSegmentState {
+    /// The closure is still running; keep showing `async_placeholder`.
+    Pending,
+    /// The closure finished; this is the string to show on the next prompt draw.
+    Ready(Option<String>),
+}
+
+/// Shared between the REPL's prompt-drawing code and whatever background threads are
+/// currently evaluating slow prompt segments, keyed by the segment's environment variable
+/// name (e.g. `PROMPT_COMMAND`).
+pub(crate) type PromptAsyncCache = Arc<Mutex<HashMap<&'static str, PromptSegmentState>>>;
 
 /// Nushell prompt definition
 #[derive(Clone)]
@@ -24,6 +43,7 @@ pub struct NushellPrompt {
     render_right_prompt_on_last_line: bool,
     engine_state: EngineState,
     stack: Stack,
+    async_cache: PromptAsyncCache,
 }
 
 impl NushellPrompt {
@@ -45,9 +65,16 @@ impl NushellPrompt {
             render_right_prompt_on_last_line: false,
             engine_state,
             stack,
+            async_cache: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Cache used to hand off the eventual result of a prompt segment's closure once it
+    /// finishes running past `$env.config.prompt.async_timeout`.
+    pub(crate) fn async_cache(&self) -> &PromptAsyncCache {
+        &self.async_cache
+    }
+
     pub fn update_prompt_left(&mut self, prompt_string: Option<String>) {
         self.left_prompt_string = prompt_string;
     }
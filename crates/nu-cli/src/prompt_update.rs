@@ -1,4 +1,7 @@
-use crate::NushellPrompt;
+use crate::{
+    prompt::{PromptAsyncCache, PromptSegmentState},
+    NushellPrompt,
+};
 use log::{trace, warn};
 use nu_engine::ClosureEvalOnce;
 use nu_protocol::{
@@ -6,6 +9,7 @@ use nu_protocol::{
     report_shell_error, Config, PipelineData, Value,
 };
 use reedline::Prompt;
+use std::{sync::mpsc, thread, time::Duration};
 
 // Name of environment variable where the prompt could be stored
 pub(crate) const PROMPT_COMMAND: &str = "PROMPT_COMMAND";
@@ -49,7 +53,7 @@ pub(crate) const VSCODE_CWD_PROPERTY_MARKER_SUFFIX: &str = "\x1b\\";
 
 pub(crate) const RESET_APPLICATION_MODE: &str = "\x1b[?1l";
 
-fn get_prompt_string(
+fn compute_prompt_string(
     prompt: &str,
     config: &Config,
     engine_state: &EngineState,
@@ -96,14 +100,87 @@ fn get_prompt_string(
         })
 }
 
+/// Resolves a prompt segment, honoring `$env.config.prompt.async_timeout`.
+///
+/// When `async_timeout` is `0` (the default), this just calls [`compute_prompt_string`]
+/// synchronously, same as always. Otherwise, the closure is evaluated on a background thread;
+/// if it hasn't finished by the timeout, `async_placeholder` is shown instead and the eventual
+/// result is stashed in `async_cache` so the *next* prompt draw can pick it up.
+fn get_prompt_string(
+    prompt: &'static str,
+    config: &Config,
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    async_cache: &PromptAsyncCache,
+) -> Option<String> {
+    if config.prompt.async_timeout <= 0 {
+        return compute_prompt_string(prompt, config, engine_state, stack);
+    }
+
+    {
+        let mut cache = async_cache.lock().expect("prompt async cache poisoned");
+        match cache.remove(prompt) {
+            Some(PromptSegmentState::Ready(result)) => return result,
+            Some(PromptSegmentState::Pending) => {
+                // Still running from a previous prompt draw; don't spawn a second thread for
+                // the same segment, just keep showing the placeholder.
+                cache.insert(prompt, PromptSegmentState::Pending);
+                return Some(config.prompt.async_placeholder.clone());
+            }
+            None => cache.insert(prompt, PromptSegmentState::Pending),
+        };
+    }
+
+    let job_state = engine_state.clone();
+    let job_stack = stack.clone();
+    let job_config = config.clone();
+    let cache = async_cache.clone();
+    let (tx, rx) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(format!("prompt segment: {prompt}"))
+        .spawn(move || {
+            let mut stack = job_stack;
+            let result = compute_prompt_string(prompt, &job_config, &job_state, &mut stack);
+            cache
+                .lock()
+                .expect("prompt async cache poisoned")
+                .insert(prompt, PromptSegmentState::Ready(result.clone()));
+            let _ = tx.send(result);
+        });
+
+    if spawned.is_err() {
+        async_cache
+            .lock()
+            .expect("prompt async cache poisoned")
+            .remove(prompt);
+        return compute_prompt_string(prompt, config, engine_state, stack);
+    }
+
+    match rx.recv_timeout(Duration::from_nanos(config.prompt.async_timeout as u64)) {
+        // The background thread already inserted this same result into the cache; drop it
+        // since we're delivering it now rather than on some future prompt draw.
+        Ok(result) => {
+            async_cache
+                .lock()
+                .expect("prompt async cache poisoned")
+                .remove(prompt);
+            result
+        }
+        Err(_) => Some(config.prompt.async_placeholder.clone()),
+    }
+}
+
 pub(crate) fn update_prompt(
     config: &Config,
     engine_state: &EngineState,
     stack: &mut Stack,
     nu_prompt: &mut NushellPrompt,
 ) {
+    let async_cache = nu_prompt.async_cache().clone();
+
     let configured_left_prompt_string =
-        match get_prompt_string(PROMPT_COMMAND, config, engine_state, stack) {
+        match get_prompt_string(PROMPT_COMMAND, config, engine_state, stack, &async_cache) {
             Some(s) => s,
             None => "".to_string(),
         };
@@ -136,18 +213,40 @@ pub(crate) fn update_prompt(
         configured_left_prompt_string.into()
     };
 
-    let right_prompt_string = get_prompt_string(PROMPT_COMMAND_RIGHT, config, engine_state, stack);
+    let right_prompt_string = get_prompt_string(
+        PROMPT_COMMAND_RIGHT,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    );
 
-    let prompt_indicator_string = get_prompt_string(PROMPT_INDICATOR, config, engine_state, stack);
+    let prompt_indicator_string =
+        get_prompt_string(PROMPT_INDICATOR, config, engine_state, stack, &async_cache);
 
-    let prompt_multiline_string =
-        get_prompt_string(PROMPT_MULTILINE_INDICATOR, config, engine_state, stack);
+    let prompt_multiline_string = get_prompt_string(
+        PROMPT_MULTILINE_INDICATOR,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    );
 
-    let prompt_vi_insert_string =
-        get_prompt_string(PROMPT_INDICATOR_VI_INSERT, config, engine_state, stack);
+    let prompt_vi_insert_string = get_prompt_string(
+        PROMPT_INDICATOR_VI_INSERT,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    );
 
-    let prompt_vi_normal_string =
-        get_prompt_string(PROMPT_INDICATOR_VI_NORMAL, config, engine_state, stack);
+    let prompt_vi_normal_string = get_prompt_string(
+        PROMPT_INDICATOR_VI_NORMAL,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    );
 
     // apply the other indicators
     nu_prompt.update_all_prompt_strings(
@@ -169,17 +268,35 @@ pub(crate) fn make_transient_prompt(
     nu_prompt: &NushellPrompt,
 ) -> Box<dyn Prompt> {
     let mut nu_prompt = nu_prompt.clone();
+    let async_cache = nu_prompt.async_cache().clone();
 
-    if let Some(s) = get_prompt_string(TRANSIENT_PROMPT_COMMAND, config, engine_state, stack) {
+    if let Some(s) = get_prompt_string(
+        TRANSIENT_PROMPT_COMMAND,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    ) {
         nu_prompt.update_prompt_left(Some(s))
     }
 
-    if let Some(s) = get_prompt_string(TRANSIENT_PROMPT_COMMAND_RIGHT, config, engine_state, stack)
-    {
+    if let Some(s) = get_prompt_string(
+        TRANSIENT_PROMPT_COMMAND_RIGHT,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    ) {
         nu_prompt.update_prompt_right(Some(s), config.render_right_prompt_on_last_line)
     }
 
-    if let Some(s) = get_prompt_string(TRANSIENT_PROMPT_INDICATOR, config, engine_state, stack) {
+    if let Some(s) = get_prompt_string(
+        TRANSIENT_PROMPT_INDICATOR,
+        config,
+        engine_state,
+        stack,
+        &async_cache,
+    ) {
         nu_prompt.update_prompt_indicator(Some(s))
     }
     if let Some(s) = get_prompt_string(
@@ -187,6 +304,7 @@ pub(crate) fn make_transient_prompt(
         config,
         engine_state,
         stack,
+        &async_cache,
     ) {
         nu_prompt.update_prompt_vi_insert(Some(s))
     }
@@ -195,6 +313,7 @@ pub(crate) fn make_transient_prompt(
         config,
         engine_state,
         stack,
+        &async_cache,
     ) {
         nu_prompt.update_prompt_vi_normal(Some(s))
     }
@@ -204,6 +323,7 @@ pub(crate) fn make_transient_prompt(
         config,
         engine_state,
         stack,
+        &async_cache,
     ) {
         nu_prompt.update_prompt_multiline(Some(s))
     }
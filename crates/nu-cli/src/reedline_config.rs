@@ -693,6 +693,12 @@ pub enum KeybindingsMode {
     },
 }
 
+/// Builds the emacs/vi keybindings used by the line editor from `config.keybindings`.
+///
+/// This only maps key chords to `ReedlineEvent`s and `EditCommand`s; it can't add vi-style
+/// operator+motion grammars (text objects like `ciw`/`da"`, named registers, `.` repeat) since
+/// those would need to be parsed and tracked by Reedline's vi engine itself, not composed from
+/// single-chord bindings here.
 pub(crate) fn create_keybindings(config: &Config) -> Result<KeybindingsMode, ShellError> {
     let parsed_keybindings = &config.keybindings;
 
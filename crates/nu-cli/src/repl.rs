@@ -10,6 +10,7 @@ use crate::{
     nu_highlight::NoOpHighlighter,
     prompt_update,
     reedline_config::{add_menus, create_keybindings, KeybindingsMode},
+    session_record::SessionRecorder,
     util::eval_source,
     NuHighlighter, NuValidator, NushellPrompt,
 };
@@ -26,8 +27,8 @@ use nu_protocol::shell_error::io::IoError;
 use nu_protocol::{
     config::NuCursorShape,
     engine::{EngineState, Stack, StateWorkingSet},
-    report_shell_error, HistoryConfig, HistoryFileFormat, PipelineData, ShellError, Span, Spanned,
-    Value,
+    record, report_shell_error, HistoryConfig, HistoryFileFormat, PipelineData, ShellError, Span,
+    Spanned, Value,
 };
 use nu_utils::{
     filesystem::{have_permission, PermissionResult},
@@ -58,7 +59,17 @@ pub fn evaluate_repl(
     prerun_command: Option<Spanned<String>>,
     load_std_lib: Option<Spanned<String>>,
     entire_start_time: Instant,
+    record_session: Option<Spanned<String>>,
 ) -> Result<()> {
+    let session_recorder = record_session.and_then(|path| {
+        match SessionRecorder::create(Path::new(&path.item), entire_start_time) {
+            Ok(recorder) => Some(recorder),
+            Err(err) => {
+                eprintln!("Error opening session recording file {}: {err}", path.item);
+                None
+            }
+        }
+    });
     // throughout this code, we hold this stack uniquely.
     // During the main REPL loop, we hand ownership of this value to an Arc,
     // so that it may be read by various reedline plugins. During this, we
@@ -196,6 +207,7 @@ pub fn evaluate_repl(
                 use_color,
                 entry_num: &mut entry_num,
                 hostname: hostname.as_deref(),
+                session_recorder: session_recorder.as_ref(),
             });
 
             // pass the most recent version of the line_editor back
@@ -292,6 +304,7 @@ struct LoopContext<'a> {
     use_color: bool,
     entry_num: &'a mut usize,
     hostname: Option<&'a str>,
+    session_recorder: Option<&'a SessionRecorder>,
 }
 
 /// Perform one iteration of the REPL loop
@@ -311,6 +324,7 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
         use_color,
         entry_num,
         hostname,
+        session_recorder,
     } = ctx;
 
     let mut start_time = std::time::Instant::now();
@@ -634,11 +648,45 @@ fn loop_iteration(ctx: LoopContext) -> (bool, Stack, Reedline) {
             }
             let cmd_duration = cmd_execution_start_time.elapsed();
 
+            if let Some(recorder) = session_recorder {
+                recorder.record(&repl_cmd_line_text, cmd_duration);
+            }
+
             stack.add_env_var(
                 "CMD_DURATION_MS".into(),
                 Value::string(format!("{}", cmd_duration.as_millis()), Span::unknown()),
             );
 
+            // Right after a command finishes, fire the "command_done" hook with a record
+            // describing how it went, for telemetry/slow-command-warning type use cases.
+            {
+                let exit_code = stack
+                    .get_env_var(engine_state, "LAST_EXIT_CODE")
+                    .and_then(|v| v.as_int().ok())
+                    .unwrap_or(0);
+
+                let info = Value::record(
+                    record! {
+                        "duration" => Value::duration(
+                            cmd_duration.as_nanos().try_into().unwrap_or(i64::MAX),
+                            Span::unknown(),
+                        ),
+                        "exit_code" => Value::int(exit_code, Span::unknown()),
+                    },
+                    Span::unknown(),
+                );
+
+                if let Err(err) = hook::eval_hooks(
+                    engine_state,
+                    &mut stack,
+                    vec![("$info".into(), info)],
+                    &engine_state.get_config().hooks.command_done.clone(),
+                    "command_done",
+                ) {
+                    report_shell_error(engine_state, &err);
+                }
+            }
+
             if history_supports_meta {
                 if let Err(e) = fill_in_result_related_history_metadata(
                     &repl_cmd_line_text,
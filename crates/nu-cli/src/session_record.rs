@@ -0,0 +1,40 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Appends a JSON-lines transcript of REPL commands to a file, for `nu --record`.
+///
+/// Each line records the command text plus when it started (relative to the start of the
+/// session) and how long it took, so a session can be reproduced command-by-command with
+/// `session replay`. It does not capture rendered output or terminal state; see `session replay`
+/// for what replaying such a file actually does.
+pub(crate) struct SessionRecorder {
+    file: Mutex<File>,
+    start: Instant,
+}
+
+impl SessionRecorder {
+    pub(crate) fn create(path: &Path, start: Instant) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+            start,
+        })
+    }
+
+    pub(crate) fn record(&self, command: &str, duration: Duration) {
+        let offset_ms = self.start.elapsed().saturating_sub(duration).as_millis();
+        let entry = serde_json::json!({
+            "command": command,
+            "offset_ms": offset_ms,
+            "duration_ms": duration.as_millis(),
+        });
+
+        let mut file = self.file.lock().expect("session recorder mutex poisoned");
+        let _ = writeln!(file, "{entry}");
+    }
+}
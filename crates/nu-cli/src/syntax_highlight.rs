@@ -6,7 +6,7 @@ use nu_parser::{flatten_block, parse, FlatShape};
 use nu_protocol::{
     ast::{Block, Expr, Expression, PipelineRedirection, RecordItem},
     engine::{EngineState, Stack, StateWorkingSet},
-    Span,
+    Config, Span,
 };
 use reedline::{Highlighter, StyledText};
 use std::sync::Arc;
@@ -16,44 +16,96 @@ pub struct NuHighlighter {
     pub stack: Arc<Stack>,
 }
 
-impl Highlighter for NuHighlighter {
-    fn highlight(&self, line: &str, _cursor: usize) -> StyledText {
-        trace!("highlighting: {}", line);
-
-        let config = self.stack.get_config(&self.engine_state);
-        let highlight_resolved_externals = config.highlight_resolved_externals;
-        let mut working_set = StateWorkingSet::new(&self.engine_state);
-        let block = parse(&mut working_set, None, line.as_bytes(), false);
-        let (shapes, global_span_offset) = {
-            let mut shapes = flatten_block(&working_set, &block);
-            // Highlighting externals has a config point because of concerns that using which to resolve
-            // externals may slow down things too much.
-            if highlight_resolved_externals {
+impl NuHighlighter {
+    /// Parses `line` and returns its flattened shapes, resolving `FlatShape::External` and
+    /// `FlatShape::Filepath`/`FlatShape::Directory` into their "not found"/"resolved" variants
+    /// according to `config.highlight_resolved_externals`/`highlight_resolved_filepaths`, the
+    /// same way [`Highlighter::highlight`] does.
+    fn flatten_shapes<'ws>(
+        &self,
+        working_set: &mut StateWorkingSet<'ws>,
+        line: &str,
+        config: &Config,
+    ) -> (Arc<Block>, Vec<(Span, FlatShape)>, usize) {
+        let block = parse(working_set, None, line.as_bytes(), false);
+        let mut shapes = flatten_block(working_set, &block);
+        // Highlighting externals has a config point because of concerns that using which to resolve
+        // externals may slow down things too much.
+        if config.highlight_resolved_externals {
+            for (span, shape) in shapes.iter_mut() {
+                if *shape == FlatShape::External {
+                    let str_contents = working_set.get_span_contents(Span::new(span.start, span.end));
+
+                    let str_word = String::from_utf8_lossy(str_contents).to_string();
+                    let paths = env::path_str(&self.engine_state, &self.stack, *span).ok();
+                    #[allow(deprecated)]
+                    let res = if let Ok(cwd) = env::current_dir_str(&self.engine_state, &self.stack)
+                    {
+                        which::which_in(str_word, paths.as_ref(), cwd).ok()
+                    } else {
+                        which::which_in_global(str_word, paths.as_ref())
+                            .ok()
+                            .and_then(|mut i| i.next())
+                    };
+                    if res.is_some() {
+                        *shape = FlatShape::ExternalResolved;
+                    }
+                }
+            }
+        }
+        // Same idea as `highlight_resolved_externals`, but checking filepaths/directories
+        // against the filesystem instead of resolving externals on PATH.
+        if config.highlight_resolved_filepaths {
+            let cwd = self.engine_state.cwd(Some(&self.stack)).ok();
+            if let Some(cwd) = cwd {
                 for (span, shape) in shapes.iter_mut() {
-                    if *shape == FlatShape::External {
+                    if matches!(shape, FlatShape::Filepath | FlatShape::Directory) {
                         let str_contents =
                             working_set.get_span_contents(Span::new(span.start, span.end));
-
                         let str_word = String::from_utf8_lossy(str_contents).to_string();
-                        let paths = env::path_str(&self.engine_state, &self.stack, *span).ok();
-                        #[allow(deprecated)]
-                        let res = if let Ok(cwd) =
-                            env::current_dir_str(&self.engine_state, &self.stack)
-                        {
-                            which::which_in(str_word, paths.as_ref(), cwd).ok()
-                        } else {
-                            which::which_in_global(str_word, paths.as_ref())
-                                .ok()
-                                .and_then(|mut i| i.next())
-                        };
-                        if res.is_some() {
-                            *shape = FlatShape::ExternalResolved;
+                        let str_word = nu_parser::trim_quotes_str(&str_word);
+                        if !cwd.join(str_word).exists() {
+                            *shape = match shape {
+                                FlatShape::Filepath => FlatShape::FilepathNotFound,
+                                FlatShape::Directory => FlatShape::DirectoryNotFound,
+                                _ => unreachable!(),
+                            };
                         }
                     }
                 }
             }
-            (shapes, self.engine_state.next_span_start())
-        };
+        }
+        (block, shapes, self.engine_state.next_span_start())
+    }
+
+    /// Parses `line` and returns its tokens as `(span, shape)` pairs, with spans relative to
+    /// `line` rather than the engine's global span space. Used by `nu-highlight --json` to expose
+    /// token boundaries to editor integrations without the ANSI styling `highlight` produces.
+    pub fn highlight_spans(&self, line: &str) -> Vec<(Span, FlatShape)> {
+        let config = self.stack.get_config(&self.engine_state);
+        let mut working_set = StateWorkingSet::new(&self.engine_state);
+        let (_, shapes, global_span_offset) = self.flatten_shapes(&mut working_set, line, &config);
+        shapes
+            .into_iter()
+            .filter(|(span, _)| span.start >= global_span_offset && span.end >= global_span_offset)
+            .map(|(span, shape)| {
+                (
+                    Span::new(span.start - global_span_offset, span.end - global_span_offset),
+                    shape,
+                )
+            })
+            .collect()
+    }
+}
+
+impl Highlighter for NuHighlighter {
+    fn highlight(&self, line: &str, _cursor: usize) -> StyledText {
+        trace!("highlighting: {}", line);
+
+        let config = self.stack.get_config(&self.engine_state);
+        let mut working_set = StateWorkingSet::new(&self.engine_state);
+        let (block, shapes, global_span_offset) =
+            self.flatten_shapes(&mut working_set, line, &config);
 
         let mut output = StyledText::default();
         let mut last_seen_span = global_span_offset;
@@ -136,7 +188,9 @@ impl Highlighter for NuHighlighter {
                 }
 
                 FlatShape::Filepath => add_colored_token(&shape.1, next_token),
+                FlatShape::FilepathNotFound => add_colored_token(&shape.1, next_token),
                 FlatShape::Directory => add_colored_token(&shape.1, next_token),
+                FlatShape::DirectoryNotFound => add_colored_token(&shape.1, next_token),
                 FlatShape::GlobInterpolation => add_colored_token(&shape.1, next_token),
                 FlatShape::GlobPattern => add_colored_token(&shape.1, next_token),
                 FlatShape::Variable(_) | FlatShape::VarDecl(_) => {
@@ -62,6 +62,8 @@ fn gather_env_vars(
         fake_env_file.push('\n');
     }
 
+    let mut initial_env_vars = std::collections::HashMap::new();
+
     let mut fake_env_file = String::new();
     // Write all the env vars into a fake file
     for (name, val) in vars {
@@ -198,9 +200,12 @@ fn gather_env_vars(
             };
 
             // stack.add_env_var(name, value);
-            engine_state.add_env_var(name, value);
+            engine_state.add_env_var(name.clone(), value.clone());
+            initial_env_vars.insert(name, value);
         }
     }
+
+    engine_state.initial_env_vars = std::sync::Arc::new(initial_env_vars);
 }
 
 /// Print a pipeline with formatting applied based on display_output hook.
@@ -5,3 +5,11 @@ fn nu_highlight_not_expr() {
     let actual = nu!("'not false' | nu-highlight | ansi strip");
     assert_eq!(actual.out, "not false");
 }
+
+#[test]
+fn nu_highlight_json_spans() {
+    let actual = nu!(
+        "'let x = 3' | nu-highlight --json | where shape == shape_int | get text.0"
+    );
+    assert_eq!(actual.out, "3");
+}
@@ -28,6 +28,9 @@ enum NumberBytes {
     Four,
     Eight,
     Auto,
+    /// A word size other than 1, 2, 4, or 8 bytes. Only meaningful for binary values,
+    /// since a fixed-width `int` cannot be widened past 8 bytes.
+    Other(usize),
 }
 
 #[derive(Clone, Copy)]
@@ -63,7 +66,7 @@ impl InputNumType {
 
 fn get_number_bytes(
     number_bytes: Option<Spanned<usize>>,
-    head: Span,
+    _head: Span,
 ) -> Result<NumberBytes, ShellError> {
     match number_bytes {
         None => Ok(NumberBytes::Auto),
@@ -71,12 +74,55 @@ fn get_number_bytes(
         Some(Spanned { item: 2, .. }) => Ok(NumberBytes::Two),
         Some(Spanned { item: 4, .. }) => Ok(NumberBytes::Four),
         Some(Spanned { item: 8, .. }) => Ok(NumberBytes::Eight),
-        Some(Spanned { span, .. }) => Err(ShellError::UnsupportedInput {
-            msg: "Only 1, 2, 4, or 8 bytes are supported as word sizes".to_string(),
-            input: "value originates from here".to_string(),
-            msg_span: head,
-            input_span: span,
-        }),
+        // Any other explicit width is only meaningful for binary values, where it is
+        // used to pad or truncate the buffer to that many bytes. Int input rejects it
+        // in `int_number_bytes` below, since i64 cannot represent a wider word.
+        Some(Spanned { item, .. }) => Ok(NumberBytes::Other(item)),
+    }
+}
+
+/// Validates a [`NumberBytes`] for use with `int` input, where the word size must fit in
+/// an `i64`. Returns an error pointing at the `--number-bytes` value when it doesn't.
+fn int_number_bytes(number_size: NumberBytes, head: Span) -> Result<NumberBytes, ShellError> {
+    if let NumberBytes::Other(n) = number_size {
+        return Err(ShellError::IncorrectValue {
+            msg: format!(
+                "Only 1, 2, 4, or 8 bytes are supported as word sizes for int input, got {n}. \
+                 Wider word sizes are only supported for binary input."
+            ),
+            val_span: head,
+            call_span: head,
+        });
+    }
+    Ok(number_size)
+}
+
+/// Returns the explicit word size in bytes that `number_size` requests, or `None` for
+/// `Auto`, meaning the binary value's own length should be used as-is.
+fn binary_word_size(number_size: NumberBytes) -> Option<usize> {
+    match number_size {
+        NumberBytes::One => Some(1),
+        NumberBytes::Two => Some(2),
+        NumberBytes::Four => Some(4),
+        NumberBytes::Eight => Some(8),
+        NumberBytes::Other(n) => Some(n),
+        NumberBytes::Auto => None,
+    }
+}
+
+/// Pads the front of `data` with zeroes or truncates it from the front so that it is
+/// exactly `size` bytes long, treating `data` as a big-endian buffer whose most
+/// significant byte comes first. Used to apply an explicit `--number-bytes` word size to
+/// binary input in the `bits` rotate/shift commands.
+fn resize_binary_to_word(data: &[u8], size: usize) -> Vec<u8> {
+    match data.len().cmp(&size) {
+        std::cmp::Ordering::Equal => data.to_vec(),
+        std::cmp::Ordering::Less => {
+            let mut resized = vec![0; size - data.len()];
+            resized.extend_from_slice(data);
+            resized
+        }
+        std::cmp::Ordering::Greater => data[data.len() - size..].to_vec(),
     }
 }
 
@@ -87,6 +133,9 @@ fn get_input_num_type(val: i64, signed: bool, number_size: NumberBytes) -> Input
             NumberBytes::Two => InputNumType::SignedTwo,
             NumberBytes::Four => InputNumType::SignedFour,
             NumberBytes::Eight => InputNumType::SignedEight,
+            NumberBytes::Other(_) => {
+                unreachable!("callers must reject NumberBytes::Other via int_number_bytes first")
+            }
             NumberBytes::Auto => {
                 if val <= 0x7F && val >= -(2i64.pow(7)) {
                     InputNumType::SignedOne
@@ -105,6 +154,9 @@ fn get_input_num_type(val: i64, signed: bool, number_size: NumberBytes) -> Input
             NumberBytes::Two => InputNumType::Two,
             NumberBytes::Four => InputNumType::Four,
             NumberBytes::Eight => InputNumType::Eight,
+            NumberBytes::Other(_) => {
+                unreachable!("callers must reject NumberBytes::Other via int_number_bytes first")
+            }
             NumberBytes::Auto => {
                 if val <= 0xFF {
                     InputNumType::One
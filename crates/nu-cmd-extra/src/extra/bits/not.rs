@@ -1,4 +1,4 @@
-use super::{get_number_bytes, NumberBytes};
+use super::{get_number_bytes, int_number_bytes, NumberBytes};
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
 
@@ -145,6 +145,10 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             if signed || val < 0 {
                 Value::int(!val, span)
             } else {
+                let number_size = match int_number_bytes(number_size, span) {
+                    Ok(number_size) => number_size,
+                    Err(err) => return Value::error(err, span),
+                };
                 use NumberBytes::*;
                 let out_val = match number_size {
                     One => !val & 0x00_00_00_00_00_FF,
@@ -162,6 +166,9 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
                             !val & 0x7F_FF_FF_FF_FF_FF
                         }
                     }
+                    Other(_) => {
+                        unreachable!("int_number_bytes rejects NumberBytes::Other above")
+                    }
                 };
                 Value::int(out_val, span)
             }
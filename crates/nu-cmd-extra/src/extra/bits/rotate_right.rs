@@ -1,4 +1,7 @@
-use super::{get_input_num_type, get_number_bytes, InputNumType, NumberBytes};
+use super::{
+    binary_word_size, get_input_num_type, get_number_bytes, int_number_bytes,
+    resize_binary_to_word, InputNumType, NumberBytes,
+};
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
 
@@ -46,7 +49,9 @@ impl Command for BitsRor {
             .named(
                 "number-bytes",
                 SyntaxShape::Int,
-                "the word size in number of bytes, it can be 1, 2, 4, 8, auto, default value `8`",
+                "the word size in number of bytes; for int input it can be 1, 2, 4, or 8 \
+                 (when omitted, the smallest size that fits the value); for binary input any width is accepted and the value is padded \
+                 or truncated to that many bytes before rotating",
                 Some('n'),
             )
             .category(Category::Bits)
@@ -112,6 +117,11 @@ impl Command for BitsRor {
                 example: "0x[ff bb 03] | bits ror 10",
                 result: Some(Value::binary(vec![0xc0, 0xff, 0xee], Span::test_data())),
             },
+            Example {
+                description: "rotate right binary data as an explicit 4-byte word, wider than the input",
+                example: "0x[ff] | bits ror 4 --number-bytes 4",
+                result: Some(Value::binary(vec![0xf0, 0x00, 0x00, 0x0f], Span::test_data())),
+            },
         ]
     }
 }
@@ -130,6 +140,10 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             use InputNumType::*;
             let val = *val;
             let bits = bits as u32;
+            let number_size = match int_number_bytes(number_size, span) {
+                Ok(number_size) => number_size,
+                Err(err) => return Value::error(err, span),
+            };
             let input_num_type = get_input_num_type(val, signed, number_size);
 
             if bits > input_num_type.num_bits() {
@@ -175,6 +189,14 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             Value::int(int, span)
         }
         Value::Binary { val, .. } => {
+            let resized;
+            let val: &[u8] = match binary_word_size(number_size) {
+                Some(size) => {
+                    resized = resize_binary_to_word(val, size);
+                    &resized
+                }
+                None => val,
+            };
             let len = val.len();
             if bits > len * 8 {
                 return Value::error(
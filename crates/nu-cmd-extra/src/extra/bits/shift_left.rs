@@ -1,4 +1,7 @@
-use super::{get_input_num_type, get_number_bytes, InputNumType, NumberBytes};
+use super::{
+    binary_word_size, get_input_num_type, get_number_bytes, int_number_bytes,
+    resize_binary_to_word, InputNumType, NumberBytes,
+};
 use itertools::Itertools;
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
@@ -49,7 +52,9 @@ impl Command for BitsShl {
             .named(
                 "number-bytes",
                 SyntaxShape::Int,
-                "the word size in number of bytes, it can be 1, 2, 4, 8, auto, default value `8`",
+                "the word size in number of bytes; for int input it can be 1, 2, 4, or 8 \
+                 (when omitted, the smallest size that fits the value); for binary input any width is accepted and the value is padded \
+                 or truncated to that many bytes before shifting",
                 Some('n'),
             )
             .category(Category::Bits)
@@ -123,6 +128,11 @@ impl Command for BitsShl {
                 example: "0x[4f f4] | bits shl 4",
                 result: Some(Value::binary(vec![0xff, 0x40], Span::test_data())),
             },
+            Example {
+                description: "Shift left binary data as an explicit 4-byte word, wider than the input",
+                example: "0x[ff] | bits shl 4 --number-bytes 4",
+                result: Some(Value::binary(vec![0x00, 0x00, 0x0f, 0xf0], Span::test_data())),
+            },
         ]
     }
 }
@@ -141,6 +151,10 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             use InputNumType::*;
             let val = *val;
             let bits = bits as u32;
+            let number_size = match int_number_bytes(number_size, span) {
+                Ok(number_size) => number_size,
+                Err(err) => return Value::error(err, span),
+            };
 
             let input_num_type = get_input_num_type(val, signed, number_size);
             if !input_num_type.is_permitted_bit_shift(bits) {
@@ -188,6 +202,14 @@ fn action(input: &Value, args: &Arguments, span: Span) -> Value {
             Value::int(int, span)
         }
         Value::Binary { val, .. } => {
+            let resized;
+            let val: &[u8] = match binary_word_size(number_size) {
+                Some(size) => {
+                    resized = resize_binary_to_word(val, size);
+                    &resized
+                }
+                None => val,
+            };
             let byte_shift = bits / 8;
             let bit_shift = bits % 8;
 
@@ -0,0 +1,134 @@
+use super::parse_cidr;
+use nu_engine::command_prelude::*;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const MAX_HOSTS: u64 = 65536;
+
+#[derive(Clone)]
+pub struct CidrHosts;
+
+impl Command for CidrHosts {
+    fn name(&self) -> &str {
+        "cidr hosts"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cidr hosts")
+            .input_output_types(vec![(Type::Nothing, Type::list(Type::String))])
+            .required(
+                "cidr",
+                SyntaxShape::String,
+                "the CIDR block to enumerate, e.g. `10.0.0.0/29`",
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "List the usable host addresses in a CIDR block."
+    }
+
+    fn extra_description(&self) -> &str {
+        "For ipv4, the network and broadcast addresses are excluded unless the prefix length is \
+         31 or 32, matching RFC 3021. For ipv6, every address in the block is included, since \
+         there's no broadcast address to exclude. Blocks larger than 65,536 addresses are \
+         rejected rather than enumerated."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ip", "cidr", "subnet", "hosts", "network"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cidr: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cidr = parse_cidr(&cidr.item, cidr.span)?;
+
+        let hosts = hosts(&cidr, head)?;
+
+        Ok(Value::list(
+            hosts
+                .into_iter()
+                .map(|addr| Value::string(addr.to_string(), head))
+                .collect(),
+            head,
+        )
+        .into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "List the usable hosts in a small ipv4 block",
+                example: "cidr hosts '192.168.1.0/29'",
+                result: Some(Value::test_list(
+                    ["1", "2", "3", "4", "5", "6"]
+                        .into_iter()
+                        .map(|last| Value::test_string(format!("192.168.1.{last}")))
+                        .collect(),
+                )),
+            },
+            Example {
+                description: "A point-to-point /31 includes both addresses",
+                example: "cidr hosts '10.0.0.0/31'",
+                result: Some(Value::test_list(vec![
+                    Value::test_string("10.0.0.0"),
+                    Value::test_string("10.0.0.1"),
+                ])),
+            },
+        ]
+    }
+}
+
+fn hosts(cidr: &super::Cidr, head: Span) -> Result<Vec<IpAddr>, ShellError> {
+    let additional_bits = cidr.max_prefix_len() - cidr.prefix_len;
+    if additional_bits > 16 {
+        return Err(ShellError::IncorrectValue {
+            msg: format!(
+                "/{} would enumerate more than {MAX_HOSTS} addresses; use a smaller block",
+                cidr.prefix_len
+            ),
+            val_span: head,
+            call_span: head,
+        });
+    }
+
+    Ok(match cidr.network {
+        IpAddr::V4(network) => {
+            let total = 1u32 << additional_bits;
+            let base = network.to_bits();
+            let (start, end) = if cidr.prefix_len >= 31 {
+                (0, total)
+            } else {
+                (1, total - 1)
+            };
+            (start..end)
+                .map(|offset| IpAddr::V4(Ipv4Addr::from_bits(base + offset)))
+                .collect()
+        }
+        IpAddr::V6(network) => {
+            let total = 1u128 << additional_bits;
+            let base = network.to_bits();
+            (0..total)
+                .map(|offset| IpAddr::V6(Ipv6Addr::from_bits(base + offset)))
+                .collect()
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(CidrHosts {})
+    }
+}
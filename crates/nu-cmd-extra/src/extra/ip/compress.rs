@@ -0,0 +1,88 @@
+use nu_engine::command_prelude::*;
+use std::net::Ipv6Addr;
+
+#[derive(Clone)]
+pub struct IpCompress;
+
+impl Command for IpCompress {
+    fn name(&self) -> &str {
+        "ip compress"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ip compress")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Compress an ipv6 address into its shortest canonical form."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ip", "ipv6", "compress", "network"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(move |value| action(value, head), engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Compress a fully expanded ipv6 address",
+            example: "'0000:0000:0000:0000:0000:0000:0000:0001' | ip compress",
+            result: Some(Value::test_string("::1")),
+        }]
+    }
+}
+
+fn action(value: Value, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => match val.trim().parse::<Ipv6Addr>() {
+            Ok(addr) => Value::string(addr.to_string(), span),
+            Err(err) => Value::error(
+                ShellError::IncorrectValue {
+                    msg: format!("'{}' is not a valid ipv6 address: {err}", val.trim()),
+                    val_span: span,
+                    call_span: head,
+                },
+                span,
+            ),
+        },
+        Value::Error { .. } => value,
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IpCompress {})
+    }
+}
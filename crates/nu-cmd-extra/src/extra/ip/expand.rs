@@ -0,0 +1,98 @@
+use nu_engine::command_prelude::*;
+use std::net::Ipv6Addr;
+
+#[derive(Clone)]
+pub struct IpExpand;
+
+impl Command for IpExpand {
+    fn name(&self) -> &str {
+        "ip expand"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ip expand")
+            .input_output_types(vec![(Type::String, Type::String)])
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Expand an ipv6 address into its full, uncompressed form."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ip", "ipv6", "expand", "network"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(move |value| action(value, head), engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Expand a compressed ipv6 address",
+            example: "'::1' | ip expand",
+            result: Some(Value::test_string(
+                "0000:0000:0000:0000:0000:0000:0000:0001",
+            )),
+        }]
+    }
+}
+
+fn action(value: Value, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => match val.trim().parse::<Ipv6Addr>() {
+            Ok(addr) => Value::string(expand(addr), span),
+            Err(err) => Value::error(
+                ShellError::IncorrectValue {
+                    msg: format!("'{}' is not a valid ipv6 address: {err}", val.trim()),
+                    val_span: span,
+                    call_span: head,
+                },
+                span,
+            ),
+        },
+        Value::Error { .. } => value,
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            span,
+        ),
+    }
+}
+
+fn expand(addr: Ipv6Addr) -> String {
+    addr.segments()
+        .iter()
+        .map(|segment| format!("{segment:04x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IpExpand {})
+    }
+}
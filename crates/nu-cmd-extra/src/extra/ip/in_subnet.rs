@@ -0,0 +1,102 @@
+use super::{parse_cidr, parse_ip, Cidr};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct IpInSubnet;
+
+impl Command for IpInSubnet {
+    fn name(&self) -> &str {
+        "ip in-subnet"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("ip in-subnet")
+            .input_output_types(vec![(Type::String, Type::Bool)])
+            .required(
+                "cidr",
+                SyntaxShape::String,
+                "the CIDR block to test against, e.g. `10.0.0.0/24`",
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Check whether an ip address falls within a CIDR block."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Always returns false when the address and the CIDR block are different ip versions."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ip", "cidr", "subnet", "network"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cidr: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let cidr = parse_cidr(&cidr.item, cidr.span)?;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(
+            move |value| action(value, &cidr, head),
+            engine_state.signals(),
+        )
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "An address inside the subnet",
+                example: "'192.168.1.42' | ip in-subnet '192.168.1.0/24'",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "An address outside the subnet",
+                example: "'192.168.2.1' | ip in-subnet '192.168.1.0/24'",
+                result: Some(Value::test_bool(false)),
+            },
+        ]
+    }
+}
+
+fn action(value: Value, cidr: &Cidr, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => match parse_ip(val, span) {
+            Ok(addr) => Value::bool(cidr.contains(addr), span),
+            Err(err) => Value::error(err, span),
+        },
+        Value::Error { .. } => value,
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IpInSubnet {})
+    }
+}
@@ -0,0 +1,106 @@
+use super::parse_ip;
+use nu_engine::command_prelude::*;
+use std::net::IpAddr;
+
+#[derive(Clone)]
+pub struct IntoIp;
+
+impl Command for IntoIp {
+    fn name(&self) -> &str {
+        "into ip"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into ip")
+            .input_output_types(vec![(Type::String, Type::record())])
+            .allow_variants_without_examples(true)
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Parse a string into a structured ip address record."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["ip", "ipv4", "ipv6", "address", "network"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(move |value| action(value, head), engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Parse an ipv4 address",
+                example: "'192.168.1.1' | into ip",
+                result: Some(Value::test_record(record! {
+                    "version" => Value::test_int(4),
+                    "address" => Value::test_string("192.168.1.1"),
+                })),
+            },
+            Example {
+                description: "Parse an ipv6 address",
+                example: "'2001:db8::1' | into ip",
+                result: Some(Value::test_record(record! {
+                    "version" => Value::test_int(6),
+                    "address" => Value::test_string("2001:db8::1"),
+                })),
+            },
+        ]
+    }
+}
+
+fn ip_to_record(addr: IpAddr, span: Span) -> Value {
+    Value::record(
+        record! {
+            "version" => Value::int(if addr.is_ipv4() { 4 } else { 6 }, span),
+            "address" => Value::string(addr.to_string(), span),
+        },
+        span,
+    )
+}
+
+fn action(value: Value, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => match parse_ip(val, span) {
+            Ok(addr) => ip_to_record(addr, span),
+            Err(err) => Value::error(err, span),
+        },
+        Value::Error { .. } => value,
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IntoIp {})
+    }
+}
@@ -0,0 +1,104 @@
+mod cidr_hosts;
+mod compress;
+mod expand;
+mod in_subnet;
+mod into_ip;
+mod ip_;
+
+pub use cidr_hosts::CidrHosts;
+pub use compress::IpCompress;
+pub use expand::IpExpand;
+pub use in_subnet::IpInSubnet;
+pub use into_ip::IntoIp;
+pub use ip_::Ip;
+
+use nu_protocol::{ShellError, Span};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub(super) fn parse_ip(s: &str, span: Span) -> Result<IpAddr, ShellError> {
+    let s = s.trim();
+    s.parse::<IpAddr>().map_err(|err| ShellError::IncorrectValue {
+        msg: format!("'{s}' is not a valid ip address: {err}"),
+        val_span: span,
+        call_span: span,
+    })
+}
+
+pub(super) struct Cidr {
+    pub(super) network: IpAddr,
+    pub(super) prefix_len: u8,
+}
+
+impl Cidr {
+    pub(super) fn max_prefix_len(&self) -> u8 {
+        match self.network {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        }
+    }
+
+    pub(super) fn contains(&self, addr: IpAddr) -> bool {
+        match (self.network, addr) {
+            (IpAddr::V4(net), IpAddr::V4(addr)) => {
+                v4_mask(self.prefix_len) & addr.to_bits() == net.to_bits()
+            }
+            (IpAddr::V6(net), IpAddr::V6(addr)) => {
+                v6_mask(self.prefix_len) & addr.to_bits() == net.to_bits()
+            }
+            _ => false,
+        }
+    }
+}
+
+fn v4_mask(prefix_len: u8) -> u32 {
+    if prefix_len == 0 {
+        0
+    } else {
+        u32::MAX << (32 - prefix_len)
+    }
+}
+
+fn v6_mask(prefix_len: u8) -> u128 {
+    if prefix_len == 0 {
+        0
+    } else {
+        u128::MAX << (128 - prefix_len)
+    }
+}
+
+pub(super) fn parse_cidr(s: &str, span: Span) -> Result<Cidr, ShellError> {
+    let s = s.trim();
+    let (addr, prefix) = s.split_once('/').ok_or_else(|| ShellError::IncorrectValue {
+        msg: format!("'{s}' is not a valid CIDR block; expected `<address>/<prefix-length>`"),
+        val_span: span,
+        call_span: span,
+    })?;
+
+    let addr = parse_ip(addr, span)?;
+    let max_prefix_len = match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+
+    let prefix_len: u8 = prefix
+        .parse()
+        .ok()
+        .filter(|prefix_len| *prefix_len <= max_prefix_len)
+        .ok_or_else(|| ShellError::IncorrectValue {
+            msg: format!(
+                "'{prefix}' is not a valid prefix length for {addr} (expected 0-{max_prefix_len})"
+            ),
+            val_span: span,
+            call_span: span,
+        })?;
+
+    let network = match addr {
+        IpAddr::V4(addr) => IpAddr::V4(Ipv4Addr::from_bits(v4_mask(prefix_len) & addr.to_bits())),
+        IpAddr::V6(addr) => IpAddr::V6(Ipv6Addr::from_bits(v6_mask(prefix_len) & addr.to_bits())),
+    };
+
+    Ok(Cidr {
+        network,
+        prefix_len,
+    })
+}
@@ -0,0 +1,102 @@
+mod decode;
+mod sign;
+
+pub use decode::JwtDecode;
+pub use sign::JwtSign;
+
+use data_encoding::BASE64URL_NOPAD;
+use nu_protocol::{ShellError, Span, Value};
+
+pub(super) fn base64url_decode(segment: &str, span: Span) -> Result<Vec<u8>, ShellError> {
+    BASE64URL_NOPAD
+        .decode(segment.as_bytes())
+        .map_err(|err| ShellError::IncorrectValue {
+            msg: format!("invalid base64url segment: {err}"),
+            val_span: span,
+            call_span: span,
+        })
+}
+
+pub(super) fn base64url_encode(data: &[u8]) -> String {
+    BASE64URL_NOPAD.encode(data)
+}
+
+pub(super) fn parse_json_segment(bytes: &[u8], span: Span) -> Result<Value, ShellError> {
+    let text = std::str::from_utf8(bytes).map_err(|err| ShellError::IncorrectValue {
+        msg: format!("token segment is not valid utf-8: {err}"),
+        val_span: span,
+        call_span: span,
+    })?;
+
+    let json = nu_json::from_str(text).map_err(|err| ShellError::IncorrectValue {
+        msg: format!("token segment is not valid json: {err}"),
+        val_span: span,
+        call_span: span,
+    })?;
+
+    Ok(nujson_to_value(json, span))
+}
+
+fn nujson_to_value(value: nu_json::Value, span: Span) -> Value {
+    match value {
+        nu_json::Value::Array(array) => Value::list(
+            array.into_iter().map(|x| nujson_to_value(x, span)).collect(),
+            span,
+        ),
+        nu_json::Value::Bool(b) => Value::bool(b, span),
+        nu_json::Value::F64(f) => Value::float(f, span),
+        nu_json::Value::I64(i) => Value::int(i, span),
+        nu_json::Value::Null => Value::nothing(span),
+        nu_json::Value::Object(k) => Value::record(
+            k.into_iter()
+                .map(|(k, v)| (k, nujson_to_value(v, span)))
+                .collect(),
+            span,
+        ),
+        nu_json::Value::U64(u) => {
+            if u > i64::MAX as u64 {
+                Value::error(
+                    ShellError::CantConvert {
+                        to_type: "i64 sized integer".into(),
+                        from_type: "value larger than i64".into(),
+                        span,
+                        help: None,
+                    },
+                    span,
+                )
+            } else {
+                Value::int(u as i64, span)
+            }
+        }
+        nu_json::Value::String(s) => Value::string(s, span),
+    }
+}
+
+pub(super) fn value_to_nujson(value: &Value) -> Result<nu_json::Value, ShellError> {
+    let span = value.span();
+    Ok(match value {
+        Value::Bool { val, .. } => nu_json::Value::Bool(*val),
+        Value::Int { val, .. } => nu_json::Value::I64(*val),
+        Value::Float { val, .. } => nu_json::Value::F64(*val),
+        Value::String { val, .. } => nu_json::Value::String(val.clone()),
+        Value::Nothing { .. } => nu_json::Value::Null,
+        Value::List { vals, .. } => {
+            nu_json::Value::Array(vals.iter().map(value_to_nujson).collect::<Result<_, _>>()?)
+        }
+        Value::Record { val, .. } => {
+            let mut object = nu_json::Map::new();
+            for (k, v) in val.iter() {
+                object.insert(k.clone(), value_to_nujson(v)?);
+            }
+            nu_json::Value::Object(object)
+        }
+        other => {
+            return Err(ShellError::UnsupportedInput {
+                msg: "JWT claims must be built from records, lists, and simple values".into(),
+                input: "value originates from here".into(),
+                msg_span: span,
+                input_span: other.span(),
+            })
+        }
+    })
+}
@@ -1,15 +1,23 @@
 mod bits;
 mod filters;
 mod formats;
+mod ip;
+mod jwt;
 mod math;
 mod platform;
+mod semver;
 mod strings;
+mod unit;
 
 pub use bits::{Bits, BitsAnd, BitsNot, BitsOr, BitsRol, BitsRor, BitsShl, BitsShr, BitsXor};
 pub use formats::ToHtml;
+pub use ip::{CidrHosts, IntoIp, Ip, IpCompress, IpExpand, IpInSubnet};
+pub use jwt::{JwtDecode, JwtSign};
 pub use math::{MathArcCos, MathArcCosH, MathArcSin, MathArcSinH, MathArcTan, MathArcTanH};
 pub use math::{MathCos, MathCosH, MathSin, MathSinH, MathTan, MathTanH};
 pub use math::{MathExp, MathLn};
+pub use semver::{IntoSemVer, SemVerCompare, SemVerMatch};
+pub use unit::UnitConvert;
 
 use nu_protocol::engine::{EngineState, StateWorkingSet};
 
@@ -49,7 +57,9 @@ pub fn add_extra_command_context(mut engine_state: EngineState) -> EngineState {
             strings::str_::case::StrPascalCase,
             strings::str_::case::StrScreamingSnakeCase,
             strings::str_::case::StrSnakeCase,
-            strings::str_::case::StrTitleCase
+            strings::str_::case::StrTitleCase,
+            strings::str_::normalize::StrNormalize,
+            strings::str_::transliterate::StrTransliterate
         );
 
         bind_command!(ToHtml, formats::FromUrl);
@@ -85,6 +95,14 @@ pub fn add_extra_command_context(mut engine_state: EngineState) -> EngineState {
             MathLn
         };
 
+        bind_command!(UnitConvert);
+
+        bind_command!(IntoSemVer, SemVerCompare, SemVerMatch);
+
+        bind_command!(JwtDecode, JwtSign);
+
+        bind_command!(Ip, IntoIp, IpInSubnet, IpExpand, IpCompress, CidrHosts);
+
         working_set.render()
     };
 
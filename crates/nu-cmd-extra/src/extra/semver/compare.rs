@@ -0,0 +1,121 @@
+use super::parse_version;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SemVerCompare;
+
+impl Command for SemVerCompare {
+    fn name(&self) -> &str {
+        "semver compare"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("semver compare")
+            .input_output_types(vec![(Type::String, Type::Int)])
+            .required(
+                "other",
+                SyntaxShape::String,
+                "the semantic version to compare against",
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Compare two semantic versions, following SemVer precedence rules."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Returns -1 if the input version has lower precedence than `other`, 0 if they are \
+         equal, or 1 if it has higher precedence. Build metadata is ignored for precedence, \
+         per the SemVer spec."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["semver", "version", "sort"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let other: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(
+            move |value| action(value, &other, head),
+            engine_state.signals(),
+        )
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "A lower version compares as less than a higher one",
+                example: "'1.2.3' | semver compare '1.10.0'",
+                result: Some(Value::test_int(-1)),
+            },
+            Example {
+                description: "Precedence ignores build metadata",
+                example: "'1.0.0+build1' | semver compare '1.0.0+build2'",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "A release has higher precedence than its prereleases",
+                example: "'1.0.0' | semver compare '1.0.0-rc.1'",
+                result: Some(Value::test_int(1)),
+            },
+        ]
+    }
+}
+
+fn action(value: Value, other: &Spanned<String>, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => {
+            let lhs = match parse_version(val, span) {
+                Ok(version) => version,
+                Err(err) => return Value::error(err, span),
+            };
+            let rhs = match parse_version(&other.item, other.span) {
+                Ok(version) => version,
+                Err(err) => return Value::error(err, span),
+            };
+
+            // `Version`'s own `Ord` also compares build metadata, so compare by precedence only.
+            let ordering = (lhs.major, lhs.minor, lhs.patch)
+                .cmp(&(rhs.major, rhs.minor, rhs.patch))
+                .then_with(|| lhs.pre.cmp(&rhs.pre));
+
+            Value::int(ordering as i64, span)
+        }
+        Value::Error { .. } => value,
+        wrong => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: wrong.get_type().to_string(),
+                dst_span: head,
+                src_span: wrong.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SemVerCompare {})
+    }
+}
@@ -0,0 +1,115 @@
+use super::parse_version;
+use nu_engine::command_prelude::*;
+use semver::Version;
+
+#[derive(Clone)]
+pub struct IntoSemVer;
+
+impl Command for IntoSemVer {
+    fn name(&self) -> &str {
+        "into semver"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into semver")
+            .input_output_types(vec![(Type::String, Type::record())])
+            .allow_variants_without_examples(true)
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Parse a string into a structured semantic version record."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["semver", "version", "parse"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(move |value| action(value, head), engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Parse a semantic version string",
+                example: "'1.2.3-beta.1+build.5' | into semver",
+                result: Some(Value::test_record(record! {
+                    "major" => Value::test_int(1),
+                    "minor" => Value::test_int(2),
+                    "patch" => Value::test_int(3),
+                    "prerelease" => Value::test_string("beta.1"),
+                    "build" => Value::test_string("build.5"),
+                })),
+            },
+            Example {
+                description: "Parse a plain version with no prerelease or build metadata",
+                example: "'2.0.0' | into semver",
+                result: Some(Value::test_record(record! {
+                    "major" => Value::test_int(2),
+                    "minor" => Value::test_int(0),
+                    "patch" => Value::test_int(0),
+                    "prerelease" => Value::test_string(""),
+                    "build" => Value::test_string(""),
+                })),
+            },
+        ]
+    }
+}
+
+fn version_to_record(version: &Version, span: Span) -> Value {
+    Value::record(
+        record! {
+            "major" => Value::int(version.major as i64, span),
+            "minor" => Value::int(version.minor as i64, span),
+            "patch" => Value::int(version.patch as i64, span),
+            "prerelease" => Value::string(version.pre.as_str(), span),
+            "build" => Value::string(version.build.as_str(), span),
+        },
+        span,
+    )
+}
+
+fn action(value: Value, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => match parse_version(val, span) {
+            Ok(version) => version_to_record(&version, span),
+            Err(err) => Value::error(err, span),
+        },
+        Value::Error { .. } => value,
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IntoSemVer {})
+    }
+}
@@ -0,0 +1,118 @@
+use super::parse_version;
+use nu_engine::command_prelude::*;
+use semver::VersionReq;
+
+#[derive(Clone)]
+pub struct SemVerMatch;
+
+impl Command for SemVerMatch {
+    fn name(&self) -> &str {
+        "semver match"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("semver match")
+            .input_output_types(vec![(Type::String, Type::Bool)])
+            .required(
+                "req",
+                SyntaxShape::String,
+                "a semantic version requirement, e.g. '^1.2', '>=1.0.0, <2.0.0'",
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Check whether a semantic version satisfies a version requirement."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["semver", "version", "requirement"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let req: Spanned<String> = call.req(engine_state, stack, 0)?;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(
+            move |value| action(value, &req, head),
+            engine_state.signals(),
+        )
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "A version satisfying a caret requirement",
+                example: "'1.4.2' | semver match '^1.2'",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "A version outside the requirement",
+                example: "'2.0.0' | semver match '^1.2'",
+                result: Some(Value::test_bool(false)),
+            },
+        ]
+    }
+}
+
+fn action(value: Value, req: &Spanned<String>, head: Span) -> Value {
+    let span = value.span();
+    match &value {
+        Value::String { val, .. } => {
+            let version = match parse_version(val, span) {
+                Ok(version) => version,
+                Err(err) => return Value::error(err, span),
+            };
+            let requirement = match VersionReq::parse(&req.item) {
+                Ok(requirement) => requirement,
+                Err(err) => {
+                    return Value::error(
+                        ShellError::IncorrectValue {
+                            msg: format!(
+                                "'{}' is not a valid version requirement: {err}",
+                                req.item
+                            ),
+                            val_span: req.span,
+                            call_span: head,
+                        },
+                        span,
+                    )
+                }
+            };
+
+            Value::bool(requirement.matches(&version), span)
+        }
+        Value::Error { .. } => value,
+        wrong => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: wrong.get_type().to_string(),
+                dst_span: head,
+                src_span: wrong.span(),
+            },
+            span,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(SemVerMatch {})
+    }
+}
@@ -0,0 +1,19 @@
+mod compare;
+mod into_semver;
+mod match_;
+
+pub use compare::SemVerCompare;
+pub use into_semver::IntoSemVer;
+pub use match_::SemVerMatch;
+
+use nu_protocol::{ShellError, Span};
+use semver::Version;
+
+pub(super) fn parse_version(s: &str, span: Span) -> Result<Version, ShellError> {
+    let s = s.trim();
+    Version::parse(s).map_err(|err| ShellError::IncorrectValue {
+        msg: format!("'{s}' is not a valid semantic version: {err}"),
+        val_span: span,
+        call_span: span,
+    })
+}
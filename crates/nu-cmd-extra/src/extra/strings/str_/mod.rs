@@ -1 +1,3 @@
 pub(crate) mod case;
+pub(crate) mod normalize;
+pub(crate) mod transliterate;
@@ -0,0 +1,152 @@
+use nu_cmd_base::input_handler::{operate, CmdArgument};
+use nu_engine::command_prelude::*;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Clone, Copy)]
+enum NormalizationForm {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl NormalizationForm {
+    fn apply(self, input: &str) -> String {
+        match self {
+            NormalizationForm::Nfc => input.nfc().collect(),
+            NormalizationForm::Nfd => input.nfd().collect(),
+            NormalizationForm::Nfkc => input.nfkc().collect(),
+            NormalizationForm::Nfkd => input.nfkd().collect(),
+        }
+    }
+}
+
+struct Arguments {
+    form: NormalizationForm,
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+#[derive(Clone)]
+pub struct StrNormalize;
+
+impl Command for StrNormalize {
+    fn name(&self) -> &str {
+        "str normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str normalize")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::List(Box::new(Type::String)), Type::List(Box::new(Type::String))),
+                (Type::table(), Type::table()),
+                (Type::record(), Type::record()),
+            ])
+            .allow_variants_without_examples(true)
+            .named(
+                "form",
+                SyntaxShape::String,
+                "the normalization form to apply: nfc, nfd, nfkc, or nfkd (defaults to nfc)",
+                Some('f'),
+            )
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, normalize strings at the given cell paths.",
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Apply Unicode normalization to a string."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "nfc", "nfd", "nfkc", "nfkd", "normalize"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let form_flag: Option<Spanned<String>> = call.get_flag(engine_state, stack, "form")?;
+        let form = match form_flag {
+            None => NormalizationForm::Nfc,
+            Some(flag) => match flag.item.to_ascii_lowercase().as_str() {
+                "nfc" => NormalizationForm::Nfc,
+                "nfd" => NormalizationForm::Nfd,
+                "nfkc" => NormalizationForm::Nfkc,
+                "nfkd" => NormalizationForm::Nfkd,
+                _ => {
+                    return Err(ShellError::IncorrectValue {
+                        msg: format!(
+                            "'{}' is not a supported normalization form; expected nfc, nfd, nfkc, or nfkd",
+                            flag.item
+                        ),
+                        val_span: flag.span,
+                        call_span: head,
+                    })
+                }
+            },
+        };
+
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let args = Arguments { form, cell_paths };
+
+        operate(action, args, input, head, engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Normalize a string to its precomposed form",
+                example: "\"e\\u{0301}\" | str normalize",
+                result: Some(Value::test_string("\u{e9}")),
+            },
+            Example {
+                description: "Decompose a string into its combining-character form",
+                example: "\"\\u{e9}\" | str normalize --form nfd",
+                result: Some(Value::test_string("e\u{0301}")),
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(args.form.apply(val), head),
+        Value::Error { .. } => input.clone(),
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            head,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StrNormalize {})
+    }
+}
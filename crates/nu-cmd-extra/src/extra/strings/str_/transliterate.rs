@@ -0,0 +1,112 @@
+use nu_cmd_base::input_handler::{operate, CmdArgument};
+use nu_engine::command_prelude::*;
+use unicode_normalization::{char::canonical_combining_class, UnicodeNormalization};
+
+struct Arguments {
+    cell_paths: Option<Vec<CellPath>>,
+}
+
+impl CmdArgument for Arguments {
+    fn take_cell_paths(&mut self) -> Option<Vec<CellPath>> {
+        self.cell_paths.take()
+    }
+}
+
+#[derive(Clone)]
+pub struct StrTransliterate;
+
+impl Command for StrTransliterate {
+    fn name(&self) -> &str {
+        "str transliterate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str transliterate")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (Type::List(Box::new(Type::String)), Type::List(Box::new(Type::String))),
+                (Type::table(), Type::table()),
+                (Type::record(), Type::record()),
+            ])
+            .allow_variants_without_examples(true)
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, transliterate strings at the given cell paths.",
+            )
+            .category(Category::Strings)
+    }
+
+    fn description(&self) -> &str {
+        "Strip diacritics from a string, e.g. for building sort keys and slugs."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This decomposes each character and drops its combining marks, so accented Latin \
+         letters fold to their plain form (\u{e9} -> e). It does not transliterate between \
+         scripts (e.g. Cyrillic or CJK to Latin), since no such mapping is part of this \
+         workspace's dependency tree."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["unicode", "diacritics", "accents", "slug", "ascii"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let args = Arguments { cell_paths };
+
+        operate(action, args, input, head, engine_state.signals())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Strip diacritics from a string",
+            example: "'café' | str transliterate",
+            result: Some(Value::test_string("cafe")),
+        }]
+    }
+}
+
+fn strip_diacritics(input: &str) -> String {
+    input
+        .nfd()
+        .filter(|c| canonical_combining_class(*c) == 0)
+        .collect()
+}
+
+fn action(input: &Value, _args: &Arguments, head: Span) -> Value {
+    match input {
+        Value::String { val, .. } => Value::string(strip_diacritics(val), head),
+        Value::Error { .. } => input.clone(),
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            head,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(StrTransliterate {})
+    }
+}
@@ -0,0 +1,192 @@
+use super::registry::convert;
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct UnitConvert;
+
+impl Command for UnitConvert {
+    fn name(&self) -> &str {
+        "unit convert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("unit convert")
+            .input_output_types(vec![
+                (Type::Int, Type::Float),
+                (Type::Float, Type::Float),
+                (Type::String, Type::Float),
+            ])
+            .allow_variants_without_examples(true)
+            .required("to", SyntaxShape::String, "Unit to convert the value into.")
+            .named(
+                "from",
+                SyntaxShape::String,
+                "source unit; required unless the input is a string like '5km' with the \
+                 unit already attached",
+                Some('f'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Convert a number between units of length, mass, temperature, time, or data rate."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Understands a fixed registry of unit names (see examples) plus simple compound \
+         rate units such as `km/h` or `mi/h`, formed from a length or data-rate unit \
+         divided by a time unit. This is not a general unit-algebra parser: only that one \
+         compound shape is understood."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["units", "measurement", "temperature", "length", "mass"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let to: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let from: Option<Spanned<String>> = call.get_flag(engine_state, stack, "from")?;
+
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+
+        input.map(
+            move |value| action(value, &to, from.as_ref(), head),
+            engine_state.signals(),
+        )
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Convert kilometers to miles",
+                example: "5 | unit convert mi --from km",
+                result: Some(Value::test_float(3.106_855_961_186_669_7)),
+            },
+            Example {
+                description: "Convert a temperature written with its unit attached",
+                example: "'98.6 f' | unit convert c",
+                result: Some(Value::test_float(37.0)),
+            },
+            Example {
+                description: "Convert pounds to kilograms",
+                example: "'150lb' | unit convert kg",
+                result: Some(Value::test_float(68.038_855_5)),
+            },
+            Example {
+                description: "Convert a data rate",
+                example: "100 | unit convert mbps --from mbps",
+                result: Some(Value::test_float(100.0)),
+            },
+            Example {
+                description: "Convert a compound rate unit",
+                example: "90 | unit convert mi/h --from km/h",
+                result: Some(Value::test_float(55.923_407_301_360_06)),
+            },
+        ]
+    }
+}
+
+fn parse_number_and_unit(value: &str, span: Span) -> Result<(f64, String), ShellError> {
+    let value = value.trim();
+    let split_at = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .ok_or_else(|| missing_unit_error(span))?;
+    let (number, unit) = value.split_at(split_at);
+    let unit = unit.trim();
+    if unit.is_empty() {
+        return Err(missing_unit_error(span));
+    }
+    let number = number
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| ShellError::CantConvert {
+            to_type: "float".into(),
+            from_type: "string".into(),
+            span,
+            help: None,
+        })?;
+    Ok((number, unit.to_string()))
+}
+
+fn missing_unit_error(span: Span) -> ShellError {
+    ShellError::IncorrectValue {
+        msg: "Expected a number followed by a unit, e.g. '5km', or pass --from explicitly"
+            .into(),
+        val_span: span,
+        call_span: span,
+    }
+}
+
+fn action(
+    value: Value,
+    to: &Spanned<String>,
+    from: Option<&Spanned<String>>,
+    head: Span,
+) -> Value {
+    let span = value.span();
+
+    let (number, from_unit) = match (&value, from) {
+        (Value::Int { val, .. }, Some(from)) => (*val as f64, from.item.clone()),
+        (Value::Float { val, .. }, Some(from)) => (*val, from.item.clone()),
+        (Value::Int { .. } | Value::Float { .. }, None) => {
+            return Value::error(
+                ShellError::MissingParameter {
+                    param_name: "from".into(),
+                    span: head,
+                },
+                span,
+            );
+        }
+        (Value::String { val, .. }, explicit_from) => match explicit_from {
+            Some(from) => match val.trim().parse::<f64>() {
+                Ok(number) => (number, from.item.clone()),
+                Err(_) => match parse_number_and_unit(val, span) {
+                    Ok((number, _)) => (number, from.item.clone()),
+                    Err(err) => return Value::error(err, span),
+                },
+            },
+            None => match parse_number_and_unit(val, span) {
+                Ok((number, unit)) => (number, unit),
+                Err(err) => return Value::error(err, span),
+            },
+        },
+        (Value::Error { .. }, _) => return value,
+        (other, _) => {
+            return Value::error(
+                ShellError::OnlySupportsThisInputType {
+                    exp_input_type: "int, float, or string".into(),
+                    wrong_type: other.get_type().to_string(),
+                    dst_span: head,
+                    src_span: other.span(),
+                },
+                span,
+            )
+        }
+    };
+
+    match convert(number, &from_unit, &to.item, span) {
+        Ok(result) => Value::float(result, span),
+        Err(err) => Value::error(err, span),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(UnitConvert {})
+    }
+}
@@ -0,0 +1,4 @@
+mod convert;
+mod registry;
+
+pub use convert::UnitConvert;
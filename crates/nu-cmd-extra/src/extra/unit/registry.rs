@@ -0,0 +1,263 @@
+use nu_protocol::{ShellError, Span};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum UnitCategory {
+    Length,
+    Mass,
+    Time,
+    DataRate,
+}
+
+pub(super) struct LinearUnit {
+    category: UnitCategory,
+    names: &'static [&'static str],
+    /// Multiplying a value in this unit by `factor` gives the category's base unit
+    /// (meters, kilograms, seconds, or bits per second, respectively).
+    factor: f64,
+}
+
+pub(super) const LINEAR_UNITS: &[LinearUnit] = &[
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["m"],
+        factor: 1.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["km"],
+        factor: 1_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["cm"],
+        factor: 0.01,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["mm"],
+        factor: 0.001,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["mi"],
+        factor: 1_609.344,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["yd"],
+        factor: 0.9144,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["ft"],
+        factor: 0.3048,
+    },
+    LinearUnit {
+        category: UnitCategory::Length,
+        names: &["in"],
+        factor: 0.0254,
+    },
+    LinearUnit {
+        category: UnitCategory::Mass,
+        names: &["kg"],
+        factor: 1.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Mass,
+        names: &["g"],
+        factor: 0.001,
+    },
+    LinearUnit {
+        category: UnitCategory::Mass,
+        names: &["mg"],
+        factor: 0.000_001,
+    },
+    LinearUnit {
+        category: UnitCategory::Mass,
+        names: &["lb"],
+        factor: 0.453_592_37,
+    },
+    LinearUnit {
+        category: UnitCategory::Mass,
+        names: &["oz"],
+        factor: 0.028_349_523_125,
+    },
+    LinearUnit {
+        category: UnitCategory::Time,
+        names: &["s"],
+        factor: 1.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Time,
+        names: &["min"],
+        factor: 60.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Time,
+        names: &["h"],
+        factor: 3_600.0,
+    },
+    LinearUnit {
+        category: UnitCategory::Time,
+        names: &["day"],
+        factor: 86_400.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["bps"],
+        factor: 1.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["kbps"],
+        factor: 1_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["mbps"],
+        factor: 1_000_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["gbps"],
+        factor: 1_000_000_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["Bps"],
+        factor: 8.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["KBps"],
+        factor: 8_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["MBps"],
+        factor: 8_000_000.0,
+    },
+    LinearUnit {
+        category: UnitCategory::DataRate,
+        names: &["GBps"],
+        factor: 8_000_000_000.0,
+    },
+];
+
+const TEMPERATURE_UNITS: &[&str] = &["c", "f", "k"];
+
+fn find_linear_unit(name: &str) -> Option<&'static LinearUnit> {
+    LINEAR_UNITS
+        .iter()
+        .find(|unit| unit.names.contains(&name))
+}
+
+fn unknown_unit_error(unit: &str, span: Span) -> ShellError {
+    ShellError::IncorrectValue {
+        msg: format!(
+            "Unknown unit '{unit}'. Supported units are m, km, cm, mm, mi, yd, ft, in \
+             (length); kg, g, mg, lb, oz (mass); s, min, h, day (time); c, f, k \
+             (temperature); bps, kbps, mbps, gbps, Bps, KBps, MBps, GBps (data rate); or a \
+             compound rate such as km/h."
+        ),
+        val_span: span,
+        call_span: span,
+    }
+}
+
+fn to_celsius(value: f64, unit: &str) -> f64 {
+    match unit {
+        "f" => (value - 32.0) * 5.0 / 9.0,
+        "k" => value - 273.15,
+        _ => value,
+    }
+}
+
+fn from_celsius(value: f64, unit: &str) -> f64 {
+    match unit {
+        "f" => value * 9.0 / 5.0 + 32.0,
+        "k" => value + 273.15,
+        _ => value,
+    }
+}
+
+fn convert_scalar(value: f64, from: &str, to: &str, span: Span) -> Result<f64, ShellError> {
+    if TEMPERATURE_UNITS.contains(&from) || TEMPERATURE_UNITS.contains(&to) {
+        if !TEMPERATURE_UNITS.contains(&from) {
+            return Err(unknown_unit_error(from, span));
+        }
+        if !TEMPERATURE_UNITS.contains(&to) {
+            return Err(unknown_unit_error(to, span));
+        }
+        return Ok(from_celsius(to_celsius(value, from), to));
+    }
+
+    let from_unit = find_linear_unit(from).ok_or_else(|| unknown_unit_error(from, span))?;
+    let to_unit = find_linear_unit(to).ok_or_else(|| unknown_unit_error(to, span))?;
+
+    if from_unit.category != to_unit.category {
+        return Err(ShellError::IncorrectValue {
+            msg: format!("Cannot convert '{from}' to '{to}': units are of different kinds"),
+            val_span: span,
+            call_span: span,
+        });
+    }
+
+    Ok(value * from_unit.factor / to_unit.factor)
+}
+
+/// Splits a compound rate unit like `km/h` into its numerator and denominator, e.g.
+/// `("km", "h")`. Units without a `/` are not compound.
+fn split_compound(unit: &str) -> Option<(&str, &str)> {
+    unit.split_once('/')
+}
+
+fn convert_compound(
+    value: f64,
+    from_num: &str,
+    from_den: &str,
+    to_num: &str,
+    to_den: &str,
+    span: Span,
+) -> Result<f64, ShellError> {
+    let from_num = find_linear_unit(from_num).ok_or_else(|| unknown_unit_error(from_num, span))?;
+    let from_den = find_linear_unit(from_den).ok_or_else(|| unknown_unit_error(from_den, span))?;
+    let to_num = find_linear_unit(to_num).ok_or_else(|| unknown_unit_error(to_num, span))?;
+    let to_den = find_linear_unit(to_den).ok_or_else(|| unknown_unit_error(to_den, span))?;
+
+    if from_den.category != UnitCategory::Time || to_den.category != UnitCategory::Time {
+        return Err(ShellError::IncorrectValue {
+            msg: "Compound rate units must have a time unit as the denominator, e.g. km/h"
+                .into(),
+            val_span: span,
+            call_span: span,
+        });
+    }
+    if from_num.category != to_num.category {
+        return Err(ShellError::IncorrectValue {
+            msg: "Cannot convert between compound units of different kinds".into(),
+            val_span: span,
+            call_span: span,
+        });
+    }
+
+    let value_in_base = value * from_num.factor / from_den.factor;
+    Ok(value_in_base * to_den.factor / to_num.factor)
+}
+
+/// Converts `value` from unit `from` to unit `to`, understanding both plain unit names
+/// (e.g. `km`, `f`, `mbps`) and simple compound rate units (e.g. `km/h`).
+pub(super) fn convert(value: f64, from: &str, to: &str, span: Span) -> Result<f64, ShellError> {
+    match (split_compound(from), split_compound(to)) {
+        (Some((from_num, from_den)), Some((to_num, to_den))) => {
+            convert_compound(value, from_num, from_den, to_num, to_den, span)
+        }
+        (None, None) => convert_scalar(value, from, to, span),
+        _ => Err(ShellError::IncorrectValue {
+            msg: format!("Cannot convert between '{from}' and '{to}': both must either be \
+                 plain units or both be compound rate units like km/h"),
+            val_span: span,
+            call_span: span,
+        }),
+    }
+}
@@ -0,0 +1,92 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct Assert;
+
+impl Command for Assert {
+    fn name(&self) -> &str {
+        "assert"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("condition", SyntaxShape::Boolean, "The condition that should hold.")
+            .named(
+                "message",
+                SyntaxShape::String,
+                "Error message to show if the condition is not true.",
+                Some('m'),
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Assert that a condition is true."
+    }
+
+    fn extra_description(&self) -> &str {
+        "If the condition is not true, it generates an error pointing at the failing condition."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["panic", "crash", "throw", "test"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let condition_span = call
+            .positional_nth(stack, 0)
+            .map(|expr| expr.span)
+            .unwrap_or(call.head);
+        let condition: bool = call.req(engine_state, stack, 0)?;
+        let message: Option<String> = call.get_flag(engine_state, stack, "message")?;
+
+        if condition {
+            return Ok(PipelineData::empty());
+        }
+
+        Err(ShellError::GenericError {
+            error: message.unwrap_or_else(|| "Assertion failed.".into()),
+            msg: "It is not true.".into(),
+            span: Some(condition_span),
+            help: None,
+            inner: vec![],
+        })
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "This assert passes",
+                example: "assert (3 == 3)",
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "This assert fails",
+                example: "assert (42 == 3)",
+                result: None,
+            },
+            Example {
+                description: "Use a custom failure message",
+                example: r#"assert (3 == 4) --message "three is not four""#,
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::Assert;
+        use crate::test_examples;
+        test_examples(Assert {})
+    }
+}
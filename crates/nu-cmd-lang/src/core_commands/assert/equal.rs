@@ -0,0 +1,95 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct AssertEqual;
+
+impl Command for AssertEqual {
+    fn name(&self) -> &str {
+        "assert equal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert equal")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("left", SyntaxShape::Any, "The first value to compare.")
+            .required("right", SyntaxShape::Any, "The second value to compare.")
+            .named(
+                "message",
+                SyntaxShape::String,
+                "Error message to show if the two values are not equal.",
+                Some('m'),
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Assert that two values are equal."
+    }
+
+    fn extra_description(&self) -> &str {
+        "For more documentation see the assert command."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["panic", "crash", "throw", "test"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let left: Value = call.req(engine_state, stack, 0)?;
+        let right: Value = call.req(engine_state, stack, 1)?;
+        let message: Option<String> = call.get_flag(engine_state, stack, "message")?;
+        let span = Span::new(left.span().start, right.span().end);
+
+        if left == right {
+            return Ok(PipelineData::empty());
+        }
+
+        Err(ShellError::GenericError {
+            error: message.unwrap_or_else(|| "Assertion failed.".into()),
+            msg: format!(
+                "These are not equal.\n        Left  : '{}'\n        Right : '{}'",
+                left.to_debug_string(),
+                right.to_debug_string()
+            ),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "This assert passes",
+                example: "assert equal 1 1",
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "This assert passes",
+                example: "assert equal (0.1 + 0.2) 0.3",
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "This assert fails",
+                example: "assert equal 1 2",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::AssertEqual;
+        use crate::test_examples;
+        test_examples(AssertEqual {})
+    }
+}
@@ -0,0 +1,109 @@
+use nu_engine::{command_prelude::*, get_eval_block_with_early_return};
+use nu_protocol::engine::Closure;
+
+#[derive(Clone)]
+pub struct AssertError;
+
+impl Command for AssertError {
+    fn name(&self) -> &str {
+        "assert error"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("assert error")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "code",
+                SyntaxShape::Closure(None),
+                "The closure that should raise an error.",
+            )
+            .named(
+                "message",
+                SyntaxShape::String,
+                "Error message to show if the closure does not raise an error.",
+                Some('m'),
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Assert that executing the code generates an error."
+    }
+
+    fn extra_description(&self) -> &str {
+        "For more documentation see the assert command."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["panic", "crash", "throw", "test"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        caller_stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let code_span = call
+            .positional_nth(caller_stack, 0)
+            .map(|expr| expr.span)
+            .unwrap_or(call.head);
+        let closure: Closure = call.req(engine_state, caller_stack, 0)?;
+        let message: Option<String> = call.get_flag(engine_state, caller_stack, "message")?;
+
+        let mut callee_stack =
+            caller_stack.captures_to_stack_preserve_out_dest(closure.captures);
+        let block = engine_state.get_block(closure.block_id);
+        let eval_block_with_early_return = get_eval_block_with_early_return(engine_state);
+
+        let result = eval_block_with_early_return(
+            engine_state,
+            &mut callee_stack,
+            block,
+            PipelineData::empty(),
+        )
+        .and_then(|pipeline| pipeline.drain_to_out_dests(engine_state, &mut callee_stack));
+
+        match result {
+            Err(
+                err @ (ShellError::Break { .. }
+                | ShellError::Continue { .. }
+                | ShellError::Return { .. }),
+            ) => Err(err),
+            Err(_) | Ok(PipelineData::Value(Value::Error { .. }, ..)) => Ok(PipelineData::empty()),
+            Ok(_) => Err(ShellError::GenericError {
+                error: message.unwrap_or_else(|| "Assertion failed.".into()),
+                msg: "There were no error during code execution.".into(),
+                span: Some(code_span),
+                help: None,
+                inner: vec![],
+            }),
+        }
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "This assert passes",
+                example: "assert error {|| missing_command}",
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "This assert fails",
+                example: "assert error {|| 12}",
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::AssertError;
+        use crate::test_examples;
+        test_examples(AssertError {})
+    }
+}
@@ -0,0 +1,7 @@
+mod command;
+mod equal;
+mod error;
+
+pub use command::Assert;
+pub use equal::AssertEqual;
+pub use error::AssertError;
@@ -16,6 +16,11 @@ impl Command for Break {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("break")
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "label",
+                SyntaxShape::String,
+                "Break out of the loop with this label, rather than the innermost one.",
+            )
             .category(Category::Core)
     }
 
@@ -23,7 +28,9 @@ impl Command for Break {
         r#"This command is a parser keyword. For details, check:
   https://www.nushell.sh/book/thinking_in_nu.html
 
-  break can only be used in while, loop, and for loops. It can not be used with each or other filter commands"#
+  break can only be used in while, loop, and for loops. It can not be used with each or other filter commands
+
+  When a label is given, it breaks out of the enclosing loop with a matching `--label`, rather than the innermost one."#
     }
 
     fn command_type(&self) -> CommandType {
@@ -32,19 +39,30 @@ impl Command for Break {
 
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        Err(ShellError::Break { span: call.head })
+        let label: Option<String> = call.opt(engine_state, stack, 0)?;
+        Err(ShellError::Break {
+            span: call.head,
+            label,
+        })
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Break out of a loop",
-            example: r#"loop { break }"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Break out of a loop",
+                example: r#"loop { break }"#,
+                result: None,
+            },
+            Example {
+                description: "Break out of an outer, labeled loop from within a nested one",
+                example: r#"for x in 1..3 --label outer { for y in 1..3 { break outer } }"#,
+                result: None,
+            },
+        ]
     }
 }
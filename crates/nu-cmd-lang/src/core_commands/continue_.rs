@@ -16,6 +16,11 @@ impl Command for Continue {
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build("continue")
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .optional(
+                "label",
+                SyntaxShape::String,
+                "Continue the loop with this label, rather than the innermost one.",
+            )
             .category(Category::Core)
     }
 
@@ -23,27 +28,41 @@ impl Command for Continue {
         r#"This command is a parser keyword. For details, check:
   https://www.nushell.sh/book/thinking_in_nu.html
 
-  continue can only be used in while, loop, and for loops. It can not be used with each or other filter commands"#
+  continue can only be used in while, loop, and for loops. It can not be used with each or other filter commands
+
+  When a label is given, it continues the enclosing loop with a matching `--label`, rather than the innermost one."#
     }
 
     fn command_type(&self) -> CommandType {
         CommandType::Keyword
     }
+
     fn run(
         &self,
-        _engine_state: &EngineState,
-        _stack: &mut Stack,
+        engine_state: &EngineState,
+        stack: &mut Stack,
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        Err(ShellError::Continue { span: call.head })
+        let label: Option<String> = call.opt(engine_state, stack, 0)?;
+        Err(ShellError::Continue {
+            span: call.head,
+            label,
+        })
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Continue a loop from the next iteration",
-            example: r#"for i in 1..10 { if $i == 5 { continue }; print $i }"#,
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Continue a loop from the next iteration",
+                example: r#"for i in 1..10 { if $i == 5 { continue }; print $i }"#,
+                result: None,
+            },
+            Example {
+                description: "Continue an outer, labeled loop from within a nested one",
+                example: r#"for x in 1..3 --label outer { for y in 1..3 { continue outer } }"#,
+                result: None,
+            },
+        ]
     }
 }
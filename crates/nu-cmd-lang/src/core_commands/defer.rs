@@ -0,0 +1,79 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::engine::Closure;
+
+#[derive(Clone)]
+pub struct Defer;
+
+impl Command for Defer {
+    fn name(&self) -> &str {
+        "defer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("defer")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "The closure to run on scope exit.",
+            )
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure when the current custom command or closure call finishes."
+    }
+
+    fn extra_description(&self) -> &str {
+        "The closure runs however the call ends: on normal completion, on error, or via \
+        `break`/`continue`/`return`. This makes it useful for cleanup that must always happen, \
+        such as removing a temporary file or restoring the working directory. Closures registered \
+        with multiple calls to `defer` run in reverse order, most-recently-registered first."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cleanup", "finally", "scope"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        stack.push_deferred(closure);
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Register a closure to run once the current call ends",
+                example: r#"defer { "cleanup" }"#,
+                result: Some(Value::nothing(Span::test_data())),
+            },
+            Example {
+                description: "Run cleanup when a custom command returns",
+                example: r#"def foo [] { defer { print "cleanup" }; print "body" }; foo"#,
+                result: None,
+            },
+            Example {
+                description: "Cleanup still runs when the command errors",
+                example: r#"def foo [] { defer { print "cleanup" }; error make {msg: "boom"} }; try { foo }"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::Defer;
+        use crate::test_examples;
+        test_examples(Defer {})
+    }
+}
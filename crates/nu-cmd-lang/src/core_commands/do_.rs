@@ -71,6 +71,12 @@ impl Command for Do {
         let eval_block_with_early_return = get_eval_block_with_early_return(engine_state);
 
         let result = eval_block_with_early_return(engine_state, &mut callee_stack, block, input);
+        let result = nu_engine::run_deferred_closures(
+            engine_state,
+            &mut callee_stack,
+            eval_block_with_early_return,
+            result,
+        );
 
         if has_env {
             // Merge the block's environment to the current stack
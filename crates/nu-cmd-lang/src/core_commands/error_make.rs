@@ -89,6 +89,17 @@ impl Command for ErrorMake {
     }"#,
                 result: None,
             },
+            Example {
+                description:
+                    "Create a custom error with a machine-readable code, so callers can match on it",
+                example: r#"error make {msg: "out of coolant", code: "my_module::out_of_coolant"}"#,
+                result: None,
+            },
+            Example {
+                description: "Attach structured metadata a caller can branch on without parsing the message",
+                example: r#"error make {msg: "request failed", code: "my_module::http_error", metadata: {status: 503}}"#,
+                result: None,
+            },
         ]
     }
 }
@@ -137,6 +148,63 @@ fn make_other_error(value: &Value, throw_span: Option<Span>) -> ShellError {
         _ => None,
     };
 
+    let code = match value.get("code") {
+        Some(Value::String { val, .. }) => Some(val.clone()),
+        Some(_) => {
+            return ShellError::GenericError {
+                error: UNABLE_TO_PARSE.into(),
+                msg: "`$.code` has wrong type, must be string".into(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            }
+        }
+        None => None,
+    };
+
+    let url = match value.get("url") {
+        Some(Value::String { val, .. }) => Some(val.clone()),
+        Some(_) => {
+            return ShellError::GenericError {
+                error: UNABLE_TO_PARSE.into(),
+                msg: "`$.url` has wrong type, must be string".into(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            }
+        }
+        None => None,
+    };
+
+    let metadata = value.get("metadata").cloned();
+
+    // A `code`/`url`/`metadata` selects the structured `LabeledError` path, which surfaces
+    // machine-readable identity through `$err.code`/`$err.metadata` in a `catch` closure, instead
+    // of the plain `GenericError`.
+    if code.is_some() || url.is_some() || metadata.is_some() {
+        let mut error = LabeledError::new(msg);
+        if let Some(help) = help {
+            error = error.with_help(help);
+        }
+        if let Some(code) = code {
+            error = error.with_code(code);
+        }
+        if let Some(url) = url {
+            error = error.with_url(url);
+        }
+        if let Some(metadata) = metadata {
+            error = error.with_metadata(metadata);
+        }
+        if let Some(label) = value.get("label") {
+            if let Value::Record { val: label, .. } = label {
+                if let Some(Value::String { val: text, .. }) = label.get("text") {
+                    error = error.with_label(text.clone(), throw_span.unwrap_or(span));
+                }
+            }
+        }
+        return error.into();
+    }
+
     let (label, label_span) = match value.get("label") {
         Some(value @ Value::Record { val, .. }) => (val, value.span()),
         Some(_) => {
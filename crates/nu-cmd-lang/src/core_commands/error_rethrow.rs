@@ -0,0 +1,84 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct ErrorRethrow;
+
+impl Command for ErrorRethrow {
+    fn name(&self) -> &str {
+        "error rethrow"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("error rethrow")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .category(Category::Core)
+    }
+
+    fn description(&self) -> &str {
+        "Re-raise an error caught by `try`/`catch`, preserving its original span."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Accepts either the `$err` record produced by `catch`, or a bare error value, and
+raises the original error unchanged. This is useful for selectively handling some errors
+in a `catch` closure while letting the rest propagate."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["throw", "raise", "reraise", "propagate"]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let value = input.into_value(span)?;
+        Err(error_from_value(value, span))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Handle a specific error and rethrow the rest",
+                example: r#"try { 1 / 0 } catch { |err|
+    if $err.code == "nu::shell::division_by_zero" {
+        0
+    } else {
+        $err | error rethrow
+    }
+}"#,
+                result: None,
+            },
+            Example {
+                description: "Rethrow the caught error unconditionally",
+                example: r#"try { 1 / 0 } catch { |err| $err | error rethrow }"#,
+                result: None,
+            },
+        ]
+    }
+}
+
+fn error_from_value(value: Value, span: Span) -> ShellError {
+    match value {
+        Value::Error { error, .. } => *error,
+        Value::Record { val, .. } => match val.get("raw") {
+            Some(Value::Error { error, .. }) => *error.clone(),
+            _ => not_an_error(span),
+        },
+        _ => not_an_error(span),
+    }
+}
+
+fn not_an_error(span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Not an error value".into(),
+        msg: "expected an error value, or the error record produced by `catch`".into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -28,6 +28,23 @@ impl Command for For {
                 "Range of the loop.",
             )
             .required("block", SyntaxShape::Block, "The block to run.")
+            .named(
+                "index",
+                SyntaxShape::VarWithOptType,
+                "Bind the iteration index (starting at 0) to this variable.",
+                Some('n'),
+            )
+            .optional(
+                "else_expression",
+                SyntaxShape::Keyword(b"else".to_vec(), Box::new(SyntaxShape::Block)),
+                "Block to run if the range was empty.",
+            )
+            .named(
+                "label",
+                SyntaxShape::String,
+                "Label this loop so `break`/`continue` from a nested loop can target it.",
+                Some('l'),
+            )
             .creates_scope()
             .category(Category::Core)
     }
@@ -70,6 +87,15 @@ impl Command for For {
             .as_block()
             .expect("internal error: missing block");
 
+        let index_var_id = call.get_flag_expr("index").and_then(|expr| expr.as_var());
+
+        let else_block_id = call
+            .positional_nth(3)
+            .and_then(|else_case| else_case.as_keyword())
+            .and_then(|else_expr| else_expr.as_block());
+
+        let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+
         let eval_expression = get_eval_expression(engine_state);
         let eval_block = get_eval_block(engine_state);
 
@@ -80,45 +106,80 @@ impl Command for For {
 
         let stack = &mut stack.push_redirection(None, None);
 
+        let mut iterated = false;
         let span = value.span();
         match value {
             Value::List { vals, .. } => {
-                for x in vals.into_iter() {
+                for (index, x) in vals.into_iter().enumerate() {
                     engine_state.signals().check(head)?;
 
                     // with_env() is used here to ensure that each iteration uses
                     // a different set of environment variables.
                     // Hence, a 'cd' in the first loop won't affect the next loop.
 
+                    iterated = true;
                     stack.add_var(var_id, x);
+                    if let Some(index_var_id) = index_var_id {
+                        stack.add_var(index_var_id, Value::int(index as i64, head));
+                    }
 
                     match eval_block(&engine_state, stack, block, PipelineData::empty()) {
-                        Err(ShellError::Break { .. }) => break,
-                        Err(ShellError::Continue { .. }) => continue,
+                        Err(ShellError::Break { label: l, .. }) if l.is_none() || l == label => {
+                            break
+                        }
+                        Err(ShellError::Continue { label: l, .. })
+                            if l.is_none() || l == label =>
+                        {
+                            continue
+                        }
                         Err(err) => return Err(err),
                         Ok(data) => data.drain()?,
                     }
                 }
             }
             Value::Range { val, .. } => {
-                for x in val.into_range_iter(span, Signals::empty()) {
+                for (index, x) in val.into_range_iter(span, Signals::empty()).enumerate() {
                     engine_state.signals().check(head)?;
+                    iterated = true;
                     stack.add_var(var_id, x);
+                    if let Some(index_var_id) = index_var_id {
+                        stack.add_var(index_var_id, Value::int(index as i64, head));
+                    }
 
                     match eval_block(&engine_state, stack, block, PipelineData::empty()) {
-                        Err(ShellError::Break { .. }) => break,
-                        Err(ShellError::Continue { .. }) => continue,
+                        Err(ShellError::Break { label: l, .. }) if l.is_none() || l == label => {
+                            break
+                        }
+                        Err(ShellError::Continue { label: l, .. })
+                            if l.is_none() || l == label =>
+                        {
+                            continue
+                        }
                         Err(err) => return Err(err),
                         Ok(data) => data.drain()?,
                     }
                 }
             }
             x => {
+                iterated = true;
                 stack.add_var(var_id, x);
+                if let Some(index_var_id) = index_var_id {
+                    stack.add_var(index_var_id, Value::int(0, head));
+                }
 
                 eval_block(&engine_state, stack, block, PipelineData::empty())?.into_value(head)?;
             }
         }
+
+        if !iterated {
+            if let Some(else_block_id) = else_block_id {
+                let else_block = engine_state.get_block(else_block_id);
+                eval_block(&engine_state, stack, else_block, PipelineData::empty())?
+                    .into_value(head)?;
+            }
+        }
+        // `else` never runs after the loop actually iterated, even if every pass hit `continue`.
+
         Ok(PipelineData::empty())
     }
 
@@ -139,6 +200,21 @@ impl Command for For {
                 example: r#"for $it in (['bob' 'fred'] | enumerate) { print $"($it.index) is ($it.item)" }"#,
                 result: None,
             },
+            Example {
+                description: "Bind the iteration index to a variable without using `enumerate`",
+                example: r#"for x in ['bob' 'fred'] --index $i { print $"($i) is ($x)" }"#,
+                result: None,
+            },
+            Example {
+                description: "Run a block if the range turned out to be empty",
+                example: "for x in [] { print $x } else { print \"nothing to do\" }",
+                result: None,
+            },
+            Example {
+                description: "Break out of an outer, labeled loop from within a nested one",
+                example: "for x in 1..3 --label outer { for y in 1..3 { break outer } }",
+                result: None,
+            },
         ]
     }
 }
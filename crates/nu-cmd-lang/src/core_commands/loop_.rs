@@ -18,6 +18,12 @@ impl Command for Loop {
             .input_output_types(vec![(Type::Nothing, Type::Nothing)])
             .allow_variants_without_examples(true)
             .required("block", SyntaxShape::Block, "Block to loop.")
+            .named(
+                "label",
+                SyntaxShape::String,
+                "Label this loop so `break`/`continue` from a nested loop can target it.",
+                Some('l'),
+            )
             .category(Category::Core)
     }
 
@@ -47,6 +53,8 @@ impl Command for Loop {
             .as_block()
             .expect("internal error: missing block");
 
+        let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+
         let block = engine_state.get_block(block_id);
         let eval_block = get_eval_block(engine_state);
 
@@ -56,8 +64,10 @@ impl Command for Loop {
             engine_state.signals().check(head)?;
 
             match eval_block(engine_state, stack, block, PipelineData::empty()) {
-                Err(ShellError::Break { .. }) => break,
-                Err(ShellError::Continue { .. }) => continue,
+                Err(ShellError::Break { label: l, .. }) if l.is_none() || l == label => break,
+                Err(ShellError::Continue { label: l, .. }) if l.is_none() || l == label => {
+                    continue
+                }
                 Err(err) => return Err(err),
                 Ok(data) => data.drain()?,
             }
@@ -66,11 +76,18 @@ impl Command for Loop {
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Loop while a condition is true",
-            example: "mut x = 0; loop { if $x > 10 { break }; $x = $x + 1 }; $x",
-            result: Some(Value::test_int(11)),
-        }]
+        vec![
+            Example {
+                description: "Loop while a condition is true",
+                example: "mut x = 0; loop { if $x > 10 { break }; $x = $x + 1 }; $x",
+                result: Some(Value::test_int(11)),
+            },
+            Example {
+                description: "Break out of an outer, labeled loop from within a nested one",
+                example: "loop --label outer { loop { break outer } }",
+                result: None,
+            },
+        ]
     }
 }
 
@@ -128,6 +128,14 @@ impl Command for Match {
     ",
                 result: Some(Value::test_string("good list")),
             },
+            Example {
+                description: "Match against a record, capturing the remaining fields",
+                example: "match {a: 1, b: 2, c: 3} { {a: $a, ..$rest} => { $rest } }",
+                result: Some(Value::test_record(record! {
+                    "b" => Value::test_int(2),
+                    "c" => Value::test_int(3),
+                })),
+            },
         ]
     }
 }
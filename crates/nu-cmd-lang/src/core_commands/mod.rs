@@ -1,14 +1,17 @@
 mod alias;
+pub(crate) mod assert;
 mod attr;
 mod break_;
 mod collect;
 mod const_;
 mod continue_;
 mod def;
+mod defer;
 mod describe;
 mod do_;
 mod echo;
 mod error_make;
+mod error_rethrow;
 mod export;
 mod export_alias;
 mod export_const;
@@ -36,16 +39,19 @@ mod version;
 mod while_;
 
 pub use alias::Alias;
+pub use assert::*;
 pub use attr::*;
 pub use break_::Break;
 pub use collect::Collect;
 pub use const_::Const;
 pub use continue_::Continue;
 pub use def::Def;
+pub use defer::Defer;
 pub use describe::Describe;
 pub use do_::Do;
 pub use echo::Echo;
 pub use error_make::ErrorMake;
+pub use error_rethrow::ErrorRethrow;
 pub use export::ExportCommand;
 pub use export_alias::ExportAlias;
 pub use export_const::ExportConst;
@@ -0,0 +1,93 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct OverlayExport;
+
+impl Command for OverlayExport {
+    fn name(&self) -> &str {
+        "overlay export"
+    }
+
+    fn description(&self) -> &str {
+        "Save an active overlay's environment variables to a file."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("overlay export")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "Name of the overlay to export.")
+            .named(
+                "path",
+                SyntaxShape::Filepath,
+                "File to write the overlay's environment to.",
+                None,
+            )
+            .category(Category::Core)
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Only the environment variables set while the overlay was active are captured; custom
+commands and aliases defined in the overlay's module are not, since they can't be
+reconstructed outside of the module that defined them. Use `overlay import` to restore a
+snapshot written by this command."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let path: Spanned<String> = call
+            .get_flag(engine_state, stack, "path")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "path".into(),
+                span: call.head,
+            })?;
+
+        if !stack.is_overlay_active(&name.item) {
+            return Err(ShellError::OverlayNotFoundAtRuntime {
+                overlay_name: name.item,
+                span: name.span,
+            });
+        }
+
+        let mut env_vars = stack.get_stack_overlay_env_vars(&name.item);
+        if let Some(global) = engine_state.env_vars.get(&name.item) {
+            for (k, v) in global.iter() {
+                env_vars.entry(k.clone()).or_insert_with(|| v.clone());
+            }
+        }
+
+        let mut contents = String::new();
+        for (key, value) in env_vars {
+            let value = value.coerce_into_string()?;
+            contents.push_str(&key);
+            contents.push('\t');
+            contents.push_str(&value.replace(['\n', '\t'], " "));
+            contents.push('\n');
+        }
+
+        std::fs::write(&path.item, contents).map_err(|err| {
+            ShellError::from(nu_protocol::shell_error::io::IoError::new(
+                err.kind(),
+                path.span,
+                Some(path.item.clone().into()),
+            ))
+        })?;
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Save the environment set up by an overlay to a file",
+            example: r#"overlay new spam
+    $env.GREETING = "hello"
+    overlay export spam --path spam.env"#,
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,78 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct OverlayImport;
+
+impl Command for OverlayImport {
+    fn name(&self) -> &str {
+        "overlay import"
+    }
+
+    fn description(&self) -> &str {
+        "Restore environment variables from a file written by `overlay export`."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("overlay import")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .required("name", SyntaxShape::String, "Name of the overlay to restore into.")
+            .named(
+                "path",
+                SyntaxShape::Filepath,
+                "File previously written by `overlay export`.",
+                None,
+            )
+            .category(Category::Core)
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"If an overlay with this name isn't already active, an empty one is created first, the
+same way `overlay new` would. Only environment variables are restored; this cannot bring
+back custom commands or aliases."#
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let path: Spanned<String> = call
+            .get_flag(engine_state, stack, "path")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "path".into(),
+                span: call.head,
+            })?;
+
+        let contents = std::fs::read_to_string(&path.item).map_err(|err| {
+            ShellError::from(nu_protocol::shell_error::io::IoError::new(
+                err.kind(),
+                path.span,
+                Some(path.item.clone().into()),
+            ))
+        })?;
+
+        if !stack.is_overlay_active(&name.item) {
+            stack.add_overlay(name.item.clone());
+        }
+
+        for line in contents.lines() {
+            let Some((key, value)) = line.split_once('\t') else {
+                continue;
+            };
+            stack.add_env_var(key.to_string(), Value::string(value, call.head));
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Restore a previously exported overlay's environment",
+            example: r#"overlay import spam --path spam.env"#,
+            result: None,
+        }]
+    }
+}
@@ -1,11 +1,15 @@
 mod command;
+mod export;
 mod hide;
+mod import;
 mod list;
 mod new;
 mod use_;
 
 pub use command::Overlay;
+pub use export::OverlayExport;
 pub use hide::OverlayHide;
+pub use import::OverlayImport;
 pub use list::OverlayList;
 pub use new::OverlayNew;
 pub use use_::OverlayUse;
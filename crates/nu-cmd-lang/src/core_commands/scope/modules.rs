@@ -12,6 +12,11 @@ impl Command for ScopeModules {
         Signature::build("scope modules")
             .input_output_types(vec![(Type::Nothing, Type::Any)])
             .allow_variants_without_examples(true)
+            .optional(
+                "name",
+                SyntaxShape::String,
+                "Only show the module with this name.",
+            )
             .category(Category::Core)
     }
 
@@ -19,6 +24,12 @@ impl Command for ScopeModules {
         "Output info on the modules in the current scope."
     }
 
+    fn extra_description(&self) -> &str {
+        "Each entry includes the module's exported commands, aliases, constants, submodules, \
+        and the file it was loaded from, which is useful for debugging `use` issues. Pass a \
+        name to only show the module with that name."
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -27,20 +38,44 @@ impl Command for ScopeModules {
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let head = call.head;
+        let name: Option<String> = call.opt(engine_state, stack, 0)?;
         let mut scope_data = ScopeData::new(engine_state, stack);
         scope_data.populate_modules();
-        Ok(Value::list(scope_data.collect_modules(head), head).into_pipeline_data())
+        let modules = scope_data.collect_modules(head);
+        let modules = match name {
+            Some(name) => modules
+                .into_iter()
+                .filter(|module| module_name(module) == Some(name.as_str()))
+                .collect(),
+            None => modules,
+        };
+        Ok(Value::list(modules, head).into_pipeline_data())
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Show the modules in the current scope",
-            example: "scope modules",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Show the modules in the current scope",
+                example: "scope modules",
+                result: None,
+            },
+            Example {
+                description: "Show information about a specific module",
+                example: "scope modules foo",
+                result: None,
+            },
+        ]
     }
 }
 
+fn module_name(module: &Value) -> Option<&str> {
+    module
+        .as_record()
+        .ok()
+        .and_then(|record| record.get("name"))
+        .and_then(|name| name.as_str().ok())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
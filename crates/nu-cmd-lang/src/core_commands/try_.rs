@@ -91,6 +91,11 @@ impl Command for Try {
                 example: "try { 1 / 0 } catch { |err| $err.msg }",
                 result: None,
             },
+            Example {
+                description: "Check the last caught error from outside of the catch closure",
+                example: "try { 1 / 0 }; $env.LAST_ERROR.code",
+                result: None,
+            },
         ]
     }
 }
@@ -105,9 +110,11 @@ fn run_catch(
 ) -> Result<PipelineData, ShellError> {
     let error = intercept_block_control(error)?;
 
+    stack.set_last_error(&error);
+    let error = error.into_value(&StateWorkingSet::new(engine_state), span);
+    stack.add_env_var("LAST_ERROR".into(), error.clone());
+
     if let Some(catch) = catch {
-        stack.set_last_error(&error);
-        let error = error.into_value(&StateWorkingSet::new(engine_state), span);
         let block = engine_state.get_block(catch.block_id);
         // Put the error value in the positional closure var
         if let Some(var) = block.signature.get_positional(0) {
@@ -23,6 +23,12 @@ impl Command for While {
                 SyntaxShape::Block,
                 "Block to loop if check succeeds.",
             )
+            .named(
+                "label",
+                SyntaxShape::String,
+                "Label this loop so `break`/`continue` from a nested loop can target it.",
+                Some('l'),
+            )
             .category(Category::Core)
     }
 
@@ -57,6 +63,8 @@ impl Command for While {
             .as_block()
             .expect("internal error: missing block");
 
+        let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+
         let eval_expression = get_eval_expression(engine_state);
         let eval_block = get_eval_block(engine_state);
 
@@ -73,8 +81,16 @@ impl Command for While {
                         let block = engine_state.get_block(block_id);
 
                         match eval_block(engine_state, stack, block, PipelineData::empty()) {
-                            Err(ShellError::Break { .. }) => break,
-                            Err(ShellError::Continue { .. }) => continue,
+                            Err(ShellError::Break { label: l, .. })
+                                if l.is_none() || l == label =>
+                            {
+                                break
+                            }
+                            Err(ShellError::Continue { label: l, .. })
+                                if l.is_none() || l == label =>
+                            {
+                                continue
+                            }
                             Err(err) => return Err(err),
                             Ok(data) => data.drain()?,
                         }
@@ -96,11 +112,18 @@ impl Command for While {
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Loop while a condition is true",
-            example: "mut x = 0; while $x < 10 { $x = $x + 1 }",
-            result: None,
-        }]
+        vec![
+            Example {
+                description: "Loop while a condition is true",
+                example: "mut x = 0; while $x < 10 { $x = $x + 1 }",
+                result: None,
+            },
+            Example {
+                description: "Break out of an outer, labeled while loop from within a nested one",
+                example: "mut x = 0; while $x < 3 --label outer { mut y = 0; while $y < 3 { break outer } }",
+                result: None,
+            },
+        ]
     }
 }
 
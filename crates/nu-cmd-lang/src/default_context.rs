@@ -16,6 +16,9 @@ pub fn create_default_context() -> EngineState {
         // Core
         bind_command! {
             Alias,
+            Assert,
+            AssertEqual,
+            AssertError,
             AttrCategory,
             AttrExample,
             AttrSearchTerms,
@@ -24,10 +27,12 @@ pub fn create_default_context() -> EngineState {
             Const,
             Continue,
             Def,
+            Defer,
             Describe,
             Do,
             Echo,
             ErrorMake,
+            ErrorRethrow,
             ExportAlias,
             ExportCommand,
             ExportConst,
@@ -46,6 +51,8 @@ pub fn create_default_context() -> EngineState {
             OverlayList,
             OverlayNew,
             OverlayHide,
+            OverlayExport,
+            OverlayImport,
             Let,
             Loop,
             Match,
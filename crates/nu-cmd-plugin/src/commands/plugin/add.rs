@@ -118,6 +118,7 @@ apparent the next time `nu` is next launched with that plugin registry file.
             PluginGcConfig {
                 enabled: true,
                 stop_after: 0,
+                restart_on_crash: true,
             },
         ));
         let interface = plugin.clone().get_plugin(Some((engine_state, stack)))?;
@@ -4,6 +4,14 @@ use nu_protocol::{IntoValue, PluginRegistryItemData};
 
 use crate::util::read_plugin_file;
 
+fn restart_on_crash(engine_state: &EngineState, plugin_name: &str) -> bool {
+    engine_state
+        .get_config()
+        .plugin_gc
+        .get(plugin_name)
+        .restart_on_crash
+}
+
 #[derive(Clone)]
 pub struct PluginList;
 
@@ -25,6 +33,7 @@ impl Command for PluginList {
                         ("filename".into(), Type::String),
                         ("shell".into(), Type::String),
                         ("commands".into(), Type::List(Type::String.into())),
+                        ("restart_on_crash".into(), Type::Bool),
                     ]
                     .into(),
                 ),
@@ -74,6 +83,11 @@ or the plugin has not been loaded yet, the values of `version`, `filename`,
 `shell`, and `commands` reflect the values in the engine and not the ones in
 the plugin registry file.
 
+The `restart_on_crash` column reflects the plugin's current effective
+`$env.config.plugin_gc` policy: whether it will be spawned again automatically
+the next time one of its commands is called after its process exits
+unexpectedly.
+
 See also: `plugin use`
 "#
         .trim()
@@ -100,6 +114,7 @@ See also: `plugin use`
                     },
                     "shell" => Value::test_nothing(),
                     "commands" => Value::test_list(vec![Value::test_string("inc")]),
+                    "restart_on_crash" => Value::test_bool(true),
                 })])),
             },
             Example {
@@ -151,6 +166,7 @@ struct PluginInfo {
     filename: String,
     shell: Option<String>,
     commands: Vec<String>,
+    restart_on_crash: bool,
 }
 
 #[derive(Debug, Clone, Copy, IntoValue, PartialOrd, Ord, PartialEq, Eq)]
@@ -199,6 +215,7 @@ fn get_plugins_in_engine(engine_state: &EngineState) -> Vec<PluginInfo> {
                     .shell()
                     .map(|path| path.to_string_lossy().into_owned()),
                 commands,
+                restart_on_crash: restart_on_crash(engine_state, plugin.identity().name()),
             }
         })
         .sorted()
@@ -217,7 +234,9 @@ fn get_plugins_in_registry(
         .plugins
         .into_iter()
         .map(|plugin| {
+            let restart_on_crash = restart_on_crash(engine_state, &plugin.name);
             let mut info = PluginInfo {
+                restart_on_crash,
                 name: plugin.name,
                 version: None,
                 status: PluginStatus::Added,
@@ -2,12 +2,14 @@ use nu_engine::{command_prelude::*, get_full_help};
 
 mod add;
 mod list;
+mod reload;
 mod rm;
 mod stop;
 mod use_;
 
 pub use add::PluginAdd;
 pub use list::PluginList;
+pub use reload::PluginReload;
 pub use rm::PluginRm;
 pub use stop::PluginStop;
 pub use use_::PluginUse;
@@ -67,6 +69,11 @@ time.
                 description: "Stop the plugin named `inc`.",
                 result: None,
             },
+            Example {
+                example: "plugin reload inc",
+                description: "Stop the `inc` plugin and re-fetch its signatures, for development iteration.",
+                result: None,
+            },
             Example {
                 example: "plugin rm inc",
                 description: "Remove the installed signatures for the `inc` plugin.",
@@ -0,0 +1,118 @@
+use crate::util::{canonicalize_possible_filename_arg, modify_plugin_file};
+use nu_engine::command_prelude::*;
+use nu_plugin_engine::{GetPlugin, PersistentPlugin};
+use nu_protocol::{PluginGcConfig, PluginRegistryItem, RegisteredPlugin};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct PluginReload;
+
+impl Command for PluginReload {
+    fn name(&self) -> &str {
+        "plugin reload"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .named(
+                "plugin-config",
+                SyntaxShape::Filepath,
+                "Use a plugin registry file other than the one set in `$nu.plugin-path`",
+                None,
+            )
+            .required(
+                "name",
+                SyntaxShape::String,
+                "The name, or filename, of the plugin to reload.",
+            )
+            .category(Category::Plugin)
+    }
+
+    fn description(&self) -> &str {
+        "Stop a plugin and re-fetch its signatures into the plugin registry file."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"
+This is `plugin stop` and `plugin add` in one step, so a plugin under
+development can be rebuilt and picked back up without retyping its filename.
+
+Like `plugin add`, this does not update the commands already in scope - run
+`plugin use` afterward to bring the reloaded signatures into the current
+session. If the plugin's behavior changed but its signatures didn't, stopping
+it here is enough: the next call respawns it fresh.
+"#
+        .trim()
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["restart", "refresh", "signature"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "plugin reload inc",
+            description: "Stop the `inc` plugin and re-fetch its signatures.",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let name: Spanned<String> = call.req(engine_state, stack, 0)?;
+        let custom_path = call.get_flag(engine_state, stack, "plugin-config")?;
+
+        let filename = canonicalize_possible_filename_arg(engine_state, stack, &name.item);
+
+        let identity = engine_state
+            .plugins()
+            .iter()
+            .find(|plugin| {
+                let id = plugin.identity();
+                id.name() == name.item || id.filename() == filename
+            })
+            .map(|plugin| plugin.identity().clone())
+            .ok_or_else(|| ShellError::GenericError {
+                error: format!("Failed to reload the `{}` plugin", name.item),
+                msg: "couldn't find a plugin with this name".into(),
+                span: Some(name.span),
+                help: Some("you may need to `plugin add` the plugin first".into()),
+                inner: vec![],
+            })?;
+
+        // Stop the currently running instance, if any, before re-querying its signatures.
+        for plugin in engine_state.plugins() {
+            if plugin.identity() == &identity {
+                plugin.stop()?;
+            }
+        }
+
+        // Start a fresh instance to get the latest signatures, the same way `plugin add` does, so
+        // this doesn't disturb the (now stopped) instance tracked by the engine.
+        let plugin = Arc::new(PersistentPlugin::new(
+            identity,
+            PluginGcConfig {
+                enabled: true,
+                stop_after: 0,
+                restart_on_crash: true,
+            },
+        ));
+        let interface = plugin.clone().get_plugin(Some((engine_state, stack)))?;
+        let metadata = interface.get_metadata()?;
+        let commands = interface.get_signature()?;
+
+        modify_plugin_file(engine_state, stack, call.head, &custom_path, |contents| {
+            let item = PluginRegistryItem::new(plugin.identity(), metadata, commands);
+            contents.upsert_plugin(item);
+            Ok(())
+        })?;
+
+        Ok(Value::nothing(call.head).into_pipeline_data())
+    }
+}
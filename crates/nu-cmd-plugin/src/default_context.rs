@@ -15,6 +15,7 @@ pub fn add_plugin_command_context(mut engine_state: EngineState) -> EngineState
             PluginAdd,
             PluginCommand,
             PluginList,
+            PluginReload,
             PluginRm,
             PluginStop,
             PluginUse,
@@ -12,10 +12,12 @@ pub fn default_shape_color(shape: &str) -> Style {
         "shape_custom" => Style::new().fg(Color::Green),
         "shape_datetime" => Style::new().fg(Color::Cyan).bold(),
         "shape_directory" => Style::new().fg(Color::Cyan),
+        "shape_directory_not_found" => Style::new().fg(Color::Red),
         "shape_external" => Style::new().fg(Color::Cyan),
         "shape_externalarg" => Style::new().fg(Color::Green).bold(),
         "shape_external_resolved" => Style::new().fg(Color::LightYellow).bold(),
         "shape_filepath" => Style::new().fg(Color::Cyan),
+        "shape_filepath_not_found" => Style::new().fg(Color::Red),
         "shape_flag" => Style::new().fg(Color::Blue).bold(),
         "shape_float" => Style::new().fg(Color::Purple).bold(),
         "shape_garbage" => Style::new().fg(Color::White).on(Color::Red).bold(),
@@ -1,5 +1,6 @@
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
+use std::io::Read;
 
 struct Arguments {
     pattern: Vec<u8>,
@@ -51,6 +52,11 @@ impl Command for BytesIndexOf {
         "Returns start index of first occurrence of pattern in bytes, or -1 if no match."
     }
 
+    fn extra_description(&self) -> &str {
+        "A single forward search over a byte stream input (the default, without --all or --end) \
+reads only as much of the stream as it takes to find the pattern, without buffering the rest."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["pattern", "match", "find", "search"]
     }
@@ -62,16 +68,46 @@ impl Command for BytesIndexOf {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
         let pattern: Vec<u8> = call.req(engine_state, stack, 0)?;
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 1)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let end = call.has_flag(engine_state, stack, "end")?;
+        let all = call.has_flag(engine_state, stack, "all")?;
+
+        // The forward, single-match case can stop reading as soon as it finds a hit, so it's
+        // handled directly against the stream's reader instead of buffering the whole thing.
+        if !end && !all && !pattern.is_empty() {
+            match input {
+                PipelineData::ByteStream(stream, ..) => {
+                    let span = stream.span();
+                    let Some(reader) = stream.reader() else {
+                        return Ok(Value::int(-1, head).into_pipeline_data());
+                    };
+                    return Ok(
+                        Value::int(streaming_index_of(reader, &pattern, span)?, head)
+                            .into_pipeline_data(),
+                    );
+                }
+                other => {
+                    let arg = Arguments {
+                        pattern,
+                        end,
+                        all,
+                        cell_paths,
+                    };
+                    return operate(index_of, arg, other, head, engine_state.signals());
+                }
+            }
+        }
+
         let arg = Arguments {
             pattern,
-            end: call.has_flag(engine_state, stack, "end")?,
-            all: call.has_flag(engine_state, stack, "all")?,
+            end,
+            all,
             cell_paths,
         };
-        operate(index_of, arg, input, call.head, engine_state.signals())
+        operate(index_of, arg, input, head, engine_state.signals())
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
@@ -160,6 +196,42 @@ fn index_of_impl(input: &[u8], arg: &Arguments, span: Span) -> Value {
     }
 }
 
+/// Scans a reader for `pattern` a chunk at a time, stopping as soon as a match is found instead
+/// of reading the whole stream into memory first.
+fn streaming_index_of(
+    mut reader: impl Read,
+    pattern: &[u8],
+    span: Span,
+) -> Result<i64, ShellError> {
+    use nu_protocol::shell_error::io::IoError;
+
+    // Invariant at the top of the loop: `buf.len() < pattern.len()`, and `base` is the stream
+    // offset of `buf[0]`.
+    let mut buf: Vec<u8> = Vec::new();
+    let mut chunk = [0u8; 8192];
+    let mut base = 0usize;
+
+    loop {
+        let n = reader
+            .read(&mut chunk)
+            .map_err(|err| IoError::new(err.kind(), span, None))?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+
+        while buf.len() >= pattern.len() {
+            if &buf[..pattern.len()] == pattern {
+                return Ok(base as i64);
+            }
+            buf.remove(0);
+            base += 1;
+        }
+    }
+
+    Ok(-1)
+}
+
 fn search_all_index(input: &[u8], pattern: &[u8], from_end: bool, span: Span) -> Value {
     let mut result = vec![];
     if from_end {
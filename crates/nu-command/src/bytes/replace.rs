@@ -1,5 +1,7 @@
 use nu_cmd_base::input_handler::{operate, CmdArgument};
 use nu_engine::command_prelude::*;
+use nu_protocol::shell_error::io::IoError;
+use std::io::Read;
 
 struct Arguments {
     find: Vec<u8>,
@@ -45,6 +47,11 @@ impl Command for BytesReplace {
         "Find and replace binary."
     }
 
+    fn extra_description(&self) -> &str {
+        "For a plain byte stream input (not a table or record), the replacement is streamed: \
+input is read and written out a chunk at a time instead of being buffered into memory in full."
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["search", "shift", "switch"]
     }
@@ -56,6 +63,7 @@ impl Command for BytesReplace {
         call: &Call,
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
         let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 2)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
         let find = call.req::<Spanned<Vec<u8>>>(engine_state, stack, 0)?;
@@ -65,15 +73,85 @@ impl Command for BytesReplace {
                 span: find.span,
             });
         }
+        let replace_with = call.req::<Vec<u8>>(engine_state, stack, 1)?;
+        let all = call.has_flag(engine_state, stack, "all")?;
+
+        let input = if cell_paths.is_none() {
+            match input {
+                PipelineData::ByteStream(stream, ..) => {
+                    let signals = engine_state.signals().clone();
+                    let type_ = stream.type_();
+                    let Some(mut reader) = stream.reader() else {
+                        return Ok(PipelineData::empty());
+                    };
+                    let find = find.item;
+                    let mut pending: Vec<u8> = Vec::new();
+                    let mut chunk = [0u8; 8192];
+                    let mut done_replacing = false;
+                    let mut eof = false;
+
+                    let output = ByteStream::from_fn(head, signals, type_, move |out| {
+                        if eof && pending.is_empty() {
+                            return Ok(false);
+                        }
+
+                        if !eof {
+                            let n = reader
+                                .read(&mut chunk)
+                                .map_err(|err| IoError::new(err.kind(), head, None))?;
+                            if n == 0 {
+                                eof = true;
+                            } else {
+                                pending.extend_from_slice(&chunk[..n]);
+                            }
+                        }
+
+                        // Only search/emit up to a point that still leaves enough of a tail
+                        // behind to detect a match spanning a chunk boundary, unless this is
+                        // the last read.
+                        let searchable = if eof {
+                            pending.len()
+                        } else {
+                            pending.len().saturating_sub(find.len().saturating_sub(1))
+                        };
+
+                        let mut i = 0;
+                        while i < searchable {
+                            let is_match = !done_replacing
+                                && pending.len() - i >= find.len()
+                                && pending[i..i + find.len()] == find[..];
+                            if is_match {
+                                out.extend_from_slice(&replace_with);
+                                i += find.len();
+                                if !all {
+                                    done_replacing = true;
+                                }
+                            } else {
+                                out.push(pending[i]);
+                                i += 1;
+                            }
+                        }
+                        pending.drain(..i);
+
+                        Ok(!(eof && pending.is_empty()))
+                    });
+
+                    return Ok(PipelineData::ByteStream(output, None).with_span(head));
+                }
+                other => other,
+            }
+        } else {
+            input
+        };
 
         let arg = Arguments {
             find: find.item,
-            replace: call.req::<Vec<u8>>(engine_state, stack, 1)?,
+            replace: replace_with,
             cell_paths,
-            all: call.has_flag(engine_state, stack, "all")?,
+            all,
         };
 
-        operate(replace, arg, input, call.head, engine_state.signals())
+        operate(replace, arg, input, head, engine_state.signals())
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
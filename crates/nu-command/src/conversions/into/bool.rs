@@ -43,6 +43,10 @@ impl Command for IntoBool {
         vec!["convert", "boolean", "true", "false", "1", "0"]
     }
 
+    fn is_const(&self) -> bool {
+        true
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -56,6 +60,30 @@ impl Command for IntoBool {
         into_bool(engine_state, stack, call, input, relaxed)
     }
 
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let relaxed = call
+            .has_flag_const(working_set, "relaxed")
+            .unwrap_or(false);
+        let cell_paths: Vec<CellPath> = call.rest_const(working_set, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+        let args = IntoBoolCmdArgument {
+            cell_paths,
+            relaxed,
+        };
+        operate(
+            action,
+            args,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
+    }
+
     fn examples(&'_ self) -> Vec<Example<'_>> {
         vec![
             Example {
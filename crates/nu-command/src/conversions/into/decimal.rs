@@ -0,0 +1,470 @@
+use nu_cmd_base::input_handler::{operate, CellPathOnlyArgs};
+use nu_engine::command_prelude::*;
+use nu_protocol::{
+    ast::{Math, Operator},
+    CustomValue,
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A fixed-point decimal, stored as an integer `mantissa` scaled by `10^-scale`.
+///
+/// Unlike `Value::Float`, arithmetic on `NuDecimal` never rounds to the nearest binary
+/// fraction, so it doesn't accumulate the surprising rounding error IEEE-754 floats do for
+/// values like money. It's bounded by `i128`, not truly arbitrary-precision (pulling in a
+/// bignum crate for that is a bigger change than this conversion command warrants), which is
+/// still plenty of range for anything measured in cents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NuDecimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// The largest scale any `NuDecimal` is allowed to carry.
+///
+/// `rescaled` multiplies by `10^(scale - self.scale)`, which fits comfortably in an `i128` for
+/// scales in this range but overflows well before `u32::MAX`. Without a cap, `Math::Multiply`
+/// (which sums the two operands' scales) can reach an overflowing scale in well under 40 chained
+/// multiplications on ordinary input, so every operation that would grow the scale rounds it back
+/// down to this instead of letting it grow unbounded.
+const MAX_SCALE: u32 = 30;
+
+impl NuDecimal {
+    pub fn new(mantissa: i128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Round `mantissa` (at `scale`) down to `target_scale`, if it's larger. Rounds half away
+    /// from zero. Returns `None` only on the pathological case of an `i128` overflow while
+    /// computing the rounding divisor.
+    fn round_to_scale(mantissa: i128, scale: u32, target_scale: u32) -> Option<(i128, u32)> {
+        let Some(drop) = scale.checked_sub(target_scale) else {
+            return Some((mantissa, scale));
+        };
+        let divisor = 10i128.checked_pow(drop)?;
+        let half = divisor / 2;
+        let rounded = if mantissa >= 0 {
+            mantissa.checked_add(half)?.checked_div(divisor)?
+        } else {
+            mantissa.checked_sub(half)?.checked_div(divisor)?
+        };
+        Some((rounded, target_scale))
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let negative = s.starts_with('-');
+        let unsigned = s.strip_prefix(['-', '+']).unwrap_or(s);
+
+        let (int_part, frac_part) = match unsigned.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (unsigned, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        if !int_part.bytes().all(|b| b.is_ascii_digit())
+            || !frac_part.bytes().all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let digits = format!("{int_part}{frac_part}");
+        let mantissa: i128 = if digits.is_empty() {
+            0
+        } else {
+            digits.parse().ok()?
+        };
+        let mantissa = if negative { -mantissa } else { mantissa };
+
+        Some(Self {
+            mantissa,
+            scale: frac_part.len() as u32,
+        })
+    }
+
+    fn rescaled(self, scale: u32) -> Option<i128> {
+        let diff = scale.checked_sub(self.scale)?;
+        let factor = 10i128.checked_pow(diff)?;
+        self.mantissa.checked_mul(factor)
+    }
+
+    fn common_scale(self, other: Self) -> u32 {
+        self.scale.max(other.scale)
+    }
+}
+
+impl fmt::Display for NuDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.mantissa);
+        }
+        let magnitude = self.mantissa.unsigned_abs();
+        let digits = format!("{:0>width$}", magnitude, width = self.scale as usize + 1);
+        let split_at = digits.len() - self.scale as usize;
+        if self.mantissa < 0 {
+            write!(f, "-")?;
+        }
+        write!(f, "{}.{}", &digits[..split_at], &digits[split_at..])
+    }
+}
+
+impl CustomValue for NuDecimal {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(*self), span)
+    }
+
+    fn type_name(&self) -> String {
+        "decimal".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        // A base `Value` has no exact-decimal representation, so preserve precision by going
+        // through the display form rather than lossily converting to `Value::Float`.
+        Ok(Value::string(self.to_string(), span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn partial_cmp(&self, other: &Value) -> Option<Ordering> {
+        let other = other.as_custom_value().ok()?.as_any().downcast_ref::<Self>()?;
+        let scale = self.common_scale(*other);
+        self.rescaled(scale)?.partial_cmp(&other.rescaled(scale)?)
+    }
+
+    fn operation(
+        &self,
+        lhs_span: Span,
+        operator: Operator,
+        op: Span,
+        right: &Value,
+    ) -> Result<Value, ShellError> {
+        let unsupported = || {
+            Err(ShellError::OperatorUnsupportedType {
+                op: operator,
+                unsupported: right.get_type(),
+                op_span: op,
+                unsupported_span: right.span(),
+                help: Some("expected another decimal, e.g. `1.5 | into decimal`"),
+            })
+        };
+
+        let Operator::Math(math) = operator else {
+            return unsupported();
+        };
+
+        let rhs = match right {
+            Value::Custom { val, .. } => match val.as_any().downcast_ref::<Self>() {
+                Some(rhs) => *rhs,
+                None => return unsupported(),
+            },
+            Value::Int { val, .. } => Self::new(*val as i128, 0),
+            _ => return unsupported(),
+        };
+
+        let span = lhs_span;
+        match math {
+            Math::Add | Math::Subtract => {
+                let scale = self.common_scale(rhs);
+                let (Some(lhs), Some(rhs)) = (self.rescaled(scale), rhs.rescaled(scale)) else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                let result = if matches!(math, Math::Add) {
+                    lhs.checked_add(rhs)
+                } else {
+                    lhs.checked_sub(rhs)
+                };
+                let Some(mantissa) = result else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                Ok(Value::custom(Box::new(Self::new(mantissa, scale)), span))
+            }
+            Math::Multiply => {
+                let Some(product) = self.mantissa.checked_mul(rhs.mantissa) else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                let raw_scale = self.scale + rhs.scale;
+                let Some((mantissa, scale)) = Self::round_to_scale(product, raw_scale, MAX_SCALE)
+                else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                Ok(Value::custom(Box::new(Self::new(mantissa, scale)), span))
+            }
+            Math::Divide => {
+                if rhs.mantissa == 0 {
+                    return Err(ShellError::DivisionByZero { span: op });
+                }
+                // Carry a few extra fractional digits through the division so exact results
+                // (e.g. 1 / 4) don't lose precision to integer truncation, then round back down
+                // to MAX_SCALE like every other operation so scale never grows without bound.
+                const EXTRA_SCALE: u32 = 8;
+                let raw_scale = self.common_scale(rhs) + EXTRA_SCALE;
+                let Some(exponent) = (raw_scale + rhs.scale).checked_sub(self.scale) else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                let Some(numerator) = 10i128
+                    .checked_pow(exponent)
+                    .and_then(|factor| self.mantissa.checked_mul(factor))
+                else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                let mantissa = numerator / rhs.mantissa;
+                let Some((mantissa, scale)) = Self::round_to_scale(mantissa, raw_scale, MAX_SCALE)
+                else {
+                    return Err(ShellError::OperatorOverflow {
+                        msg: "decimal operation overflowed".into(),
+                        span,
+                        help: None,
+                    });
+                };
+                Ok(Value::custom(Box::new(Self::new(mantissa, scale)), span))
+            }
+            _ => unsupported(),
+        }
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "NuDecimal"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
+
+#[derive(Clone)]
+pub struct IntoDecimal;
+
+impl Command for IntoDecimal {
+    fn name(&self) -> &str {
+        "into decimal"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("into decimal")
+            .input_output_types(vec![
+                (Type::String, Type::Custom("decimal".into())),
+                (Type::Int, Type::Custom("decimal".into())),
+                (Type::Float, Type::Custom("decimal".into())),
+                (Type::Custom("decimal".into()), Type::Custom("decimal".into())),
+            ])
+            .rest(
+                "rest",
+                SyntaxShape::CellPath,
+                "For a data structure input, convert data at the given cell paths.",
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Convert data into an exact, base-10 decimal value."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Unlike `into float`, arithmetic on the result doesn't round to the nearest binary \
+            fraction, so it's a better fit for money and other values where base-10 rounding \
+            matters. It's still backed by a fixed-size integer rather than a true arbitrary- \
+            precision bignum."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["convert", "number", "money", "float", "precise"]
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest(engine_state, stack, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(action, args, input, call.head, engine_state.signals())
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest_const(working_set, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(
+            action,
+            args,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Convert a string to a decimal",
+                example: "'19.99' | into decimal",
+                result: None,
+            },
+            Example {
+                description: "Add two decimals without floating point rounding error",
+                example: "(0.1 | into decimal) + (0.2 | into decimal)",
+                result: None,
+            },
+        ]
+    }
+}
+
+fn action(input: &Value, _args: &CellPathOnlyArgs, head: Span) -> Value {
+    let span = input.span();
+    match input {
+        Value::Custom { val, .. } if val.as_any().downcast_ref::<NuDecimal>().is_some() => {
+            input.clone()
+        }
+        Value::String { val, .. } => match NuDecimal::from_str(val) {
+            Some(decimal) => Value::custom(Box::new(decimal), span),
+            None => Value::error(
+                ShellError::CantConvert {
+                    to_type: "decimal".into(),
+                    from_type: "string".into(),
+                    span,
+                    help: None,
+                },
+                span,
+            ),
+        },
+        Value::Int { val, .. } => Value::custom(Box::new(NuDecimal::new(*val as i128, 0)), span),
+        Value::Float { val, .. } => match NuDecimal::from_str(&val.to_string()) {
+            Some(decimal) => Value::custom(Box::new(decimal), span),
+            None => Value::error(
+                ShellError::CantConvert {
+                    to_type: "decimal".into(),
+                    from_type: "float".into(),
+                    span,
+                    help: None,
+                },
+                span,
+            ),
+        },
+        Value::Error { .. } => input.clone(),
+        other => Value::error(
+            ShellError::OnlySupportsThisInputType {
+                exp_input_type: "string, int or float".into(),
+                wrong_type: other.get_type().to_string(),
+                dst_span: head,
+                src_span: other.span(),
+            },
+            head,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(IntoDecimal {})
+    }
+
+    #[test]
+    fn parses_and_displays_roundtrip() {
+        for s in ["0", "1.5", "-1.50", "100", "-0.001"] {
+            let decimal = NuDecimal::from_str(s).expect("should parse");
+            assert_eq!(decimal.to_string(), s.trim_start_matches('+'));
+        }
+    }
+
+    #[test]
+    fn add_avoids_float_rounding_error() {
+        let a = NuDecimal::from_str("0.1").unwrap();
+        let b = NuDecimal::from_str("0.2").unwrap();
+        let result = a
+            .operation(
+                Span::test_data(),
+                Operator::Math(Math::Add),
+                Span::test_data(),
+                &Value::custom(Box::new(b), Span::test_data()),
+            )
+            .expect("addition should succeed");
+        let Value::Custom { val, .. } = result else {
+            panic!("expected a decimal result");
+        };
+        let sum = val.as_any().downcast_ref::<NuDecimal>().unwrap();
+        assert_eq!(sum.to_string(), "0.3");
+    }
+
+    #[test]
+    fn multiply_caps_scale_growth_instead_of_overflowing() {
+        let mut acc = NuDecimal::from_str("1.1").unwrap();
+        let factor = NuDecimal::from_str("1.1").unwrap();
+        for _ in 0..60 {
+            let result = acc
+                .operation(
+                    Span::test_data(),
+                    Operator::Math(Math::Multiply),
+                    Span::test_data(),
+                    &Value::custom(Box::new(factor), Span::test_data()),
+                )
+                .expect("repeated multiplication should not overflow");
+            let Value::Custom { val, .. } = result else {
+                panic!("expected a decimal result");
+            };
+            acc = *val.as_any().downcast_ref::<NuDecimal>().unwrap();
+            assert!(acc.scale <= MAX_SCALE);
+        }
+    }
+
+    #[test]
+    fn divide_by_zero_errors() {
+        let a = NuDecimal::from_str("1").unwrap();
+        let b = NuDecimal::from_str("0").unwrap();
+        let result = a.operation(
+            Span::test_data(),
+            Operator::Math(Math::Divide),
+            Span::test_data(),
+            &Value::custom(Box::new(b), Span::test_data()),
+        );
+        assert!(matches!(result, Err(ShellError::DivisionByZero { .. })));
+    }
+}
@@ -40,6 +40,10 @@ impl Command for IntoFloat {
         vec!["convert", "number", "floating", "decimal"]
     }
 
+    fn is_const(&self) -> bool {
+        true
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -52,6 +56,23 @@ impl Command for IntoFloat {
         operate(action, args, input, call.head, engine_state.signals())
     }
 
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest_const(working_set, 0)?;
+        let args = CellPathOnlyArgs::from(cell_paths);
+        operate(
+            action,
+            args,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
+    }
+
     fn examples(&'_ self) -> Vec<Example<'_>> {
         vec![
             Example {
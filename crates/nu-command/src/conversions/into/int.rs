@@ -97,6 +97,10 @@ impl Command for IntoInt {
         vec!["convert", "number", "natural"]
     }
 
+    fn is_const(&self) -> bool {
+        true
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -107,50 +111,31 @@ impl Command for IntoInt {
         let cell_paths = call.rest(engine_state, stack, 0)?;
         let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
 
-        let radix = call.get_flag::<Value>(engine_state, stack, "radix")?;
-        let radix: u32 = match radix {
-            Some(val) => {
-                let span = val.span();
-                match val {
-                    Value::Int { val, .. } => {
-                        if !(2..=36).contains(&val) {
-                            return Err(ShellError::TypeMismatch {
-                                err_message: "Radix must lie in the range [2, 36]".to_string(),
-                                span,
-                            });
-                        }
-                        val as u32
-                    }
-                    _ => 10,
-                }
-            }
-            None => 10,
-        };
+        let radix = parse_radix(call.get_flag::<Value>(engine_state, stack, "radix")?)?;
+        let little_endian = parse_endian(call.get_flag::<Value>(engine_state, stack, "endian")?)?;
+        let signed = call.has_flag(engine_state, stack, "signed")?;
 
-        let endian = call.get_flag::<Value>(engine_state, stack, "endian")?;
-        let little_endian = match endian {
-            Some(val) => {
-                let span = val.span();
-                match val {
-                    Value::String { val, .. } => match val.as_str() {
-                        "native" => cfg!(target_endian = "little"),
-                        "little" => true,
-                        "big" => false,
-                        _ => {
-                            return Err(ShellError::TypeMismatch {
-                                err_message: "Endian must be one of native, little, big"
-                                    .to_string(),
-                                span,
-                            })
-                        }
-                    },
-                    _ => false,
-                }
-            }
-            None => cfg!(target_endian = "little"),
+        let args = Arguments {
+            radix,
+            little_endian,
+            signed,
+            cell_paths,
         };
+        operate(action, args, input, call.head, engine_state.signals())
+    }
 
-        let signed = call.has_flag(engine_state, stack, "signed")?;
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let cell_paths: Vec<CellPath> = call.rest_const(working_set, 0)?;
+        let cell_paths = (!cell_paths.is_empty()).then_some(cell_paths);
+
+        let radix = parse_radix(call.get_flag_const::<Value>(working_set, "radix")?)?;
+        let little_endian = parse_endian(call.get_flag_const::<Value>(working_set, "endian")?)?;
+        let signed = call.has_flag_const(working_set, "signed")?;
 
         let args = Arguments {
             radix,
@@ -158,7 +143,13 @@ impl Command for IntoInt {
             signed,
             cell_paths,
         };
-        operate(action, args, input, call.head, engine_state.signals())
+        operate(
+            action,
+            args,
+            input,
+            call.head,
+            working_set.permanent().signals(),
+        )
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
@@ -240,6 +231,48 @@ impl Command for IntoInt {
     }
 }
 
+fn parse_radix(radix: Option<Value>) -> Result<u32, ShellError> {
+    match radix {
+        Some(val) => {
+            let span = val.span();
+            match val {
+                Value::Int { val, .. } => {
+                    if !(2..=36).contains(&val) {
+                        return Err(ShellError::TypeMismatch {
+                            err_message: "Radix must lie in the range [2, 36]".to_string(),
+                            span,
+                        });
+                    }
+                    Ok(val as u32)
+                }
+                _ => Ok(10),
+            }
+        }
+        None => Ok(10),
+    }
+}
+
+fn parse_endian(endian: Option<Value>) -> Result<bool, ShellError> {
+    match endian {
+        Some(val) => {
+            let span = val.span();
+            match val {
+                Value::String { val, .. } => match val.as_str() {
+                    "native" => Ok(cfg!(target_endian = "little")),
+                    "little" => Ok(true),
+                    "big" => Ok(false),
+                    _ => Err(ShellError::TypeMismatch {
+                        err_message: "Endian must be one of native, little, big".to_string(),
+                        span,
+                    }),
+                },
+                _ => Ok(false),
+            }
+        }
+        None => Ok(cfg!(target_endian = "little")),
+    }
+}
+
 fn action(input: &Value, args: &Arguments, span: Span) -> Value {
     let radix = args.radix;
     let signed = args.signed;
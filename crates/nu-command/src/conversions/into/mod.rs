@@ -3,6 +3,7 @@ mod bool;
 mod cell_path;
 mod command;
 mod datetime;
+mod decimal;
 mod duration;
 mod filesize;
 mod float;
@@ -17,6 +18,7 @@ pub use bool::IntoBool;
 pub use cell_path::IntoCellPath;
 pub use command::Into;
 pub use datetime::IntoDatetime;
+pub use decimal::IntoDecimal;
 pub use duration::IntoDuration;
 pub use filesize::IntoFilesize;
 pub use float::IntoFloat;
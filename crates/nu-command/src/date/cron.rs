@@ -0,0 +1,405 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Timelike};
+use nu_engine::{command_prelude::*, get_full_help};
+
+/// A parsed standard 5-field cron expression: minute hour day-of-month month day-of-week.
+struct CronSchedule {
+    minute: Vec<u32>,
+    hour: Vec<u32>,
+    day_of_month: Vec<u32>,
+    day_of_month_is_wildcard: bool,
+    month: Vec<u32>,
+    day_of_week: Vec<u32>,
+    day_of_week_is_wildcard: bool,
+}
+
+impl CronSchedule {
+    fn parse(expr: &str, span: Span) -> Result<CronSchedule, ShellError> {
+        let fields: Vec<&str> = expr.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(ShellError::GenericError {
+                error: "Invalid cron expression".into(),
+                msg: format!(
+                    "expected 5 space-separated fields (minute hour day-of-month month day-of-week), got {}",
+                    fields.len()
+                ),
+                span: Some(span),
+                help: Some("example: \"*/15 9-17 * * mon-fri\"".into()),
+                inner: vec![],
+            });
+        }
+
+        Ok(CronSchedule {
+            minute: parse_field(fields[0], 0, 59, &[], span)?,
+            hour: parse_field(fields[1], 0, 23, &[], span)?,
+            day_of_month: parse_field(fields[2], 1, 31, &[], span)?,
+            day_of_month_is_wildcard: fields[2] == "*",
+            month: parse_field(fields[3], 1, 12, &MONTH_NAMES, span)?,
+            day_of_week: parse_field(fields[4], 0, 6, &DAY_NAMES, span)?,
+            day_of_week_is_wildcard: fields[4] == "*",
+        })
+    }
+
+    /// Whether `dt` matches this schedule, per crontab(5) semantics: all fields must
+    /// match *except* day-of-month and day-of-week, which are OR'd together whenever
+    /// both are restricted (i.e. neither is `*`). This lets `0 9 1 * mon` mean "the 1st
+    /// of the month, or any Monday", rather than requiring both simultaneously.
+    fn matches(&self, dt: &DateTime<FixedOffset>) -> bool {
+        let day_matches = if !self.day_of_month_is_wildcard && !self.day_of_week_is_wildcard {
+            self.day_of_month.contains(&dt.day())
+                || self
+                    .day_of_week
+                    .contains(&dt.weekday().num_days_from_sunday())
+        } else {
+            self.day_of_month.contains(&dt.day())
+                && self
+                    .day_of_week
+                    .contains(&dt.weekday().num_days_from_sunday())
+        };
+
+        self.minute.contains(&dt.minute())
+            && self.hour.contains(&dt.hour())
+            && self.month.contains(&dt.month())
+            && day_matches
+    }
+
+    /// Find the next `count` times (strictly after `from`) that satisfy this schedule.
+    fn next(&self, from: DateTime<FixedOffset>, count: usize) -> Vec<DateTime<FixedOffset>> {
+        let mut current = from
+            .with_second(0)
+            .and_then(|dt| dt.with_nanosecond(0))
+            .unwrap_or(from)
+            + Duration::minutes(1);
+
+        let mut results = Vec::with_capacity(count);
+        // A schedule that never matches (e.g. Feb 30) would loop forever; bound the search.
+        let mut steps_left = 60 * 24 * 366 * 5;
+
+        while results.len() < count && steps_left > 0 {
+            if self.matches(&current) {
+                results.push(current);
+            }
+            current += Duration::minutes(1);
+            steps_left -= 1;
+        }
+
+        results
+    }
+}
+
+/// Three-letter names for the month field, in `jan`-`dec` order; index `i` maps to month `i + 1`.
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// Three-letter names for the day-of-week field, in `sun`-`sat` order; index `i` maps to `i`
+/// (0 = Sunday), matching `Datelike::weekday().num_days_from_sunday()`.
+const DAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// Parse a single cron field, which may be `*`, a number, a range (`a-b`), a step (`*/n` or
+/// `a-b/n`), or a comma-separated list of any of the above. `names`, when non-empty, also
+/// accepts case-insensitive three-letter names (e.g. `mon`, `jan`) in place of numbers, with
+/// `names[i]` mapping to the value `i + min`.
+fn parse_field(
+    field: &str,
+    min: u32,
+    max: u32,
+    names: &[&str],
+    span: Span,
+) -> Result<Vec<u32>, ShellError> {
+    let mut values = Vec::new();
+
+    for part in field.split(',') {
+        let (range_part, step) = match part.split_once('/') {
+            Some((range_part, step)) => (
+                range_part,
+                step.parse::<u32>()
+                    .map_err(|_| invalid_field(field, span))?,
+            ),
+            None => (part, 1),
+        };
+
+        let (start, end) = if range_part == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range_part.split_once('-') {
+            (
+                parse_component(start, min, names, span)?,
+                parse_component(end, min, names, span)?,
+            )
+        } else {
+            let value = parse_component(range_part, min, names, span)?;
+            (value, value)
+        };
+
+        if start < min || end > max || start > end || step == 0 {
+            return Err(invalid_field(field, span));
+        }
+
+        let mut value = start;
+        while value <= end {
+            values.push(value);
+            value += step;
+        }
+    }
+
+    values.sort_unstable();
+    values.dedup();
+
+    if values.is_empty() {
+        Err(invalid_field(field, span))
+    } else {
+        Ok(values)
+    }
+}
+
+fn parse_component(s: &str, min: u32, names: &[&str], span: Span) -> Result<u32, ShellError> {
+    if let Some(idx) = names.iter().position(|name| name.eq_ignore_ascii_case(s)) {
+        return Ok(idx as u32 + min);
+    }
+    s.parse::<u32>().map_err(|_| invalid_field(s, span))
+}
+
+fn invalid_field(field: &str, span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Invalid cron field".into(),
+        msg: format!("could not parse cron field `{field}`"),
+        span: Some(span),
+        help: Some("fields accept `*`, a number, `a-b`, `*/n`, `a-b/n`, or a comma-separated list of these".into()),
+        inner: vec![],
+    }
+}
+
+#[derive(Clone)]
+pub struct Cron;
+
+impl Command for Cron {
+    fn name(&self) -> &str {
+        "cron"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cron")
+            .category(Category::Date)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn description(&self) -> &str {
+        "Cron expression commands."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["schedule", "crontab", "recurring"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct CronNext;
+
+impl Command for CronNext {
+    fn name(&self) -> &str {
+        "cron next"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cron next")
+            .required("expression", SyntaxShape::String, "the cron expression")
+            .named(
+                "count",
+                SyntaxShape::Int,
+                "how many upcoming run times to return",
+                Some('c'),
+            )
+            .named(
+                "from",
+                SyntaxShape::DateTime,
+                "compute upcoming run times after this date instead of now",
+                Some('f'),
+            )
+            .input_output_types(vec![(Type::Nothing, Type::List(Box::new(Type::Date)))])
+            .category(Category::Date)
+    }
+
+    fn description(&self) -> &str {
+        "Expand a cron expression into its upcoming run times."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Accepts standard 5-field cron syntax: minute hour day-of-month month day-of-week. \
+Each field may be `*`, a number, a range (`a-b`), a step (`*/n` or `a-b/n`), or a \
+comma-separated list of these. Day-of-week is 0-6 with 0 = Sunday; month and day-of-week \
+also accept three-letter names (`jan`, `mon`, ...). As in crontab(5), if both day-of-month \
+and day-of-week are restricted (neither is `*`), a date matches when it satisfies either one."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["schedule", "crontab", "recurring"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let expr: String = call.req(engine_state, stack, 0)?;
+        let count: Option<i64> = call.get_flag(engine_state, stack, "count")?;
+        let count = count.unwrap_or(5).max(0) as usize;
+        let from: Option<Value> = call.get_flag(engine_state, stack, "from")?;
+        let from = match from {
+            Some(value) => value.as_date()?,
+            None => {
+                let now = chrono::Local::now();
+                now.with_timezone(now.offset())
+            }
+        };
+
+        let schedule = CronSchedule::parse(&expr, head)?;
+        let times = schedule
+            .next(from, count)
+            .into_iter()
+            .map(|dt| Value::date(dt, head))
+            .collect::<Vec<_>>();
+
+        Ok(Value::list(times, head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Show the next 3 times a job scheduled for 9am on weekdays would run",
+            example: "cron next '0 9 * * mon-fri' --count 3",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct CronMatch;
+
+impl Command for CronMatch {
+    fn name(&self) -> &str {
+        "cron match"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("cron match")
+            .required("expression", SyntaxShape::String, "the cron expression")
+            .required(
+                "datetime",
+                SyntaxShape::DateTime,
+                "the date to test against the expression",
+            )
+            .input_output_types(vec![(Type::Nothing, Type::Bool)])
+            .category(Category::Date)
+    }
+
+    fn description(&self) -> &str {
+        "Check whether a date matches a cron expression."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["schedule", "crontab", "recurring"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let expr: String = call.req(engine_state, stack, 0)?;
+        let datetime: Value = call.req(engine_state, stack, 1)?;
+        let dt = datetime.as_date()?;
+
+        let schedule = CronSchedule::parse(&expr, head)?;
+        Ok(Value::bool(schedule.matches(&dt), head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Check whether right now falls on the hour",
+            example: "cron match '0 * * * *' (date now)",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use nu_protocol::Span;
+
+    fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(0)
+            .unwrap()
+            .with_ymd_and_hms(y, m, d, h, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_wildcard_field() {
+        let values = parse_field("*", 0, 4, &[], Span::test_data()).unwrap();
+        assert_eq!(values, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn parses_step_field() {
+        let values = parse_field("*/15", 0, 59, &[], Span::test_data()).unwrap();
+        assert_eq!(values, vec![0, 15, 30, 45]);
+    }
+
+    #[test]
+    fn parses_list_and_range_field() {
+        let values = parse_field("1,3,5-7", 0, 10, &[], Span::test_data()).unwrap();
+        assert_eq!(values, vec![1, 3, 5, 6, 7]);
+    }
+
+    #[test]
+    fn rejects_out_of_range_field() {
+        assert!(parse_field("60", 0, 59, &[], Span::test_data()).is_err());
+    }
+
+    #[test]
+    fn finds_next_matching_minute() {
+        let schedule = CronSchedule::parse("30 9 * * *", Span::test_data()).unwrap();
+        let from = dt(2024, 1, 1, 9, 0);
+        let next = schedule.next(from, 2);
+        assert_eq!(next[0], dt(2024, 1, 1, 9, 30));
+        assert_eq!(next[1], dt(2024, 1, 2, 9, 30));
+    }
+
+    #[test]
+    fn matches_checks_all_fields() {
+        let schedule = CronSchedule::parse("0 9 * * mon-fri", Span::test_data()).unwrap();
+        assert!(schedule.matches(&dt(2024, 1, 1, 9, 0))); // Monday
+        assert!(!schedule.matches(&dt(2024, 1, 6, 9, 0))); // Saturday
+    }
+
+    #[test]
+    fn matches_ors_day_of_month_and_day_of_week_when_both_restricted() {
+        // "the 1st of the month, or any Monday" -- crontab(5) semantics OR these two
+        // fields together when both are restricted, rather than ANDing them.
+        let schedule = CronSchedule::parse("0 9 1 * mon", Span::test_data()).unwrap();
+        assert!(schedule.matches(&dt(2024, 1, 1, 9, 0))); // 1st, a Monday: matches both
+        assert!(schedule.matches(&dt(2024, 1, 8, 9, 0))); // not the 1st, but a Monday
+        assert!(schedule.matches(&dt(2024, 2, 1, 9, 0))); // the 1st, but not a Monday
+        assert!(!schedule.matches(&dt(2024, 1, 2, 9, 0))); // neither
+    }
+}
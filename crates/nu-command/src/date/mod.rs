@@ -1,3 +1,4 @@
+mod cron;
 mod date_;
 mod from_human;
 mod humanize;
@@ -7,6 +8,7 @@ mod parser;
 mod to_timezone;
 mod utils;
 
+pub use cron::{Cron, CronMatch, CronNext};
 pub use date_::Date;
 pub use from_human::DateFromHuman;
 pub use humanize::DateHumanize;
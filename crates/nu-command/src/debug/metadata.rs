@@ -97,11 +97,18 @@ impl Command for Metadata {
                             "source",
                             Value::string(path.to_string_lossy().to_string(), head),
                         ),
+                        PipelineMetadata {
+                            data_source: DataSource::Url(url),
+                            ..
+                        } => record.push("source", Value::string(url.clone(), head)),
                         _ => {}
                     }
                     if let Some(ref content_type) = x.content_type {
                         record.push("content_type", Value::string(content_type, head));
                     }
+                    if let Some(ref custom) = x.custom {
+                        record.push("custom", Value::record(custom.clone(), head));
+                    }
                 }
 
                 Ok(Value::record(record, head).into_pipeline_data())
@@ -157,11 +164,18 @@ fn build_metadata_record(arg: &Value, metadata: Option<&PipelineMetadata>, head:
                 "source",
                 Value::string(path.to_string_lossy().to_string(), head),
             ),
+            PipelineMetadata {
+                data_source: DataSource::Url(url),
+                ..
+            } => record.push("source", Value::string(url.clone(), head)),
             _ => {}
         }
         if let Some(ref content_type) = x.content_type {
             record.push("content_type", Value::string(content_type, head));
         }
+        if let Some(ref custom) = x.custom {
+            record.push("custom", Value::record(custom.clone(), head));
+        }
     }
 
     Value::record(record, head)
@@ -84,11 +84,18 @@ fn build_metadata_record(metadata: Option<&PipelineMetadata>, head: Span) -> Val
                 "source",
                 Value::string(path.to_string_lossy().to_string(), head),
             ),
+            PipelineMetadata {
+                data_source: DataSource::Url(url),
+                ..
+            } => record.push("source", Value::string(url.clone(), head)),
             _ => {}
         }
         if let Some(ref content_type) = x.content_type {
             record.push("content_type", Value::string(content_type, head));
         }
+        if let Some(ref custom) = x.custom {
+            record.push("custom", Value::record(custom.clone(), head));
+        }
     }
 
     Value::record(record, head)
@@ -33,6 +33,12 @@ impl Command for MetadataSet {
                 "Assign content type metadata to the input",
                 Some('c'),
             )
+            .named(
+                "custom",
+                SyntaxShape::Record(vec![]),
+                "Merge a record of user-defined metadata into the input",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Debug)
     }
@@ -48,6 +54,7 @@ impl Command for MetadataSet {
         let ds_fp: Option<String> = call.get_flag(engine_state, stack, "datasource-filepath")?;
         let ds_ls = call.has_flag(engine_state, stack, "datasource-ls")?;
         let content_type: Option<String> = call.get_flag(engine_state, stack, "content-type")?;
+        let custom: Option<Record> = call.get_flag(engine_state, stack, "custom")?;
 
         let mut metadata = match &mut input {
             PipelineData::Value(_, metadata)
@@ -60,6 +67,13 @@ impl Command for MetadataSet {
             metadata.content_type = Some(content_type);
         }
 
+        if let Some(custom) = custom {
+            let existing = metadata.custom.get_or_insert_with(Record::new);
+            for (col, val) in custom {
+                existing.insert(col, val);
+            }
+        }
+
         match (ds_fp, ds_ls) {
             (Some(path), false) => metadata.data_source = DataSource::FilePath(path.into()),
             (None, true) => metadata.data_source = DataSource::Ls,
@@ -89,6 +103,15 @@ impl Command for MetadataSet {
                     "content_type" => Value::test_string("text/plain"),
                 })),
             },
+            Example {
+                description: "Attach user-defined metadata for downstream commands to inspect",
+                example: "'crates' | metadata set --custom {origin: local} | metadata",
+                result: Some(Value::test_record(record! {
+                    "custom" => Value::test_record(record! {
+                        "origin" => Value::test_string("local"),
+                    }),
+                })),
+            },
         ]
     }
 }
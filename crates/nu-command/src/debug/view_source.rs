@@ -226,6 +226,7 @@ impl Command for ViewSource {
             x.set_metadata(Some(PipelineMetadata {
                 data_source: DataSource::None,
                 content_type: Some("application/x-nuscript".into()),
+                custom: None,
             }))
         })
     }
@@ -68,6 +68,8 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Length,
             Lines,
             ParEach,
+            ParPipe,
+            ParRun,
             ChunkBy,
             Prepend,
             Reduce,
@@ -97,9 +99,12 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
 
         // Misc
         bind_command! {
+            Diff,
             Panic,
+            Patch,
             Source,
             Tutor,
+            Validate,
         };
 
         // Path
@@ -107,10 +112,12 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Path,
             PathBasename,
             PathSelf,
+            PathCommonPrefix,
             PathDirname,
             PathExists,
             PathExpand,
             PathJoin,
+            PathNormalize,
             PathParse,
             PathRelativeTo,
             PathSplit,
@@ -123,8 +130,16 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Complete,
             External,
             Exec,
+            LogsTail,
             NuCheck,
+            Service,
+            ServiceList,
+            ServiceLogs,
+            ServiceStart,
+            ServiceStatus,
+            ServiceStop,
             Sys,
+            SysContainer,
             SysCpu,
             SysDisks,
             SysHost,
@@ -139,9 +154,11 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         // Help
         bind_command! {
             Help,
+            HelpAll,
             HelpAliases,
             HelpExterns,
             HelpCommands,
+            HelpGenerate,
             HelpModules,
             HelpOperators,
             HelpPipeAndRedirect,
@@ -271,6 +288,9 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
 
         // Date
         bind_command! {
+            Cron,
+            CronMatch,
+            CronNext,
             Date,
             DateFromHuman,
             DateHumanize,
@@ -321,6 +341,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
         bind_command! {
             Griddle,
             Table,
+            Tree,
         };
 
         // Conversions
@@ -331,6 +352,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             IntoBinary,
             IntoCellPath,
             IntoDatetime,
+            IntoDecimal,
             IntoDuration,
             IntoFloat,
             IntoFilesize,
@@ -344,11 +366,13 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
 
         // Env
         bind_command! {
+            EnvDiff,
             ExportEnv,
             LoadEnv,
             SourceEnv,
             WithEnv,
             ConfigNu,
+            ConfigCheck,
             ConfigEnv,
             ConfigFlatten,
             ConfigMeta,
@@ -407,6 +431,12 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Port,
             VersionCheck,
         }
+        #[cfg(all(feature = "network", feature = "sqlite"))]
+        bind_command! {
+            HttpAuth,
+            HttpAuthOauth2,
+            HttpAuthToken,
+        }
         bind_command! {
             Url,
             UrlBuildQuery,
@@ -437,6 +467,7 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             SeqDate,
             SeqChar,
             Generate,
+            Poll,
         };
 
         // Hash
@@ -444,6 +475,8 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             Hash,
             HashMd5::default(),
             HashSha256::default(),
+            Checksum,
+            ChecksumVerify,
         };
 
         // Experimental
@@ -466,6 +499,15 @@ pub fn add_shell_command_context(mut engine_state: EngineState) -> EngineState {
             DateFormat,
         };
 
+        // Secret
+        #[cfg(feature = "sqlite")]
+        bind_command! {
+            Secret,
+            SecretGet,
+            SecretList,
+            SecretSet,
+        };
+
         // Stor
         #[cfg(feature = "sqlite")]
         bind_command! {
@@ -0,0 +1,87 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::Config;
+
+#[derive(Clone)]
+pub struct ConfigCheck;
+
+impl Command for ConfigCheck {
+    fn name(&self) -> &str {
+        "config check"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::Nothing, Type::table()),
+                (Type::record(), Type::table()),
+            ])
+            .category(Category::Env)
+    }
+
+    fn description(&self) -> &str {
+        "Validate a config record against the config schema."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Checks $env.config, or a record piped in, for unknown keys, values of the wrong type, and deprecated options, and reports each problem along with the path and span of the value that caused it. Unlike assigning to $env.config directly, this does not stop on the first error, and does not require the record to actually be applied."
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Check the current configuration for mistakes",
+                example: "config check",
+                result: None,
+            },
+            Example {
+                description: "Check a config record before assigning it",
+                example: "{ ls: { clickable_links: 1 } } | config check",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let value = match input {
+            PipelineData::Empty => stack
+                .get_env_var(engine_state, "config")
+                .cloned()
+                .unwrap_or_else(|| Value::record(Record::new(), head)),
+            input => input.into_value(head)?,
+        };
+
+        let problems = Config::check(&value)
+            .into_iter()
+            .map(|error| {
+                let span = error.span().unwrap_or(head);
+                Value::record(
+                    record! {
+                        "path" => match error.path() {
+                            Some(path) => Value::string(path, span),
+                            None => Value::nothing(span),
+                        },
+                        "error" => Value::string(error.to_string(), span),
+                        "span" => Value::record(
+                            record! {
+                                "start" => Value::int(span.start as i64, span),
+                                "end" => Value::int(span.end as i64, span),
+                            },
+                            span,
+                        ),
+                    },
+                    span,
+                )
+            })
+            .collect();
+
+        Ok(Value::list(problems, head).into_pipeline_data())
+    }
+}
@@ -1,4 +1,5 @@
 mod config_;
+mod config_check;
 mod config_env;
 mod config_flatten;
 mod config_nu;
@@ -6,6 +7,7 @@ mod config_reset;
 mod config_use_colors;
 
 pub use config_::ConfigMeta;
+pub use config_check::ConfigCheck;
 pub use config_env::ConfigEnv;
 pub use config_flatten::ConfigFlatten;
 pub use config_nu::ConfigNu;
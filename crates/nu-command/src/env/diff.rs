@@ -0,0 +1,113 @@
+use nu_engine::command_prelude::*;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct EnvDiff;
+
+impl Command for EnvDiff {
+    fn name(&self) -> &str {
+        "env diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("env diff")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .optional(
+                "snapshot",
+                SyntaxShape::Record(vec![]),
+                "A record of environment variables to diff against, such as one saved from `$env`. Defaults to the environment nushell inherited at startup.",
+            )
+            .category(Category::Env)
+    }
+
+    fn description(&self) -> &str {
+        "Show environment variables that were added, removed, or changed since a snapshot."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"With no argument, this compares against the environment nushell inherited from its
+parent process at startup, before any config or script ran. To diff against a point in the
+middle of a session instead, save `$env` to a variable first and pass it in:
+
+    let before = $env
+    $env.FOO = "bar"
+    env diff $before"#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["environment", "compare", "changes"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Show what has changed in the environment since the shell started",
+                example: "env diff",
+                result: None,
+            },
+            Example {
+                description: "Show what a block of code changed in the environment",
+                example: "let before = $env; $env.FOO = 'bar'; env diff $before",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let snapshot: Option<Record> = call.opt(engine_state, stack, 0)?;
+
+        let before: HashMap<String, Value> = match snapshot {
+            Some(record) => record.into_iter().collect(),
+            None => (*engine_state.initial_env_vars).clone(),
+        };
+        let after = stack.get_env_vars(engine_state);
+
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let mut rows = Vec::new();
+        for name in names {
+            let before_val = before.get(name);
+            let after_val = after.get(name);
+
+            let status = match (before_val, after_val) {
+                (None, Some(_)) => "added",
+                (Some(_), None) => "removed",
+                (Some(b), Some(a)) if b != a => "changed",
+                _ => continue,
+            };
+
+            rows.push(Value::record(
+                record! {
+                    "name" => Value::string(name.clone(), span),
+                    "status" => Value::string(status, span),
+                    "before" => before_val.cloned().unwrap_or(Value::nothing(span)),
+                    "after" => after_val.cloned().unwrap_or(Value::nothing(span)),
+                },
+                span,
+            ));
+        }
+
+        Ok(Value::list(rows, span).into_pipeline_data())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(EnvDiff {})
+    }
+}
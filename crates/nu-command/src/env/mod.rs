@@ -1,15 +1,18 @@
 mod config;
+mod diff;
 mod export_env;
 mod load_env;
 mod source_env;
 mod with_env;
 
+pub use config::ConfigCheck;
 pub use config::ConfigEnv;
 pub use config::ConfigFlatten;
 pub use config::ConfigMeta;
 pub use config::ConfigNu;
 pub use config::ConfigReset;
 pub use config::ConfigUseColors;
+pub use diff::EnvDiff;
 pub use export_env::ExportEnv;
 pub use load_env::LoadEnv;
 pub use source_env::SourceEnv;
@@ -29,6 +29,12 @@ impl Command for WithEnv {
         "Runs a block with an environment variable set."
     }
 
+    fn extra_description(&self) -> &str {
+        r#"The variables are only ever set on a copy of the environment scoped to the block: if the
+block errors partway through, or exits early, that copy is simply dropped, so the caller's
+environment is always left exactly as it was, with no separate `--restore` step needed."#
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -30,6 +30,7 @@ struct Args {
     directory: bool,
     use_mime_type: bool,
     use_threads: bool,
+    names_only: bool,
     call_span: Span,
 }
 
@@ -42,6 +43,14 @@ impl Command for Ls {
         "List the filenames, sizes, and modification times of items in a directory."
     }
 
+    fn extra_description(&self) -> &str {
+        r#"`--threads` already stats entries across a pool of worker threads and streams
+each one to the pipeline as soon as it's ready, rather than collecting the whole
+directory first. For directories so large that even parallel stat calls are too
+slow, `--names-only` skips the stat call entirely and reports just each entry's
+name."#
+    }
+
     fn search_terms(&self) -> Vec<&str> {
         vec!["dir"]
     }
@@ -76,6 +85,11 @@ impl Command for Ls {
             )
             .switch("mime-type", "Show mime-type in type column instead of 'file' (based on filenames only; files' contents are not examined)", Some('m'))
             .switch("threads", "Use multiple threads to list contents. Output will be non-deterministic.", Some('t'))
+            .switch(
+                "names-only",
+                "Only report each entry's name, skipping the stat call used for type, size, and dates (fastest for very large directories)",
+                Some('n'),
+            )
             .category(Category::FileSystem)
     }
 
@@ -94,6 +108,7 @@ impl Command for Ls {
         let directory = call.has_flag(engine_state, stack, "directory")?;
         let use_mime_type = call.has_flag(engine_state, stack, "mime-type")?;
         let use_threads = call.has_flag(engine_state, stack, "threads")?;
+        let names_only = call.has_flag(engine_state, stack, "names-only")?;
         let call_span = call.head;
         #[allow(deprecated)]
         let cwd = current_dir(engine_state, stack)?;
@@ -107,6 +122,7 @@ impl Command for Ls {
             directory,
             use_mime_type,
             use_threads,
+            names_only,
             call_span,
         };
 
@@ -125,6 +141,7 @@ impl Command for Ls {
                         PipelineMetadata {
                             data_source: DataSource::Ls,
                             content_type: None,
+                            custom: None,
                         },
                     ),
             ),
@@ -150,6 +167,7 @@ impl Command for Ls {
                         PipelineMetadata {
                             data_source: DataSource::Ls,
                             content_type: None,
+                            custom: None,
                         },
                     ))
             }
@@ -211,6 +229,11 @@ impl Command for Ls {
                 example: "['/path/to/directory' '/path/to/file'] | each {|| ls -D $in } | flatten",
                 result: None,
             },
+            Example {
+                description: "List just the names of entries in a huge directory, skipping the stat call for each one",
+                example: "ls --names-only /massive/directory",
+                result: None,
+            },
         ]
     }
 }
@@ -248,6 +271,7 @@ fn ls_for_one_pattern(
         directory,
         use_mime_type,
         use_threads,
+        names_only,
         call_span,
     } = args;
     let pattern_arg = {
@@ -378,7 +402,11 @@ fn ls_for_one_pattern(
                 .par_bridge()
                 .filter_map(move |x| match x {
                     Ok(path) => {
-                        let metadata = std::fs::symlink_metadata(&path).ok();
+                        let metadata = if names_only {
+                            None
+                        } else {
+                            std::fs::symlink_metadata(&path).ok()
+                        };
                         let hidden_dir_clone = Arc::clone(&hidden_dirs);
                         let mut hidden_dir_mutex = hidden_dir_clone
                             .lock()
@@ -442,17 +470,24 @@ fn ls_for_one_pattern(
 
                         match display_name {
                             Ok(name) => {
-                                let entry = dir_entry_dict(
-                                    &path,
-                                    &name,
-                                    metadata.as_ref(),
-                                    call_span,
-                                    long,
-                                    du,
-                                    &signals_clone,
-                                    use_mime_type,
-                                    args.full_paths,
-                                );
+                                let entry = if names_only {
+                                    Ok(Value::record(
+                                        record! { "name" => Value::string(name, call_span) },
+                                        call_span,
+                                    ))
+                                } else {
+                                    dir_entry_dict(
+                                        &path,
+                                        &name,
+                                        metadata.as_ref(),
+                                        call_span,
+                                        long,
+                                        du,
+                                        &signals_clone,
+                                        use_mime_type,
+                                        args.full_paths,
+                                    )
+                                };
                                 match entry {
                                     Ok(value) => Some(value),
                                     Err(err) => Some(Value::error(err, call_span)),
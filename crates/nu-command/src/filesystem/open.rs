@@ -30,7 +30,8 @@ impl Command for Open {
     }
 
     fn extra_description(&self) -> &str {
-        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope."
+        "Support to automatically parse files with an extension `.xyz` can be provided by a `from xyz` command in scope. \
+An http(s) URL can be given instead of a local path, and `-` reads from stdin."
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -98,6 +99,24 @@ impl Command for Open {
             let arg_span = path.span;
             // let path_no_whitespace = &path.item.trim_end_matches(|x| matches!(x, '\x09'..='\x0d'));
 
+            if path.item.as_ref() == "-" {
+                let stream = PipelineData::ByteStream(
+                    ByteStream::stdin(call_span)?,
+                    Some(PipelineMetadata {
+                        data_source: DataSource::None,
+                        content_type: None,
+                        custom: None,
+                    }),
+                );
+                output.push(stream);
+                continue;
+            }
+
+            if let Some(url) = as_remote_url(path.item.as_ref()) {
+                output.push(open_remote(engine_state, stack, url, arg_span, raw)?);
+                continue;
+            }
+
             for path in
                 nu_engine::glob_from(&path, &cwd, call_span, None, engine_state.signals().clone())
                     .map_err(|err| match err {
@@ -171,6 +190,7 @@ impl Command for Open {
                         Some(PipelineMetadata {
                             data_source: DataSource::FilePath(path.to_path_buf()),
                             content_type: None,
+                            custom: None,
                         }),
                     );
 
@@ -227,6 +247,7 @@ impl Command for Open {
                                 stream.set_metadata(Some(PipelineMetadata {
                                     data_source: DataSource::FilePath(path.to_path_buf()),
                                     content_type,
+                                    custom: None,
                                 }));
                             output.push(stream_with_content_type);
                         }
@@ -274,6 +295,16 @@ impl Command for Open {
                 example: r#"def "from ndjson" [] { from json -o }; open myfile.ndjson"#,
                 result: None,
             },
+            Example {
+                description: "Open a URL, converting it the same way a local file would be",
+                example: "open https://example.com/data.csv",
+                result: None,
+            },
+            Example {
+                description: "Read raw bytes from stdin",
+                example: "open --raw -",
+                result: None,
+            },
         ]
     }
 }
@@ -320,6 +351,92 @@ fn detect_content_type(extension: &str) -> Option<String> {
     }
 }
 
+/// If `path` looks like a URL `open` knows how to fetch (currently `http`/`https`), return it.
+fn as_remote_url(path: &str) -> Option<&str> {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "network")]
+fn open_remote(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    url: &str,
+    span: Span,
+    raw: bool,
+) -> Result<PipelineData, ShellError> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|err| ShellError::NetworkFailure {
+            msg: format!("Failed to fetch '{url}': {err}"),
+            span,
+        })?;
+
+    let content_type = response.header("content-type").map(|s| s.to_string());
+    let reader = response.into_reader();
+    let stream = PipelineData::ByteStream(
+        ByteStream::read(
+            reader,
+            span,
+            engine_state.signals().clone(),
+            ByteStreamType::Unknown,
+        ),
+        Some(PipelineMetadata {
+            data_source: DataSource::Url(url.to_string()),
+            content_type: content_type.clone(),
+            custom: None,
+        }),
+    );
+
+    if raw {
+        return Ok(stream);
+    }
+
+    let ext = content_type
+        .as_deref()
+        .and_then(|content_type| content_type.split(';').next())
+        .and_then(|essence| essence.split('/').next_back())
+        .map(|ext| ext.to_string())
+        .or_else(|| {
+            url::Url::parse(url)
+                .ok()?
+                .path_segments()?
+                .next_back()
+                .and_then(|name| Path::new(name).extension())
+                .map(|ext| ext.to_string_lossy().to_string())
+        });
+
+    match ext.and_then(|ext| engine_state.find_decl(format!("from {ext}").as_bytes(), &[])) {
+        Some(converter_id) => {
+            let call = ast::Call::new(span);
+            engine_state
+                .get_decl(converter_id)
+                .run(engine_state, stack, &(&call).into(), stream)
+        }
+        None => Ok(stream),
+    }
+}
+
+#[cfg(not(feature = "network"))]
+fn open_remote(
+    _engine_state: &EngineState,
+    _stack: &mut Stack,
+    url: &str,
+    span: Span,
+    _raw: bool,
+) -> Result<PipelineData, ShellError> {
+    Err(ShellError::GenericError {
+        error: "`open` was built without network support".into(),
+        msg: format!("cannot open remote URL '{url}'"),
+        span: Some(span),
+        help: Some("rebuild nu with the `network` feature to enable `open <url>`".into()),
+        inner: vec![],
+    })
+}
+
 #[cfg(test)]
 mod test {
 
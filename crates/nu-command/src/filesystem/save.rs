@@ -54,6 +54,17 @@ impl Command for Save {
             .switch("append", "append input to the end of the file", Some('a'))
             .switch("force", "overwrite the destination", Some('f'))
             .switch("progress", "enable progress bar", Some('p'))
+            .switch(
+                "atomic",
+                "write to a temporary file and rename it into place, so the destination is never left partially written",
+                None,
+            )
+            .named(
+                "backup",
+                SyntaxShape::String,
+                "if the destination already exists, copy it to '<destination>.<ext>' before overwriting",
+                None,
+            )
             .category(Category::FileSystem)
     }
 
@@ -68,8 +79,19 @@ impl Command for Save {
         let append = call.has_flag(engine_state, stack, "append")?;
         let force = call.has_flag(engine_state, stack, "force")?;
         let progress = call.has_flag(engine_state, stack, "progress")?;
+        let atomic = call.has_flag(engine_state, stack, "atomic")?;
+        let backup: Option<String> = call.get_flag(engine_state, stack, "backup")?;
 
         let span = call.head;
+
+        if atomic && append {
+            return Err(ShellError::IncompatibleParameters {
+                left_message: "--atomic".into(),
+                left_span: call.get_flag_span(stack, "atomic").unwrap_or(span),
+                right_message: "--append".into(),
+                right_span: call.get_flag_span(stack, "append").unwrap_or(span),
+            });
+        }
         #[allow(deprecated)]
         let cwd = current_dir(engine_state, stack)?;
 
@@ -91,7 +113,22 @@ impl Command for Save {
             PipelineData::ByteStream(stream, metadata) => {
                 check_saving_to_source_file(metadata.as_ref(), &path, stderr_path.as_ref())?;
 
-                let (file, stderr_file) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (target, stderr_target) = get_files(
+                    &path,
+                    stderr_path.as_ref(),
+                    append,
+                    force,
+                    atomic,
+                    backup.as_deref(),
+                )?;
+                let (file, target_commit) = target.into_parts();
+                let (stderr_file, stderr_commit) = match stderr_target {
+                    Some(target) => {
+                        let (file, commit) = target.into_parts();
+                        (Some(file), Some(commit))
+                    }
+                    None => (None, None),
+                };
 
                 let size = stream.known_size();
                 let signals = engine_state.signals();
@@ -190,6 +227,11 @@ impl Command for Save {
                     }
                 }
 
+                target_commit.commit(span)?;
+                if let Some(commit) = stderr_commit {
+                    commit.commit(span)?;
+                }
+
                 Ok(PipelineData::Empty)
             }
             PipelineData::ListStream(ls, pipeline_metadata)
@@ -201,13 +243,23 @@ impl Command for Save {
                     stderr_path.as_ref(),
                 )?;
 
-                let (mut file, _) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (target, _) = get_files(
+                    &path,
+                    stderr_path.as_ref(),
+                    append,
+                    force,
+                    atomic,
+                    backup.as_deref(),
+                )?;
+                let (mut file, commit) = target.into_parts();
                 for val in ls {
                     file.write_all(&value_to_bytes(val)?)
                         .map_err(&from_io_error)?;
                     file.write_all("\n".as_bytes()).map_err(&from_io_error)?;
                 }
                 file.flush().map_err(&from_io_error)?;
+                drop(file);
+                commit.commit(span)?;
 
                 Ok(PipelineData::empty())
             }
@@ -226,10 +278,20 @@ impl Command for Save {
                     input_to_bytes(input, Path::new(&path.item), raw, engine_state, stack, span)?;
 
                 // Only open file after successful conversion
-                let (mut file, _) = get_files(&path, stderr_path.as_ref(), append, force)?;
+                let (target, _) = get_files(
+                    &path,
+                    stderr_path.as_ref(),
+                    append,
+                    force,
+                    atomic,
+                    backup.as_deref(),
+                )?;
+                let (mut file, commit) = target.into_parts();
 
                 file.write_all(&bytes).map_err(&from_io_error)?;
                 file.flush().map_err(&from_io_error)?;
+                drop(file);
+                commit.commit(span)?;
 
                 Ok(PipelineData::empty())
             }
@@ -263,6 +325,17 @@ impl Command for Save {
                 example: r#"do -i {} | save foo.txt --stderr bar.txt"#,
                 result: None,
             },
+            Example {
+                description:
+                    "Overwrite a config file without ever leaving it partially written",
+                example: r#"open config.nu | save --force --atomic config.nu"#,
+                result: None,
+            },
+            Example {
+                description: "Keep a copy of the previous contents before overwriting",
+                example: r#"'new contents' | save --force --backup bak foo.txt"#,
+                result: None,
+            },
         ]
     }
 
@@ -412,6 +485,27 @@ fn prepare_path(
     }
 }
 
+/// If `backup_ext` is given and `path` already exists, copy it to `path.<ext>` before it gets
+/// truncated or replaced, so the previous contents aren't lost.
+fn backup_if_present(path: &Path, backup_ext: Option<&str>, span: Span) -> Result<(), ShellError> {
+    let Some(backup_ext) = backup_ext else {
+        return Ok(());
+    };
+
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let mut backup_path = path.as_os_str().to_owned();
+    backup_path.push(".");
+    backup_path.push(backup_ext);
+    let backup_path = PathBuf::from(backup_path);
+
+    std::fs::copy(path, &backup_path)
+        .map_err(|err| ShellError::Io(IoError::new(err.kind(), span, backup_path)))?;
+    Ok(())
+}
+
 fn open_file(path: &Path, span: Span, append: bool) -> Result<File, ShellError> {
     let file: Result<File, nu_protocol::shell_error::io::ErrorKind> = match (append, path.exists())
     {
@@ -438,13 +532,86 @@ fn open_file(path: &Path, span: Span, append: bool) -> Result<File, ShellError>
     file.map_err(|err_kind| ShellError::Io(IoError::new(err_kind, span, PathBuf::from(path))))
 }
 
+/// A file opened for writing, plus what needs to happen once writing is done.
+struct SaveTarget {
+    file: File,
+    commit: Commit,
+}
+
+impl SaveTarget {
+    fn into_parts(self) -> (File, Commit) {
+        (self.file, self.commit)
+    }
+}
+
+/// The final step for a [`SaveTarget`]: for a plain save this is a no-op, for `--atomic` it
+/// renames the temporary file into place once the file handle has been dropped.
+struct Commit {
+    write_path: PathBuf,
+    final_path: PathBuf,
+}
+
+impl Commit {
+    fn commit(self, span: Span) -> Result<(), ShellError> {
+        if self.write_path != self.final_path {
+            std::fs::rename(&self.write_path, &self.final_path).map_err(|err| {
+                ShellError::Io(IoError::new(err.kind(), span, self.final_path.clone()))
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Open `path` for writing, honoring `--atomic` (write to a sibling temp file and rename it into
+/// place later) and `--backup` (snapshot the previous contents before they're touched).
+fn open_target(
+    path: &Path,
+    span: Span,
+    append: bool,
+    atomic: bool,
+    backup_ext: Option<&str>,
+) -> Result<SaveTarget, ShellError> {
+    backup_if_present(path, backup_ext, span)?;
+
+    if atomic {
+        // `--atomic` and `--append` are rejected together up front, so we always start from a
+        // fresh temporary file here.
+        let mut tmp_name = path
+            .file_name()
+            .map(|name| name.to_owned())
+            .unwrap_or_default();
+        tmp_name.push(format!(".nu-save-{}.tmp", std::process::id()));
+        let write_path = path.with_file_name(tmp_name);
+
+        let file = open_file(&write_path, span, false)?;
+        Ok(SaveTarget {
+            file,
+            commit: Commit {
+                write_path,
+                final_path: path.to_path_buf(),
+            },
+        })
+    } else {
+        let file = open_file(path, span, append)?;
+        Ok(SaveTarget {
+            file,
+            commit: Commit {
+                write_path: path.to_path_buf(),
+                final_path: path.to_path_buf(),
+            },
+        })
+    }
+}
+
 /// Get output file and optional stderr file
 fn get_files(
     path: &Spanned<PathBuf>,
     stderr_path: Option<&Spanned<PathBuf>>,
     append: bool,
     force: bool,
-) -> Result<(File, Option<File>), ShellError> {
+    atomic: bool,
+    backup_ext: Option<&str>,
+) -> Result<(SaveTarget, Option<SaveTarget>), ShellError> {
     // First check both paths
     let (path, path_span) = prepare_path(path, append, force)?;
     let stderr_path_and_span = stderr_path
@@ -453,9 +620,9 @@ fn get_files(
         .transpose()?;
 
     // Only if both files can be used open and possibly truncate them
-    let file = open_file(path, path_span, append)?;
+    let target = open_target(path, path_span, append, atomic, backup_ext)?;
 
-    let stderr_file = stderr_path_and_span
+    let stderr_target = stderr_path_and_span
         .map(|(stderr_path, stderr_path_span)| {
             if path == stderr_path {
                 Err(ShellError::GenericError {
@@ -466,12 +633,12 @@ fn get_files(
                     inner: vec![],
                 })
             } else {
-                open_file(stderr_path, stderr_path_span, append)
+                open_target(stderr_path, stderr_path_span, append, atomic, backup_ext)
             }
         })
         .transpose()?;
 
-    Ok((file, stderr_file))
+    Ok((target, stderr_target))
 }
 
 fn stream_to_file(
@@ -0,0 +1,225 @@
+use nu_protocol::{ast::CellPath, ast::PathMember, ShellError, Span, Value};
+
+/// A parsed cell-path pattern, as produced by [`parse_cell_path_pattern`].
+pub(crate) type CellPathPattern = Vec<PatternSegment>;
+
+/// One segment of a parsed cell-path pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PatternSegment {
+    Key(String),
+    KeyWildcard,
+    Index(usize),
+    IndexWildcard,
+}
+
+/// Parses a dot-separated cell-path pattern such as `items.*.id` or `a[*].b` into
+/// a sequence of segments, where a standalone `*` matches any record column or list
+/// index at that position, and `[*]` matches any list index.
+///
+/// This intentionally reuses nushell's existing dot/bracket cell-path syntax rather
+/// than introducing JSON Pointer's `/`-separated, `~0`/`~1`-escaped syntax, so patterns
+/// read the same as any other cell path in the language.
+pub(crate) fn parse_cell_path_pattern(
+    pattern: &str,
+    span: Span,
+) -> Result<CellPathPattern, ShellError> {
+    let mut segments = vec![];
+
+    for part in pattern.split('.') {
+        if part.is_empty() {
+            return Err(ShellError::IncorrectValue {
+                msg: "cell-path pattern segments can't be empty".into(),
+                val_span: span,
+                call_span: span,
+            });
+        }
+
+        let (key, mut rest) = match part.find('[') {
+            Some(idx) => (&part[..idx], &part[idx..]),
+            None => (part, ""),
+        };
+
+        if !key.is_empty() {
+            segments.push(if key == "*" {
+                PatternSegment::KeyWildcard
+            } else {
+                PatternSegment::Key(key.into())
+            });
+        }
+
+        while !rest.is_empty() {
+            let close = rest.find(']').ok_or_else(|| ShellError::IncorrectValue {
+                msg: "unterminated `[` in cell-path pattern".into(),
+                val_span: span,
+                call_span: span,
+            })?;
+            let inside = &rest[1..close];
+            segments.push(if inside == "*" {
+                PatternSegment::IndexWildcard
+            } else {
+                let idx: usize = inside.parse().map_err(|_| ShellError::IncorrectValue {
+                    msg: format!("invalid index `{inside}` in cell-path pattern"),
+                    val_span: span,
+                    call_span: span,
+                })?;
+                PatternSegment::Index(idx)
+            });
+            rest = &rest[close + 1..];
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Expands a parsed pattern against a concrete value, returning one [`CellPath`] per
+/// concrete member that matched. Wildcards are resolved against `value`'s actual
+/// shape, so a pattern like `items.*.id` can expand differently for each row of a
+/// table with differently-shaped `items`.
+pub(crate) fn expand_cell_path_pattern(
+    value: &Value,
+    pattern: &CellPathPattern,
+    span: Span,
+) -> Vec<CellPath> {
+    let mut out = vec![];
+    let mut members = vec![];
+    expand_into(value, pattern, span, &mut members, &mut out);
+    out
+}
+
+fn expand_into(
+    value: &Value,
+    pattern: &[PatternSegment],
+    span: Span,
+    members: &mut Vec<PathMember>,
+    out: &mut Vec<CellPath>,
+) {
+    let Some((segment, rest)) = pattern.split_first() else {
+        out.push(CellPath {
+            members: members.clone(),
+        });
+        return;
+    };
+
+    match segment {
+        PatternSegment::Key(name) => {
+            if let Value::Record { val, .. } = value {
+                if let Some(inner) = val.get(name) {
+                    members.push(PathMember::String {
+                        val: name.clone(),
+                        span,
+                        optional: false,
+                    });
+                    expand_into(inner, rest, span, members, out);
+                    members.pop();
+                }
+            }
+        }
+        PatternSegment::KeyWildcard => match value {
+            Value::Record { val, .. } => {
+                for (col, inner) in val.iter() {
+                    members.push(PathMember::String {
+                        val: col.clone(),
+                        span,
+                        optional: false,
+                    });
+                    expand_into(inner, rest, span, members, out);
+                    members.pop();
+                }
+            }
+            Value::List { vals, .. } => {
+                for (idx, inner) in vals.iter().enumerate() {
+                    members.push(PathMember::Int {
+                        val: idx,
+                        span,
+                        optional: false,
+                    });
+                    expand_into(inner, rest, span, members, out);
+                    members.pop();
+                }
+            }
+            _ => {}
+        },
+        PatternSegment::Index(idx) => {
+            if let Value::List { vals, .. } = value {
+                if let Some(inner) = vals.get(*idx) {
+                    members.push(PathMember::Int {
+                        val: *idx,
+                        span,
+                        optional: false,
+                    });
+                    expand_into(inner, rest, span, members, out);
+                    members.pop();
+                }
+            }
+        }
+        PatternSegment::IndexWildcard => {
+            if let Value::List { vals, .. } = value {
+                for (idx, inner) in vals.iter().enumerate() {
+                    members.push(PathMember::Int {
+                        val: idx,
+                        span,
+                        optional: false,
+                    });
+                    expand_into(inner, rest, span, members, out);
+                    members.pop();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use nu_protocol::record;
+
+    #[test]
+    fn parses_dot_and_bracket_wildcards() {
+        let span = Span::test_data();
+        assert_eq!(
+            parse_cell_path_pattern("a.*.b", span).unwrap(),
+            vec![
+                PatternSegment::Key("a".into()),
+                PatternSegment::KeyWildcard,
+                PatternSegment::Key("b".into()),
+            ]
+        );
+        assert_eq!(
+            parse_cell_path_pattern("items[*].id", span).unwrap(),
+            vec![
+                PatternSegment::Key("items".into()),
+                PatternSegment::IndexWildcard,
+                PatternSegment::Key("id".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_wildcards_against_value() {
+        let span = Span::test_data();
+        let pattern = parse_cell_path_pattern("items.*.id", span).unwrap();
+        let value = Value::test_record(record! {
+            "items" => Value::test_list(vec![
+                Value::test_record(record! { "id" => Value::test_int(1) }),
+                Value::test_record(record! { "id" => Value::test_int(2) }),
+            ]),
+        });
+
+        let paths = expand_cell_path_pattern(&value, &pattern, span);
+        let names: Vec<_> = paths.iter().map(CellPath::to_column_name).collect();
+        assert_eq!(names, vec!["items.0.id", "items.1.id"]);
+    }
+
+    #[test]
+    fn key_wildcard_matches_list_indices_too() {
+        let span = Span::test_data();
+        let pattern = parse_cell_path_pattern("items.*", span).unwrap();
+        let value = Value::test_record(record! {
+            "items" => Value::test_list(vec![Value::test_int(1), Value::test_int(2)]),
+        });
+
+        let paths = expand_cell_path_pattern(&value, &pattern, span);
+        let names: Vec<_> = paths.iter().map(CellPath::to_column_name).collect();
+        assert_eq!(names, vec!["items.0", "items.1"]);
+    }
+}
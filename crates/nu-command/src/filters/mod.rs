@@ -1,6 +1,7 @@
 mod all;
 mod any;
 mod append;
+mod cell_path_pattern;
 mod chunk_by;
 mod chunks;
 mod columns;
@@ -30,6 +31,8 @@ mod lines;
 mod merge;
 mod move_;
 mod par_each;
+mod par_pipe;
+mod par_run;
 mod prepend;
 mod reduce;
 mod reject;
@@ -89,6 +92,8 @@ pub use merge::Merge;
 pub use merge::MergeDeep;
 pub use move_::Move;
 pub use par_each::ParEach;
+pub use par_pipe::ParPipe;
+pub use par_run::ParRun;
 pub use prepend::Prepend;
 pub use reduce::Reduce;
 pub use reject::Reject;
@@ -0,0 +1,167 @@
+use nu_engine::{command_prelude::*, get_eval_block_with_early_return};
+use nu_protocol::{engine::Closure, report_shell_error, shell_error::io::IoError, OutDest, Signals};
+use std::{sync::Arc, thread};
+
+use super::tee::{panic_error, tee_channel, TeeReceiver, TeeSender};
+
+#[derive(Clone)]
+pub struct ParPipe;
+
+impl Command for ParPipe {
+    fn name(&self) -> &str {
+        "par-pipe"
+    }
+
+    fn description(&self) -> &str {
+        "Run multiple closures on the same input concurrently, merging their output streams."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Unlike `tee`, which passes the original input through while its closures run purely for
+side effects, `par-pipe` merges each closure's own output into a single interleaved stream. Each
+closure runs on its own thread against a private copy of the input. Without `--buffer-size`, a
+slow closure only delays its own contribution to the output, not the others; with it, a branch
+whose channel is full holds up the feeding of input to every branch."#
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("par-pipe")
+            .input_output_type(Type::Any, Type::list(Type::Any))
+            .named(
+                "buffer-size",
+                SyntaxShape::Int,
+                "Bound each branch's internal channel to this many elements, so a slow branch \
+                    applies backpressure instead of buffering unboundedly.",
+                None,
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(None),
+                "A closure to run against the input.",
+            )
+            .rest(
+                "more_closures",
+                SyntaxShape::Closure(None),
+                "Additional closures to run concurrently, each contributing to the merged output.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "[1 2 3] | par-pipe { each { $in * 2 } } { each { $in * 3 } } | sort",
+            description: "Double and triple each input value on separate threads, merging the results",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let from_io_error = IoError::factory(head, None);
+        let buffer_size: Option<usize> = call.get_flag(engine_state, stack, "buffer-size")?;
+
+        let first_closure: Spanned<Closure> = call.req(engine_state, stack, 0)?;
+        let closure_span = first_closure.span;
+        let mut closures = vec![first_closure.item];
+        closures.extend(
+            call.rest::<Spanned<Closure>>(engine_state, stack, 1)?
+                .into_iter()
+                .map(|c| c.item),
+        );
+
+        let span = input.span().unwrap_or(head);
+        let metadata = input.metadata();
+        let engine_state_arc = Arc::new(engine_state.clone());
+
+        let (merge_tx, merge_rx) = tee_channel::<Value>(buffer_size);
+
+        let mut branch_senders = Vec::with_capacity(closures.len());
+        let mut branch_threads = Vec::with_capacity(closures.len());
+
+        for closure in closures {
+            let (branch_tx, branch_rx): (TeeSender<Value>, TeeReceiver<Value>) =
+                tee_channel(buffer_size);
+            let closure_engine_state = engine_state_arc.clone();
+            let mut closure_stack = stack
+                .captures_to_stack_preserve_out_dest(closure.captures)
+                .reset_pipes();
+            let eval_block_with_early_return = get_eval_block_with_early_return(engine_state);
+            let merge_tx = merge_tx.clone();
+            let metadata = metadata.clone();
+
+            let thread = thread::Builder::new()
+                .name("par-pipe".into())
+                .spawn(move || {
+                    let branch_input = branch_rx.into_pipeline_data_with_metadata(
+                        span,
+                        Signals::empty(),
+                        metadata,
+                    );
+                    let result = eval_block_with_early_return(
+                        &closure_engine_state,
+                        &mut closure_stack,
+                        closure_engine_state.get_block(closure.block_id),
+                        branch_input,
+                    );
+                    match result {
+                        Ok(data) => {
+                            for value in data {
+                                if merge_tx.send(value).is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            let _ = merge_tx.send(Value::error(err, closure_span));
+                        }
+                    }
+                })
+                .map_err(&from_io_error)?;
+
+            branch_senders.push(branch_tx);
+            branch_threads.push(thread);
+        }
+
+        // Drop our own sender so `merge_rx` ends once every branch thread's clone is gone.
+        drop(merge_tx);
+
+        let signals = engine_state.signals().clone();
+        let report_engine_state = engine_state_arc.clone();
+        thread::Builder::new()
+            .name("par-pipe".into())
+            .spawn(move || {
+                for value in input.into_iter() {
+                    if signals.interrupted() {
+                        break;
+                    }
+                    for sender in &branch_senders {
+                        let _ = sender.send(value.clone());
+                    }
+                }
+                // Dropping the senders here lets each branch see the end of its input.
+                drop(branch_senders);
+                for thread in branch_threads {
+                    if let Err(_err) = thread.join() {
+                        report_shell_error(&report_engine_state, &panic_error());
+                    }
+                }
+            })
+            .map_err(&from_io_error)?;
+
+        Ok(merge_rx.into_pipeline_data_with_metadata(
+            span,
+            engine_state.signals().clone(),
+            metadata,
+        ))
+    }
+
+    fn pipe_redirection(&self) -> (Option<OutDest>, Option<OutDest>) {
+        (Some(OutDest::PipeSeparate), Some(OutDest::PipeSeparate))
+    }
+}
@@ -0,0 +1,179 @@
+use nu_engine::{command_prelude::*, ClosureEvalOnce};
+use nu_protocol::{engine::Closure, report_shell_error, Record, Signals};
+use rayon::prelude::*;
+use std::sync::{atomic::AtomicBool, mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Clone)]
+pub struct ParRun;
+
+impl Command for ParRun {
+    fn name(&self) -> &str {
+        "par-run"
+    }
+
+    fn description(&self) -> &str {
+        "Run a closure over each input item concurrently, with a concurrency limit and optional per-item timeout."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Each item is run in its own thread, up to --max-concurrent at a time. If --timeout \
+elapses before an item's closure returns, that item's row reports timed_out = true immediately \
+and the underlying thread is signaled to interrupt, the same way ctrl-c interrupts a running \
+pipeline; it stops at its next signal check rather than being forcibly killed, so it may still \
+run a little past the timeout, but it will not run forever. Useful for fleet operations and bulk \
+API calls where a few slow or unreachable targets shouldn't hold up the rest."
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build("par-run")
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::table()),
+                (Type::table(), Type::table()),
+            ])
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "The closure to run on each item.",
+            )
+            .named(
+                "max-concurrent",
+                SyntaxShape::Int,
+                "the maximum number of items to process at once (default: number of CPUs)",
+                Some('m'),
+            )
+            .named(
+                "timeout",
+                SyntaxShape::Duration,
+                "per-item timeout; items that exceed it are reported as timed out",
+                Some('T'),
+            )
+            .allow_variants_without_examples(true)
+            .category(Category::Filters)
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["parallel", "fan-out", "concurrency", "fleet"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            example: "[a b c] | par-run --max-concurrent 2 { |it| http get $\"https://($it)\" }",
+            description: "Fetch three URLs, at most two at a time",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let closure: Closure = call.req(engine_state, stack, 0)?;
+        let max_concurrent: Option<usize> = call.get_flag(engine_state, stack, "max-concurrent")?;
+        let timeout: Option<i64> = call.get_flag(engine_state, stack, "timeout")?;
+        let timeout = timeout.map(|nanos| Duration::from_nanos(nanos.max(0) as u64));
+
+        let items: Vec<Value> = input.into_iter().collect();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(max_concurrent.unwrap_or(0))
+            .build()
+            .map_err(|e| ShellError::GenericError {
+                error: "Error creating thread pool".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let rows = pool.install(|| {
+            items
+                .into_par_iter()
+                .map(|item| run_one(engine_state, stack, &closure, item, head, timeout))
+                .collect::<Vec<_>>()
+        });
+
+        Ok(Value::list(rows, head).into_pipeline_data())
+    }
+}
+
+fn run_one(
+    engine_state: &EngineState,
+    stack: &Stack,
+    closure: &Closure,
+    item: Value,
+    head: Span,
+    timeout: Option<Duration>,
+) -> Value {
+    let item_span = item.span();
+
+    let mut job_state = engine_state.clone();
+    job_state.is_interactive = false;
+    let interrupt = Arc::new(AtomicBool::new(false));
+    job_state.set_signals(Signals::new(interrupt.clone()));
+    let job_stack = stack.clone();
+    let closure = closure.clone();
+
+    let (tx, rx) = mpsc::channel();
+    let started = Instant::now();
+
+    let spawned = thread::Builder::new().name("par-run item".into()).spawn(move || {
+        let result = ClosureEvalOnce::new(&job_state, &job_stack, closure)
+            .run_with_value(item)
+            .and_then(|data| data.into_value(item_span))
+            .unwrap_or_else(|err| {
+                report_shell_error(&job_state, &err);
+                Value::error(err, item_span)
+            });
+        let _ = tx.send(result);
+    });
+
+    let mut record = Record::new();
+    if spawned.is_err() {
+        record.push("ok", Value::bool(false, head));
+        record.push("timed_out", Value::bool(false, head));
+        record.push(
+            "error",
+            Value::string("failed to spawn thread for item", head),
+        );
+        record.push("duration", Value::duration(started.elapsed().as_nanos() as i64, head));
+        return Value::record(record, head);
+    }
+
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout).ok(),
+        None => rx.recv().ok(),
+    };
+
+    let duration = Value::duration(started.elapsed().as_nanos() as i64, head);
+
+    match received {
+        Some(value) => {
+            let is_error = value.is_error();
+            record.push("ok", Value::bool(!is_error, head));
+            record.push("timed_out", Value::bool(false, head));
+            record.push("value", value);
+            record.push("duration", duration);
+        }
+        None => {
+            // The closure's evaluation loop checks signals at points like loop iterations and
+            // external command boundaries, the same mechanism ctrl-c uses, so this makes a
+            // hung/slow item stop soon after the timeout instead of running forever.
+            interrupt.store(true, std::sync::atomic::Ordering::Relaxed);
+            record.push("ok", Value::bool(false, head));
+            record.push("timed_out", Value::bool(true, head));
+            record.push(
+                "value",
+                Value::string("item timed out before completing", head),
+            );
+            record.push("duration", duration);
+        }
+    }
+
+    Value::record(record, head)
+}
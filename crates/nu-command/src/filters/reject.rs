@@ -1,3 +1,4 @@
+use super::cell_path_pattern::{expand_cell_path_pattern, parse_cell_path_pattern, CellPathPattern};
 use nu_engine::command_prelude::*;
 use nu_protocol::ast::PathMember;
 use std::{cmp::Reverse, collections::HashSet};
@@ -27,6 +28,12 @@ impl Command for Reject {
                 SyntaxShape::CellPath,
                 "The names of columns to remove from the table.",
             )
+            .named(
+                "paths",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Remove using cell-path patterns with `*` wildcards (e.g. `items.*.secret`), resolved independently for each row.",
+                None,
+            )
             .category(Category::Filters)
     }
 
@@ -35,7 +42,13 @@ impl Command for Reject {
     }
 
     fn extra_description(&self) -> &str {
-        "To remove a quantity of rows or columns, use `skip`, `drop`, or `drop column`."
+        r#"To remove a quantity of rows or columns, use `skip`, `drop`, or `drop column`.
+
+`--paths` is an alternative to the positional cell paths for removing fields whose
+position isn't known ahead of time or differs from row to row. A `*` segment matches
+any record column or list index, so `items.*.secret` removes `secret` from every
+element of `items`, however many there are, resolving the wildcard separately for
+each row."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -50,6 +63,25 @@ impl Command for Reject {
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+        let paths: Option<Vec<Spanned<String>>> = call.get_flag(engine_state, stack, "paths")?;
+        let span = call.head;
+
+        if let Some(paths) = paths {
+            if !columns.is_empty() {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "`--paths` can't be used together with positional cell paths".into(),
+                    span,
+                });
+            }
+
+            let patterns = paths
+                .iter()
+                .map(|p| parse_cell_path_pattern(&p.item, p.span))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return reject_paths(engine_state, span, patterns, input);
+        }
+
         let mut new_columns: Vec<CellPath> = vec![];
         for col_val in columns {
             let col_span = &col_val.span();
@@ -87,7 +119,6 @@ impl Command for Reject {
                 }
             }
         }
-        let span = call.head;
 
         let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
         if ignore_errors {
@@ -170,10 +201,52 @@ impl Command for Reject {
                     Value::test_int(3),
                 ])),
             },
+            Example {
+                description: "Strip a nested field out of every element of a list, without an `each`/`upsert` chain",
+                example: "{items: [{id: 1, secret: x}, {id: 2, secret: y}]} | reject --paths [items.*.secret]",
+                result: Some(Value::test_record(record! {
+                    "items" => Value::test_list(vec![
+                        Value::test_record(record! { "id" => Value::test_int(1) }),
+                        Value::test_record(record! { "id" => Value::test_int(2) }),
+                    ]),
+                })),
+            },
         ]
     }
 }
 
+fn reject_paths(
+    engine_state: &EngineState,
+    call_span: Span,
+    patterns: Vec<CellPathPattern>,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    input.map(
+        move |mut value| {
+            let mut cell_paths: Vec<_> = patterns
+                .iter()
+                .flat_map(|pattern| expand_cell_path_pattern(&value, pattern, call_span))
+                .collect();
+            // Remove list-index matches back-to-front so earlier removals don't shift the
+            // indices of paths still queued for removal.
+            cell_paths.sort_unstable_by(|a, b| {
+                b.members
+                    .partial_cmp(&a.members)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+            for cell_path in &cell_paths {
+                if let Err(error) = value.remove_data_at_cell_path(&cell_path.members) {
+                    return Value::error(error, call_span);
+                }
+            }
+
+            value
+        },
+        engine_state.signals(),
+    )
+}
+
 fn reject(
     engine_state: &EngineState,
     span: Span,
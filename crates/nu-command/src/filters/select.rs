@@ -1,3 +1,4 @@
+use super::cell_path_pattern::{expand_cell_path_pattern, parse_cell_path_pattern, CellPathPattern};
 use nu_engine::command_prelude::*;
 use nu_protocol::{ast::PathMember, PipelineIterator};
 use std::collections::BTreeSet;
@@ -28,6 +29,12 @@ impl Command for Select {
                 SyntaxShape::CellPath,
                 "The columns to select from the table.",
             )
+            .named(
+                "paths",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "Select using cell-path patterns with `*` wildcards (e.g. `items.*.id`), resolved independently for each row.",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Filters)
     }
@@ -39,7 +46,13 @@ impl Command for Select {
     fn extra_description(&self) -> &str {
         r#"This differs from `get` in that, rather than accessing the given value in the data structure,
 it removes all non-selected values from the structure. Hence, using `select` on a table will
-produce a table, a list will produce a list, and a record will produce a record."#
+produce a table, a list will produce a list, and a record will produce a record.
+
+`--paths` is an alternative to the positional cell paths, for when the columns to keep
+aren't known ahead of time or differ from row to row. A `*` segment matches any record
+column or list index, so `items.*.id` pulls the `id` out of every element of `items`,
+however many there are. Since wildcards are resolved separately for each row, rows whose
+`items` don't share the same shape are each matched on their own terms."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -54,6 +67,26 @@ produce a table, a list will produce a list, and a record will produce a record.
         input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
         let columns: Vec<Value> = call.rest(engine_state, stack, 0)?;
+        let paths: Option<Vec<Spanned<String>>> = call.get_flag(engine_state, stack, "paths")?;
+        let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
+        let span = call.head;
+
+        if let Some(paths) = paths {
+            if !columns.is_empty() {
+                return Err(ShellError::IncompatibleParametersSingle {
+                    msg: "`--paths` can't be used together with positional cell paths".into(),
+                    span,
+                });
+            }
+
+            let patterns = paths
+                .iter()
+                .map(|p| parse_cell_path_pattern(&p.item, p.span))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            return select_paths(engine_state, span, patterns, ignore_errors, input);
+        }
+
         let mut new_columns: Vec<CellPath> = vec![];
         for col_val in columns {
             let col_span = col_val.span();
@@ -99,8 +132,6 @@ produce a table, a list will produce a list, and a record will produce a record.
                 }
             }
         }
-        let ignore_errors = call.has_flag(engine_state, stack, "ignore-errors")?;
-        let span = call.head;
 
         if ignore_errors {
             for cell_path in &mut new_columns {
@@ -167,10 +198,48 @@ produce a table, a list will produce a list, and a record will produce a record.
                     }),
                 ]))
             },
+            Example {
+                description: "Pull a nested field out of every element of a list, without an `each`/`get` chain",
+                example: "{items: [{id: 1, name: a}, {id: 2, name: b}]} | select --paths [items.*.id]",
+                result: Some(Value::test_record(record! {
+                    "items.0.id" => Value::test_int(1),
+                    "items.1.id" => Value::test_int(2),
+                })),
+            },
         ]
     }
 }
 
+fn select_paths(
+    engine_state: &EngineState,
+    call_span: Span,
+    patterns: Vec<CellPathPattern>,
+    ignore_errors: bool,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    input.map(
+        move |value| {
+            let mut record = Record::new();
+
+            for pattern in &patterns {
+                for path in expand_cell_path_pattern(&value, pattern, call_span) {
+                    match value.clone().follow_cell_path(&path.members, false) {
+                        Ok(fetcher) => record.push(path.to_column_name(), fetcher),
+                        Err(e) => {
+                            if !ignore_errors {
+                                return Value::error(e, call_span);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Value::record(record, value.span())
+        },
+        engine_state.signals(),
+    )
+}
+
 fn select(
     engine_state: &EngineState,
     call_span: Span,
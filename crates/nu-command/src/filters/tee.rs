@@ -28,7 +28,8 @@ impl Command for Tee {
 
     fn extra_description(&self) -> &str {
         r#"This is useful for doing something else with a stream while still continuing to
-use it in your pipeline."#
+use it in your pipeline. Multiple closures may be given, and each one runs on its own
+thread, concurrently, without any of them having to re-read the source."#
     }
 
     fn signature(&self) -> Signature {
@@ -39,11 +40,23 @@ use it in your pipeline."#
                 "For external commands: copy the standard error stream instead.",
                 Some('e'),
             )
+            .named(
+                "buffer-size",
+                SyntaxShape::Int,
+                "Bound each branch's internal channel to this many elements, so a slow branch \
+                    applies backpressure instead of buffering unboundedly.",
+                None,
+            )
             .required(
                 "closure",
                 SyntaxShape::Closure(None),
                 "The other command to send the stream to.",
             )
+            .rest(
+                "more_closures",
+                SyntaxShape::Closure(None),
+                "Additional commands to send the stream to, each on its own branch.",
+            )
             .category(Category::Filters)
     }
 
@@ -70,7 +83,12 @@ use it in your pipeline."#
                 example: "10000 | tee { 1..$in | print } | $in * 5",
                 description: "Do something with a value on another thread, while also passing through the value",
                 result: Some(Value::test_int(50000)),
-            }
+            },
+            Example {
+                example: "open raw.csv | tee { save raw-copy.csv } { from csv | save parsed.nuon } | ignore",
+                description: "Save a raw copy of a stream while separately parsing and saving it, without reading it twice",
+                result: None,
+            },
         ]
     }
 
@@ -84,31 +102,41 @@ use it in your pipeline."#
         let head = call.head;
         let from_io_error = IoError::factory(head, None);
         let use_stderr = call.has_flag(engine_state, stack, "stderr")?;
+        let buffer_size: Option<usize> = call.get_flag(engine_state, stack, "buffer-size")?;
 
-        let closure: Spanned<Closure> = call.req(engine_state, stack, 0)?;
-        let closure_span = closure.span;
-        let closure = closure.item;
+        let first_closure: Spanned<Closure> = call.req(engine_state, stack, 0)?;
+        let closure_span = first_closure.span;
+        let mut closures = vec![first_closure.item];
+        closures.extend(
+            call.rest::<Spanned<Closure>>(engine_state, stack, 1)?
+                .into_iter()
+                .map(|c| c.item),
+        );
 
         let engine_state_arc = Arc::new(engine_state.clone());
 
-        let mut eval_block = {
-            let closure_engine_state = engine_state_arc.clone();
-            let mut closure_stack = stack
-                .captures_to_stack_preserve_out_dest(closure.captures)
-                .reset_pipes();
-            let eval_block_with_early_return = get_eval_block_with_early_return(engine_state);
-
-            move |input| {
-                let result = eval_block_with_early_return(
-                    &closure_engine_state,
-                    &mut closure_stack,
-                    closure_engine_state.get_block(closure.block_id),
-                    input,
-                );
-                // Make sure to drain any iterator produced to avoid unexpected behavior
-                result.and_then(|data| data.drain().map(|_| ()))
-            }
-        };
+        let mut eval_blocks: Vec<EvalBlockFn> = closures
+            .into_iter()
+            .map(|closure| {
+                let closure_engine_state = engine_state_arc.clone();
+                let mut closure_stack = stack
+                    .captures_to_stack_preserve_out_dest(closure.captures)
+                    .reset_pipes();
+                let eval_block_with_early_return = get_eval_block_with_early_return(engine_state);
+
+                let block: EvalBlockFn = Box::new(move |input| {
+                    let result = eval_block_with_early_return(
+                        &closure_engine_state,
+                        &mut closure_stack,
+                        closure_engine_state.get_block(closure.block_id),
+                        input,
+                    );
+                    // Make sure to drain any iterator produced to avoid unexpected behavior
+                    result.and_then(|data| data.drain().map(|_| ()))
+                });
+                block
+            })
+            .collect();
 
         // Convert values that can be represented as streams into streams. Streams can pass errors
         // through later, so if we treat string/binary/list as a stream instead, it's likely that
@@ -126,6 +154,7 @@ use it in your pipeline."#
                 signals: engine_state.signals().clone(),
                 type_,
                 metadata: metadata.clone(),
+                buffer_size,
             };
 
             match stream.into_source() {
@@ -134,8 +163,8 @@ use it in your pipeline."#
                         return stderr_misuse(span, head);
                     }
 
-                    let tee_thread = spawn_tee(info, eval_block)?;
-                    let tee = IoTee::new(read, tee_thread);
+                    let tee_threads = spawn_tees(&info, eval_blocks)?;
+                    let tee = IoTee::new(read, tee_threads);
 
                     Ok(PipelineData::ByteStream(
                         ByteStream::read(tee, span, engine_state.signals().clone(), type_),
@@ -147,8 +176,8 @@ use it in your pipeline."#
                         return stderr_misuse(span, head);
                     }
 
-                    let tee_thread = spawn_tee(info, eval_block)?;
-                    let tee = IoTee::new(file, tee_thread);
+                    let tee_threads = spawn_tees(&info, eval_blocks)?;
+                    let tee = IoTee::new(file, tee_threads);
 
                     Ok(PipelineData::ByteStream(
                         ByteStream::read(tee, span, engine_state.signals().clone(), type_),
@@ -159,8 +188,8 @@ use it in your pipeline."#
                 ByteStreamSource::Child(mut child) => {
                     let stderr_thread = if use_stderr {
                         let stderr_thread = if let Some(stderr) = child.stderr.take() {
-                            let tee_thread = spawn_tee(info.clone(), eval_block)?;
-                            let tee = IoTee::new(stderr, tee_thread);
+                            let tee_threads = spawn_tees(&info, eval_blocks)?;
+                            let tee = IoTee::new(stderr, tee_threads);
                             match stack.stderr() {
                                 OutDest::Pipe | OutDest::PipeSeparate | OutDest::Value => {
                                     child.stderr = Some(ChildPipe::Tee(Box::new(tee)));
@@ -216,8 +245,8 @@ use it in your pipeline."#
                         };
 
                         if let Some(stdout) = child.stdout.take() {
-                            let tee_thread = spawn_tee(info.clone(), eval_block)?;
-                            let tee = IoTee::new(stdout, tee_thread);
+                            let tee_threads = spawn_tees(&info, eval_blocks)?;
+                            let tee = IoTee::new(stdout, tee_threads);
                             match stack.stdout() {
                                 OutDest::Pipe | OutDest::PipeSeparate | OutDest::Value => {
                                     child.stdout = Some(ChildPipe::Tee(Box::new(tee)));
@@ -260,26 +289,39 @@ use it in your pipeline."#
                 // really do that
                 let signals = engine_state.signals().clone();
 
-                Ok(tee(input.into_iter(), move |rx| {
-                    let input = rx.into_pipeline_data_with_metadata(span, signals, metadata_clone);
-                    eval_block(input)
-                })
-                .map_err(&from_io_error)?
-                .map(move |result| result.unwrap_or_else(|err| Value::error(err, closure_span)))
-                .into_pipeline_data_with_metadata(
-                    span,
-                    engine_state.signals().clone(),
-                    metadata,
-                ))
+                let consumers: Vec<TeeConsumer<Value>> = eval_blocks
+                    .drain(..)
+                    .map(|mut eval_block| -> TeeConsumer<Value> {
+                        let signals = signals.clone();
+                        let metadata_clone = metadata_clone.clone();
+                        Box::new(move |rx| {
+                            let input =
+                                rx.into_pipeline_data_with_metadata(span, signals, metadata_clone);
+                            eval_block(input)
+                        })
+                    })
+                    .collect();
+
+                Ok(tee(input.into_iter(), consumers, buffer_size)
+                    .map_err(&from_io_error)?
+                    .map(move |result| result.unwrap_or_else(|err| Value::error(err, closure_span)))
+                    .into_pipeline_data_with_metadata(
+                        span,
+                        engine_state.signals().clone(),
+                        metadata,
+                    ))
             } else {
                 // Otherwise, we can spawn a thread with the input value, but we have nowhere to
                 // send an error to other than just trying to print it to stderr.
                 let value = input.into_value(span)?;
-                let value_clone = value.clone();
-                tee_once(engine_state_arc, move || {
-                    eval_block(value_clone.into_pipeline_data_with_metadata(metadata_clone))
-                })
-                .map_err(&from_io_error)?;
+                for mut eval_block in eval_blocks {
+                    let value_clone = value.clone();
+                    let metadata_clone = metadata_clone.clone();
+                    tee_once(engine_state_arc.clone(), move || {
+                        eval_block(value_clone.into_pipeline_data_with_metadata(metadata_clone))
+                    })
+                    .map_err(&from_io_error)?;
+                }
                 Ok(value.into_pipeline_data_with_metadata(metadata))
             }
         }
@@ -290,63 +332,120 @@ use it in your pipeline."#
     }
 }
 
-fn panic_error() -> ShellError {
+pub(crate) fn panic_error() -> ShellError {
     ShellError::NushellFailed {
         msg: "A panic occurred on a thread spawned by `tee`".into(),
     }
 }
 
-/// Copies the iterator to a channel on another thread. If an error is produced on that thread,
-/// it is embedded in the resulting iterator as an `Err` as soon as possible. When the iterator
-/// finishes, it waits for the other thread to finish, also handling any error produced at that
-/// point.
+type EvalBlockFn = Box<dyn FnMut(PipelineData) -> Result<(), ShellError> + Send>;
+type TeeConsumer<T> = Box<dyn FnOnce(TeeReceiver<T>) -> Result<(), ShellError> + Send>;
+
+/// Either end of a possibly-bounded channel, chosen based on `--buffer-size`.
+pub(crate) enum TeeReceiver<T> {
+    Unbounded(mpsc::Receiver<T>),
+    Bounded(mpsc::Receiver<T>),
+}
+
+impl<T> IntoIterator for TeeReceiver<T> {
+    type Item = T;
+    type IntoIter = mpsc::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            TeeReceiver::Unbounded(rx) | TeeReceiver::Bounded(rx) => rx.into_iter(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) enum TeeSender<T> {
+    Unbounded(Sender<T>),
+    Bounded(mpsc::SyncSender<T>),
+}
+
+impl<T> TeeSender<T> {
+    pub(crate) fn send(&self, value: T) -> Result<(), mpsc::SendError<T>> {
+        match self {
+            TeeSender::Unbounded(tx) => tx.send(value),
+            TeeSender::Bounded(tx) => tx.send(value).map_err(|e| mpsc::SendError(e.0)),
+        }
+    }
+}
+
+pub(crate) fn tee_channel<T>(buffer_size: Option<usize>) -> (TeeSender<T>, TeeReceiver<T>) {
+    match buffer_size {
+        Some(n) => {
+            let (tx, rx) = mpsc::sync_channel(n);
+            (TeeSender::Bounded(tx), TeeReceiver::Bounded(rx))
+        }
+        None => {
+            let (tx, rx) = mpsc::channel();
+            (TeeSender::Unbounded(tx), TeeReceiver::Unbounded(rx))
+        }
+    }
+}
+
+/// Copies the iterator to a channel on another thread for each consumer. If an error is produced
+/// on any of those threads, it is embedded in the resulting iterator as an `Err` as soon as
+/// possible. When the iterator finishes, it waits for the other threads to finish, also handling
+/// any error produced at that point.
 fn tee<T>(
     input: impl Iterator<Item = T>,
-    with_cloned_stream: impl FnOnce(mpsc::Receiver<T>) -> Result<(), ShellError> + Send + 'static,
+    consumers: Vec<TeeConsumer<T>>,
+    buffer_size: Option<usize>,
 ) -> Result<impl Iterator<Item = Result<T, ShellError>>, std::io::Error>
 where
     T: Clone + Send + 'static,
 {
-    // For sending the values to the other thread
-    let (tx, rx) = mpsc::channel();
+    let mut senders = Vec::with_capacity(consumers.len());
+    let mut threads = Vec::with_capacity(consumers.len());
 
-    let mut thread = Some(
-        thread::Builder::new()
+    for consumer in consumers {
+        let (tx, rx) = tee_channel(buffer_size);
+        let thread = thread::Builder::new()
             .name("tee".into())
-            .spawn(move || with_cloned_stream(rx))?,
-    );
+            .spawn(move || consumer(rx))?;
+        senders.push(Some(tx));
+        threads.push(Some(thread));
+    }
 
     let mut iter = input.into_iter();
-    let mut tx = Some(tx);
 
     Ok(std::iter::from_fn(move || {
-        if thread.as_ref().is_some_and(|t| t.is_finished()) {
-            // Check for an error from the other thread
-            let result = thread
-                .take()
-                .expect("thread was taken early")
-                .join()
-                .unwrap_or_else(|_| Err(panic_error()));
-            if let Err(err) = result {
-                // Embed the error early
-                return Some(Err(err));
+        for thread in threads.iter_mut() {
+            if thread.as_ref().is_some_and(|t| t.is_finished()) {
+                let result = thread
+                    .take()
+                    .expect("thread was taken early")
+                    .join()
+                    .unwrap_or_else(|_| Err(panic_error()));
+                if let Err(err) = result {
+                    return Some(Err(err));
+                }
             }
         }
 
-        // Get a value from the iterator
         if let Some(value) = iter.next() {
-            // Send a copy, ignoring any error if the channel is closed
-            let _ = tx.as_ref().map(|tx| tx.send(value.clone()));
+            for sender in senders.iter_mut() {
+                if let Some(tx) = sender.as_ref() {
+                    if tx.send(value.clone()).is_err() {
+                        *sender = None;
+                    }
+                }
+            }
             Some(Ok(value))
         } else {
-            // Close the channel so the stream ends for the other thread
-            drop(tx.take());
-            // Wait for the other thread, and embed any error produced
-            thread.take().and_then(|t| {
-                t.join()
-                    .unwrap_or_else(|_| Err(panic_error()))
-                    .err()
-                    .map(Err)
+            for sender in senders.iter_mut() {
+                sender.take();
+            }
+            threads.iter_mut().find_map(|thread| {
+                thread.take().and_then(|t| {
+                    t.join()
+                        .unwrap_or_else(|_| Err(panic_error()))
+                        .err()
+                        .map(Err)
+                })
             })
         }
     }))
@@ -375,42 +474,59 @@ fn stderr_misuse<T>(span: Span, head: Span) -> Result<T, ShellError> {
 
 struct IoTee<R: Read> {
     reader: R,
-    sender: Option<Sender<Vec<u8>>>,
-    thread: Option<JoinHandle<Result<(), ShellError>>>,
+    senders: Vec<Option<TeeSender<Vec<u8>>>>,
+    threads: Vec<Option<JoinHandle<Result<(), ShellError>>>>,
 }
 
 impl<R: Read> IoTee<R> {
-    fn new(reader: R, tee: TeeThread) -> Self {
+    fn new(reader: R, tees: Vec<TeeThread>) -> Self {
+        let mut senders = Vec::with_capacity(tees.len());
+        let mut threads = Vec::with_capacity(tees.len());
+        for tee in tees {
+            senders.push(Some(tee.sender));
+            threads.push(Some(tee.thread));
+        }
         Self {
             reader,
-            sender: Some(tee.sender),
-            thread: Some(tee.thread),
+            senders,
+            threads,
         }
     }
 }
 
 impl<R: Read> Read for IoTee<R> {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        if let Some(thread) = self.thread.take() {
-            if thread.is_finished() {
-                if let Err(err) = thread.join().unwrap_or_else(|_| Err(panic_error())) {
+        for thread in self.threads.iter_mut() {
+            if thread.as_ref().is_some_and(|t| t.is_finished()) {
+                if let Err(err) = thread
+                    .take()
+                    .expect("thread was taken early")
+                    .join()
+                    .unwrap_or_else(|_| Err(panic_error()))
+                {
                     return Err(io::Error::other(err));
                 }
-            } else {
-                self.thread = Some(thread)
             }
         }
         let len = self.reader.read(buf)?;
         if len == 0 {
-            self.sender = None;
-            if let Some(thread) = self.thread.take() {
-                if let Err(err) = thread.join().unwrap_or_else(|_| Err(panic_error())) {
-                    return Err(io::Error::other(err));
+            for sender in self.senders.iter_mut() {
+                sender.take();
+            }
+            for thread in self.threads.iter_mut() {
+                if let Some(thread) = thread.take() {
+                    if let Err(err) = thread.join().unwrap_or_else(|_| Err(panic_error())) {
+                        return Err(io::Error::other(err));
+                    }
                 }
             }
-        } else if let Some(sender) = self.sender.as_mut() {
-            if sender.send(buf[..len].to_vec()).is_err() {
-                self.sender = None;
+        } else {
+            for sender in self.senders.iter_mut() {
+                if let Some(tx) = sender.as_ref() {
+                    if tx.send(buf[..len].to_vec()).is_err() {
+                        *sender = None;
+                    }
+                }
             }
         }
         Ok(len)
@@ -418,27 +534,22 @@ impl<R: Read> Read for IoTee<R> {
 }
 
 struct TeeThread {
-    sender: Sender<Vec<u8>>,
+    sender: TeeSender<Vec<u8>>,
     thread: JoinHandle<Result<(), ShellError>>,
 }
 
-fn spawn_tee(
-    info: StreamInfo,
-    mut eval_block: impl FnMut(PipelineData) -> Result<(), ShellError> + Send + 'static,
-) -> Result<TeeThread, ShellError> {
-    let (sender, receiver) = mpsc::channel();
+fn spawn_tee(info: &StreamInfo, mut eval_block: EvalBlockFn) -> Result<TeeThread, ShellError> {
+    let (sender, receiver) = tee_channel(info.buffer_size);
+    let span = info.span;
+    let type_ = info.type_;
+    let metadata = info.metadata.clone();
 
     let thread = thread::Builder::new()
         .name("tee".into())
         .spawn(move || {
             // We use Signals::empty() here because we assume there already is a Signals on the other side
-            let stream = ByteStream::from_iter(
-                receiver.into_iter(),
-                info.span,
-                Signals::empty(),
-                info.type_,
-            );
-            eval_block(PipelineData::ByteStream(stream, info.metadata))
+            let stream = ByteStream::from_iter(receiver.into_iter(), span, Signals::empty(), type_);
+            eval_block(PipelineData::ByteStream(stream, metadata))
         })
         .map_err(|err| {
             IoError::new_with_additional_context(err.kind(), info.span, None, "Could not spawn tee")
@@ -447,12 +558,23 @@ fn spawn_tee(
     Ok(TeeThread { sender, thread })
 }
 
+fn spawn_tees(
+    info: &StreamInfo,
+    eval_blocks: Vec<EvalBlockFn>,
+) -> Result<Vec<TeeThread>, ShellError> {
+    eval_blocks
+        .into_iter()
+        .map(|eval_block| spawn_tee(info, eval_block))
+        .collect()
+}
+
 #[derive(Clone)]
 struct StreamInfo {
     span: Span,
     signals: Signals,
     type_: ByteStreamType,
     metadata: Option<PipelineMetadata>,
+    buffer_size: Option<usize>,
 }
 
 fn copy(src: impl Read, dest: impl Write, info: &StreamInfo) -> Result<(), ShellError> {
@@ -510,15 +632,17 @@ fn tee_copies_values_to_other_thread_and_passes_them_through() {
 
     let expected_values = vec![1, 2, 3, 4];
 
-    let my_result = tee(expected_values.clone().into_iter(), move |rx| {
+    let consumer: TeeConsumer<i32> = Box::new(move |rx| {
         for val in rx {
             let _ = tx.send(val);
         }
         Ok(())
-    })
-    .expect("io error")
-    .collect::<Result<Vec<i32>, ShellError>>()
-    .expect("should not produce error");
+    });
+
+    let my_result = tee(expected_values.clone().into_iter(), vec![consumer], None)
+        .expect("io error")
+        .collect::<Result<Vec<i32>, ShellError>>()
+        .expect("should not produce error");
 
     assert_eq!(expected_values, my_result);
 
@@ -527,19 +651,53 @@ fn tee_copies_values_to_other_thread_and_passes_them_through() {
     assert_eq!(expected_values, other_threads_result);
 }
 
+#[test]
+fn tee_copies_values_to_multiple_branches() {
+    let (tx1, rx1) = mpsc::channel();
+    let (tx2, rx2) = mpsc::channel();
+
+    let expected_values = vec![1, 2, 3, 4];
+
+    let consumer1: TeeConsumer<i32> = Box::new(move |rx| {
+        for val in rx {
+            let _ = tx1.send(val);
+        }
+        Ok(())
+    });
+    let consumer2: TeeConsumer<i32> = Box::new(move |rx| {
+        for val in rx {
+            let _ = tx2.send(val);
+        }
+        Ok(())
+    });
+
+    let my_result = tee(
+        expected_values.clone().into_iter(),
+        vec![consumer1, consumer2],
+        None,
+    )
+    .expect("io error")
+    .collect::<Result<Vec<i32>, ShellError>>()
+    .expect("should not produce error");
+
+    assert_eq!(expected_values, my_result);
+    assert_eq!(expected_values, rx1.into_iter().collect::<Vec<_>>());
+    assert_eq!(expected_values, rx2.into_iter().collect::<Vec<_>>());
+}
+
 #[test]
 fn tee_forwards_errors_back_immediately() {
     use std::time::Duration;
     let slow_input = (0..100).inspect(|_| std::thread::sleep(Duration::from_millis(1)));
-    let iter = tee(slow_input, |_| {
+    let consumer: TeeConsumer<i32> = Box::new(|_| {
         Err(ShellError::Io(IoError::new_with_additional_context(
             std::io::ErrorKind::Other,
             Span::test_data(),
             None,
             "test",
         )))
-    })
-    .expect("io error");
+    });
+    let iter = tee(slow_input, vec![consumer], None).expect("io error");
     for result in iter {
         if let Ok(val) = result {
             // should not make it to the end
@@ -561,7 +719,7 @@ fn tee_waits_for_the_other_thread() {
     use std::time::Duration;
     let waited = Arc::new(AtomicBool::new(false));
     let waited_clone = waited.clone();
-    let iter = tee(0..100, move |_| {
+    let consumer: TeeConsumer<i32> = Box::new(move |_| {
         std::thread::sleep(Duration::from_millis(10));
         waited_clone.store(true, Ordering::Relaxed);
         Err(ShellError::Io(IoError::new_with_additional_context(
@@ -570,8 +728,8 @@ fn tee_waits_for_the_other_thread() {
             None,
             "test",
         )))
-    })
-    .expect("io error");
+    });
+    let iter = tee(0..100, vec![consumer], None).expect("io error");
     let last = iter.last();
     assert!(waited.load(Ordering::Relaxed), "failed to wait");
     assert!(
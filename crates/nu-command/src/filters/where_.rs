@@ -16,7 +16,14 @@ impl Command for Where {
     fn extra_description(&self) -> &str {
         r#"This command works similar to 'filter' but allows extra shorthands for working with
 tables, known as "row conditions". On the other hand, reading the condition from a variable is
-not supported."#
+not supported.
+
+Note that the row condition is only ever evaluated here, against values already produced by
+whatever is upstream: there's no negotiation with the source command, so `open file.csv | where
+col == x` still parses and reads the whole file before any row is discarded. Pushing predicates
+like this down into a source (skipping rows a reader like `open` or `ls` could otherwise cheaply
+exclude) would need those commands to expose something a filter could introspect and act on,
+which none of them currently do."#
     }
 
     fn command_type(&self) -> CommandType {
@@ -23,6 +23,18 @@ impl Command for Generate {
                 "Generator function.",
             )
             .optional("initial", SyntaxShape::Any, "Initial value.")
+            .named(
+                "until",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Stop generating once this predicate returns true for a generated value.",
+                None,
+            )
+            .named(
+                "take",
+                SyntaxShape::Int,
+                "Stop generating after this many values have been produced.",
+                None,
+            )
             .allow_variants_without_examples(true)
             .category(Category::Generators)
     }
@@ -40,7 +52,20 @@ used as the next argument to the closure, otherwise generation stops.
 Additionally, if an input stream is provided, the generator closure accepts two
 arguments. On each invocation an element of the input stream is provided as the
 first argument. The second argument is the `next` value from the last invocation.
-In this case, generation also stops when the input stream stops."#
+In this case, generation also stops when the input stream stops.
+
+Values are produced lazily: the closure is not invoked again until a downstream
+command asks for the next value, so an infinite generator (one whose 'next' key
+never disappears on its own) is safe as long as something in the pipeline
+eventually stops pulling, such as `first`, `take`, or `--until`/`--take` given
+directly to `generate`. Without such a limit, an infinite generator piped into
+a command that must consume the whole stream (like `length` or `sort`) will
+never finish, since nothing ever applies backpressure to stop the pulls.
+
+`--until` and `--take` bound the stream up front, so `generate` itself can feed
+a collector safely instead of relying on the rest of the pipeline to cut it
+off. `--until` excludes the value that first matches its predicate, matching
+`take until`'s semantics."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -89,6 +114,24 @@ In this case, generation also stops when the input stream stops."#
                     Value::test_int(15),
                 ])),
             },
+            Example {
+                example: "generate {|i| {out: $i, next: ($i + 2)} } 0 --take 3",
+                description: "Bound an infinite generator to a fixed number of values",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(0),
+                    Value::test_int(2),
+                    Value::test_int(4),
+                ])),
+            },
+            Example {
+                example: "generate {|i| {out: $i, next: ($i + 2)} } 0 --until {|i| $i > 4 }",
+                description: "Stop an infinite generator once a predicate matches",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(0),
+                    Value::test_int(2),
+                    Value::test_int(4),
+                ])),
+            },
         ]
     }
 
@@ -102,8 +145,11 @@ In this case, generation also stops when the input stream stops."#
         let head = call.head;
         let closure: Closure = call.req(engine_state, stack, 0)?;
         let initial: Option<Value> = call.opt(engine_state, stack, 1)?;
+        let until: Option<Closure> = call.get_flag(engine_state, stack, "until")?;
+        let take: Option<Spanned<i64>> = call.get_flag(engine_state, stack, "take")?;
         let block = engine_state.get_block(closure.block_id);
         let mut closure = ClosureEval::new(engine_state, stack, closure);
+        let until = until.map(|closure| ClosureEval::new(engine_state, stack, closure));
 
         match input {
             PipelineData::Empty => {
@@ -126,9 +172,8 @@ In this case, generation also stops when the input stream stops."#
                     Some(output)
                 });
 
-                Ok(iter
-                    .flatten()
-                    .into_pipeline_data(call.head, engine_state.signals().clone()))
+                let iter = apply_bounds(Box::new(iter.flatten()), until, take, head)?;
+                Ok(iter.into_pipeline_data(call.head, engine_state.signals().clone()))
             }
             input @ (PipelineData::Value(Value::Range { .. }, ..)
             | PipelineData::Value(Value::List { .. }, ..)
@@ -144,9 +189,8 @@ In this case, generation also stops when the input stream stops."#
                     state = next_input;
                     Some(output)
                 });
-                Ok(iter
-                    .flatten()
-                    .into_pipeline_data(call.head, engine_state.signals().clone()))
+                let iter = apply_bounds(Box::new(iter.flatten()), until, take, head)?;
+                Ok(iter.into_pipeline_data(call.head, engine_state.signals().clone()))
             }
             _ => Err(ShellError::PipelineMismatch {
                 exp_input_type: "nothing".to_string(),
@@ -157,6 +201,35 @@ In this case, generation also stops when the input stream stops."#
     }
 }
 
+fn apply_bounds(
+    iter: Box<dyn Iterator<Item = Value> + Send>,
+    until: Option<ClosureEval>,
+    take: Option<Spanned<i64>>,
+    head: Span,
+) -> Result<Box<dyn Iterator<Item = Value> + Send>, ShellError> {
+    let iter: Box<dyn Iterator<Item = Value> + Send> = match until {
+        Some(mut until) => Box::new(iter.take_while(move |value| {
+            until
+                .run_with_value(value.clone())
+                .and_then(|data| data.into_value(head))
+                .map(|cond| cond.is_false())
+                .unwrap_or(false)
+        })),
+        None => iter,
+    };
+
+    let iter: Box<dyn Iterator<Item = Value> + Send> = match take {
+        Some(take) => {
+            let count = usize::try_from(take.item)
+                .map_err(|_| ShellError::NeedsPositiveValue { span: take.span })?;
+            Box::new(iter.take(count))
+        }
+        None => iter,
+    };
+
+    Ok(iter)
+}
+
 fn get_initial_state(
     initial: Option<Value>,
     signature: &Signature,
@@ -1,11 +1,13 @@
 mod cal;
 mod generate;
+mod poll;
 mod seq;
 mod seq_char;
 mod seq_date;
 
 pub use cal::Cal;
 pub use generate::Generate;
+pub use poll::Poll;
 pub use seq::Seq;
 pub use seq_char::SeqChar;
 pub use seq_date::SeqDate;
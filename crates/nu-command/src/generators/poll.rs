@@ -0,0 +1,161 @@
+use nu_engine::{command_prelude::*, ClosureEval};
+use nu_protocol::engine::Closure;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+const CTRL_C_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Clone)]
+pub struct Poll;
+
+impl Command for Poll {
+    fn name(&self) -> &str {
+        "poll"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("poll")
+            .input_output_types(vec![(Type::Nothing, Type::list(Type::Any))])
+            .required_named(
+                "every",
+                SyntaxShape::Duration,
+                "Interval to wait between invocations.",
+                Some('e'),
+            )
+            .required(
+                "closure",
+                SyntaxShape::Closure(Some(vec![])),
+                "Closure to invoke on every interval.",
+            )
+            .named(
+                "times",
+                SyntaxShape::Int,
+                "Stop after this many invocations.",
+                None,
+            )
+            .named(
+                "until",
+                SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                "Stop once this predicate returns true for an invocation's result.",
+                None,
+            )
+            .category(Category::Generators)
+    }
+
+    fn description(&self) -> &str {
+        "Periodically invoke a closure, emitting each result as a stream."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The closure is invoked immediately, and then again after every `--every`
+interval, until interrupted with Ctrl-C or stopped by `--times`/`--until`. Like
+other streams, results are only produced as something downstream pulls them,
+so `poll --every 5sec { http get https://example.com/status } | each {|r| print $r}`
+polls at the given interval for as long as something keeps consuming the stream.
+
+`--times n` stops after n invocations. `--until` stops (excluding the matching
+result) the first time its predicate returns true, matching `take until`'s
+semantics."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["watch", "interval", "loop", "stream", "dashboard"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let every: Option<i64> = call.get_flag(engine_state, stack, "every")?;
+        let every = every.ok_or(ShellError::MissingParameter {
+            param_name: "every".into(),
+            span: head,
+        })?;
+        let every = Duration::from_nanos(every.max(0) as u64);
+        let closure: Closure = call.req(engine_state, stack, 1)?;
+        let times: Option<i64> = call.get_flag(engine_state, stack, "times")?;
+        let until: Option<Closure> = call.get_flag(engine_state, stack, "until")?;
+
+        let mut closure = ClosureEval::new(engine_state, stack, closure);
+        let mut until = until.map(|closure| ClosureEval::new(engine_state, stack, closure));
+        let signals = engine_state.signals().clone();
+
+        let mut invocations = 0i64;
+        let mut first = true;
+        let mut stopped = false;
+
+        let iter = std::iter::from_fn(move || {
+            if stopped {
+                return None;
+            }
+            if let Some(times) = times {
+                if invocations >= times {
+                    return None;
+                }
+            }
+
+            if first {
+                first = false;
+            } else {
+                let deadline = Instant::now() + every;
+                loop {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        break;
+                    }
+                    thread::sleep(CTRL_C_CHECK_INTERVAL.min(remaining));
+                    if signals.check(head).is_err() {
+                        stopped = true;
+                        return None;
+                    }
+                }
+            }
+
+            invocations += 1;
+            let result = match closure.run_with_input(PipelineData::Empty) {
+                Ok(data) => match data.into_value(head) {
+                    Ok(value) => value,
+                    Err(err) => Value::error(err, head),
+                },
+                Err(err) => Value::error(err, head),
+            };
+
+            if let Some(until) = until.as_mut() {
+                let matched = until
+                    .run_with_value(result.clone())
+                    .and_then(|data| data.into_value(head))
+                    .map(|cond| cond.is_true())
+                    .unwrap_or(false);
+                if matched {
+                    stopped = true;
+                    return None;
+                }
+            }
+
+            Some(result)
+        });
+
+        Ok(iter.into_pipeline_data(head, engine_state.signals().clone()))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Poll a value every second for three iterations",
+                example: "poll --every 1sec --times 3 { random int 0..10 }",
+                result: None,
+            },
+            Example {
+                description: "Poll until a predicate on the result is met",
+                example: "poll --every 1sec { random int 0..10 } --until {|x| $x > 8 }",
+                result: None,
+            },
+        ]
+    }
+}
@@ -20,6 +20,10 @@ impl Command for Seq {
         "Output sequences of numbers."
     }
 
+    fn is_const(&self) -> bool {
+        true
+    }
+
     fn run(
         &self,
         engine_state: &EngineState,
@@ -27,7 +31,27 @@ impl Command for Seq {
         call: &Call,
         _input: PipelineData,
     ) -> Result<PipelineData, ShellError> {
-        seq(engine_state, stack, call)
+        let rest_nums: Vec<Spanned<f64>> = call.rest(engine_state, stack, 0)?;
+        let rest_nums_check: Result<Vec<Spanned<i64>>, ShellError> =
+            call.rest(engine_state, stack, 0);
+        seq(call.head, rest_nums, rest_nums_check.is_err(), engine_state)
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let rest_nums: Vec<Spanned<f64>> = call.rest_const(working_set, 0)?;
+        let rest_nums_check: Result<Vec<Spanned<i64>>, ShellError> =
+            call.rest_const(working_set, 0);
+        seq(
+            call.head,
+            rest_nums,
+            rest_nums_check.is_err(),
+            working_set.permanent(),
+        )
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
@@ -80,26 +104,22 @@ impl Command for Seq {
     }
 }
 
+// note that the check for int or float has to occur before generating the sequence. prior, the
+// check would occur after everything had been generated; this does not work well with
+// ListStreams. As such, callers check whether parsing the arguments as ints errors out: that
+// means there is a float in the input, which necessarily means that parts of the output will be
+// floats.
 fn seq(
+    span: Span,
+    rest_nums: Vec<Spanned<f64>>,
+    contains_decimals: bool,
     engine_state: &EngineState,
-    stack: &mut Stack,
-    call: &Call,
 ) -> Result<PipelineData, ShellError> {
-    let span = call.head;
-    let rest_nums: Vec<Spanned<f64>> = call.rest(engine_state, stack, 0)?;
-
-    // note that the check for int or float has to occur here. prior, the check would occur after
-    // everything had been generated; this does not work well with ListStreams.
-    // As such, the simple test is to check if this errors out: that means there is a float in the
-    // input, which necessarily means that parts of the output will be floats.
-    let rest_nums_check: Result<Vec<Spanned<i64>>, ShellError> = call.rest(engine_state, stack, 0);
-    let contains_decimals = rest_nums_check.is_err();
-
     if rest_nums.is_empty() {
         return Err(ShellError::GenericError {
             error: "seq requires some parameters".into(),
             msg: "needs parameter".into(),
-            span: Some(call.head),
+            span: Some(span),
             help: None,
             inner: vec![],
         });
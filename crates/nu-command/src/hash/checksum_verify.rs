@@ -0,0 +1,205 @@
+use md5::Md5;
+use nu_engine::command_prelude::*;
+use nu_path::expand_path_with;
+use rayon::prelude::*;
+use sha2::Sha256;
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct ChecksumVerify;
+
+impl Command for ChecksumVerify {
+    fn name(&self) -> &str {
+        "checksum verify"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("checksum verify")
+            .input_output_types(vec![
+                (Type::Nothing, Type::table()),
+                (Type::table(), Type::table()),
+            ])
+            .optional(
+                "file",
+                SyntaxShape::Filepath,
+                "A checksum file listing expected hashes, one per line, in the format produced by tools like `sha256sum`.",
+            )
+            .named(
+                "algorithm",
+                SyntaxShape::String,
+                "The hash algorithm the expected hashes were computed with (`sha256` or `md5`). Defaults to sha256.",
+                Some('a'),
+            )
+            .category(Category::Hash)
+    }
+
+    fn description(&self) -> &str {
+        "Verify files against expected checksums."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Reads expected hashes either from a checksum file (as accepted by `sha256sum --check`)
+or from a piped table with `path` and `hash` columns, hashes each referenced file in parallel,
+and returns a table reporting whether each one matches. This replaces shelling out to
+`sha256sum -c` and parsing its text output."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["sha256sum", "md5sum", "hash", "verify", "integrity"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Verify files listed in a sha256sum-style checksum file",
+                example: "checksum verify sums.txt --algorithm sha256",
+                result: None,
+            },
+            Example {
+                description: "Verify a piped table of paths and expected hashes",
+                example: "[[path hash]; [foo.txt abc123]] | checksum verify",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cwd = engine_state.cwd(Some(stack))?;
+        let file: Option<Spanned<String>> = call.opt(engine_state, stack, 0)?;
+        let algorithm: Spanned<String> = call
+            .get_flag(engine_state, stack, "algorithm")?
+            .unwrap_or(Spanned {
+                item: "sha256".to_string(),
+                span: head,
+            });
+
+        let entries = match file {
+            Some(file) => {
+                let path = expand_path_with(&file.item, &cwd, true);
+                let contents = std::fs::read_to_string(&path).map_err(|err| ShellError::GenericError {
+                    error: format!("Could not read checksum file '{}'", path.display()),
+                    msg: err.to_string(),
+                    span: Some(file.span),
+                    help: None,
+                    inner: vec![],
+                })?;
+                parse_checksum_file(&contents, file.span)?
+            }
+            None => {
+                let value = input.into_value(head)?;
+                let table = value.into_list()?;
+                table
+                    .into_iter()
+                    .map(|row| {
+                        let span = row.span();
+                        let record = row.into_record()?;
+                        let path = record
+                            .get("path")
+                            .ok_or_else(|| ShellError::CantFindColumn {
+                                col_name: "path".into(),
+                                span: Some(span),
+                                src_span: span,
+                            })?
+                            .clone()
+                            .coerce_into_string()?;
+                        let hash = record
+                            .get("hash")
+                            .ok_or_else(|| ShellError::CantFindColumn {
+                                col_name: "hash".into(),
+                                span: Some(span),
+                                src_span: span,
+                            })?
+                            .clone()
+                            .coerce_into_string()?;
+                        Ok((path, hash))
+                    })
+                    .collect::<Result<Vec<_>, ShellError>>()?
+            }
+        };
+
+        let signals = engine_state.signals().clone();
+        let rows = entries
+            .into_par_iter()
+            .map(|(path, expected)| {
+                signals.check(head)?;
+                let full_path = expand_path_with(&path, &cwd, true);
+                verify_one(&algorithm, &path, &full_path, &expected, head)
+            })
+            .collect::<Result<Vec<_>, ShellError>>()?;
+
+        Ok(Value::list(rows, head).into_pipeline_data())
+    }
+}
+
+fn verify_one(
+    algorithm: &Spanned<String>,
+    display_path: &str,
+    full_path: &PathBuf,
+    expected: &str,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let expected = expected.to_lowercase();
+
+    let (status, actual) = match std::fs::read(full_path) {
+        Ok(bytes) => {
+            let actual = hash_bytes(&algorithm.item, &bytes, algorithm.span)?;
+            if actual == expected {
+                ("ok", Value::string(actual, span))
+            } else {
+                ("failed", Value::string(actual, span))
+            }
+        }
+        Err(_) => ("missing", Value::nothing(span)),
+    };
+
+    Ok(Value::record(
+        record! {
+            "path" => Value::string(display_path, span),
+            "status" => Value::string(status, span),
+            "expected" => Value::string(expected, span),
+            "actual" => actual,
+        },
+        span,
+    ))
+}
+
+fn hash_bytes(algorithm: &str, bytes: &[u8], span: Span) -> Result<String, ShellError> {
+    use digest::Digest;
+
+    match algorithm {
+        "sha256" => Ok(format!("{:x}", Sha256::digest(bytes))),
+        "md5" => Ok(format!("{:x}", Md5::digest(bytes))),
+        other => Err(ShellError::InvalidValue {
+            valid: "sha256 or md5".into(),
+            actual: other.into(),
+            span,
+        }),
+    }
+}
+
+/// Parses the GNU coreutils `sha256sum`/`md5sum` checksum file format: each line is
+/// `<hash>  <path>` (two spaces, text mode) or `<hash> *<path>` (binary mode).
+fn parse_checksum_file(contents: &str, span: Span) -> Result<Vec<(String, String)>, ShellError> {
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (hash, rest) = line.split_once(' ').ok_or_else(|| ShellError::GenericError {
+                error: "Invalid checksum file".into(),
+                msg: format!("could not parse line: '{line}'"),
+                span: Some(span),
+                help: Some("expected lines like '<hash>  <path>'".into()),
+                inner: vec![],
+            })?;
+            let path = rest.strip_prefix(' ').or_else(|| rest.strip_prefix('*')).unwrap_or(rest);
+            Ok((path.to_string(), hash.to_string()))
+        })
+        .collect()
+}
@@ -143,6 +143,22 @@ pub fn help_aliases(
         long_desc.push_str("\n\n");
         long_desc.push_str(&format!("{G}Expansion{RESET}:\n  {alias_expansion}"));
 
+        if let Some((file, line)) = find_file_of_span(engine_state, alias.wrapped_call.span) {
+            long_desc.push_str("\n\n");
+            long_desc.push_str(&format!("{G}Source{RESET}: {file}:{line}"));
+        }
+
+        let examples = alias.examples();
+        if !examples.is_empty() {
+            long_desc.push_str("\n\n");
+            long_desc.push_str(&format!("{G}Examples{RESET}:"));
+            for example in examples {
+                long_desc.push_str("\n  ");
+                long_desc.push_str(example.description);
+                long_desc.push_str(&format!("\n  > {C}{}{RESET}", example.example));
+            }
+        }
+
         let config = stack.get_config(engine_state);
         if !config.use_ansi_coloring.get(engine_state) {
             long_desc = nu_utils::strip_ansi_string_likely(long_desc);
@@ -152,6 +168,21 @@ pub fn help_aliases(
     }
 }
 
+/// Find the file name and line number (indexed from 1) that a span was parsed from.
+fn find_file_of_span(engine_state: &EngineState, span: Span) -> Option<(&str, usize)> {
+    for file in engine_state.files() {
+        if file.covered_span.contains_span(span) {
+            let chunk =
+                engine_state.get_span_contents(Span::new(file.covered_span.start, span.start));
+            let line_num = chunk.iter().filter(|&&b| b == b'\n').count() + 1;
+
+            return Some((&file.name, line_num));
+        }
+    }
+
+    None
+}
+
 fn build_help_aliases(engine_state: &EngineState, stack: &Stack, span: Span) -> Vec<Value> {
     let mut scope_data = ScopeData::new(engine_state, stack);
     scope_data.populate_decls();
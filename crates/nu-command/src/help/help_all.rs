@@ -0,0 +1,119 @@
+use nu_engine::command_prelude::*;
+use nu_utils::IgnoreCaseExt;
+
+#[derive(Clone)]
+pub struct HelpAll;
+
+impl Command for HelpAll {
+    fn name(&self) -> &str {
+        "help all"
+    }
+
+    fn description(&self) -> &str {
+        "Show a unified view of every command, alias, and extern in scope."
+    }
+
+    fn extra_description(&self) -> &str {
+        "This combines the tables shown by `help commands`, `help aliases`, and `help externs` into \
+        a single table with a `kind` column, so the whole current scope can be explored in one query."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("help all")
+            .category(Category::Core)
+            .named(
+                "find",
+                SyntaxShape::String,
+                "string to find in names and descriptions",
+                Some('f'),
+            )
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .allow_variants_without_examples(true)
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "show every command, alias, and extern in scope",
+                example: "help all",
+                result: None,
+            },
+            Example {
+                description: "search for a string across names and descriptions",
+                example: "help all --find ls",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        help_all(engine_state, stack, call)
+    }
+}
+
+pub fn help_all(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<PipelineData, ShellError> {
+    let head = call.head;
+    let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
+
+    let mut found_cmds_vec = build_help_all(engine_state, head);
+
+    if let Some(find) = find {
+        let needle = find.item.to_folded_case();
+        found_cmds_vec.retain(|cmd| {
+            let name = cmd
+                .get_data_by_key("name")
+                .and_then(|val| val.as_str().ok().map(|s| s.to_string()))
+                .unwrap_or_default();
+            let description = cmd
+                .get_data_by_key("description")
+                .and_then(|val| val.as_str().ok().map(|s| s.to_string()))
+                .unwrap_or_default();
+
+            name.to_folded_case().contains(&needle) || description.to_folded_case().contains(&needle)
+        });
+    }
+
+    Ok(Value::list(found_cmds_vec, head).into_pipeline_data())
+}
+
+fn build_help_all(engine_state: &EngineState, span: Span) -> Vec<Value> {
+    let commands = engine_state.get_decls_sorted(false);
+
+    commands
+        .into_iter()
+        .map(|(_, decl_id)| {
+            let decl = engine_state.get_decl(decl_id);
+            let sig = decl.signature().update_from_command(decl);
+
+            Value::record(
+                record! {
+                    "name" => Value::string(sig.name, span),
+                    "kind" => Value::string(decl.command_type().to_string(), span),
+                    "category" => Value::string(sig.category.to_string(), span),
+                    "description" => Value::string(sig.description, span),
+                },
+                span,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_examples() {
+        use super::HelpAll;
+        use crate::test_examples;
+        test_examples(HelpAll {})
+    }
+}
@@ -1,6 +1,10 @@
-use crate::help::highlight_search_in_table;
-use nu_color_config::StyleComputer;
+use crate::help::help_render::{render_command_doc, DocFormat};
 use nu_engine::{command_prelude::*, get_full_help};
+use nu_utils::IgnoreCaseExt;
+use nucleo_matcher::{
+    pattern::{Atom, AtomKind, CaseMatching, Normalization},
+    Config, Matcher, Utf32Str,
+};
 
 #[derive(Clone)]
 pub struct HelpCommands;
@@ -25,9 +29,22 @@ impl Command for HelpCommands {
             .named(
                 "find",
                 SyntaxShape::String,
-                "string to find in command names, descriptions, and search terms",
+                "fuzzy search command names, descriptions, extra descriptions, search terms, \
+                and examples, showing the best matches first",
                 Some('f'),
             )
+            .named(
+                "search-term",
+                SyntaxShape::String,
+                "only show commands whose declared search terms contain this string",
+                None,
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "render the command's documentation as `markdown`, `html`, or `man` instead of plain text",
+                None,
+            )
             .input_output_types(vec![(Type::Nothing, Type::table())])
             .allow_variants_without_examples(true)
     }
@@ -50,26 +67,20 @@ pub fn help_commands(
 ) -> Result<PipelineData, ShellError> {
     let head = call.head;
     let find: Option<Spanned<String>> = call.get_flag(engine_state, stack, "find")?;
+    let search_term: Option<Spanned<String>> = call.get_flag(engine_state, stack, "search-term")?;
+    let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
     let rest: Vec<Spanned<String>> = call.rest(engine_state, stack, 0)?;
 
-    // 🚩The following two-lines are copied from filters/find.rs:
-    let style_computer = StyleComputer::from_config(engine_state, stack);
-    // Currently, search results all use the same style.
-    // Also note that this sample string is passed into user-written code (the closure that may or may not be
-    // defined for "string").
-    let string_style = style_computer.compute("string", &Value::string("search result", head));
-    let highlight_style =
-        style_computer.compute("search_result", &Value::string("search result", head));
-
     if let Some(f) = find {
-        let all_cmds_vec = build_help_commands(engine_state, head);
-        let found_cmds_vec = highlight_search_in_table(
-            all_cmds_vec,
-            &f.item,
-            &["name", "description", "search_terms"],
-            &string_style,
-            &highlight_style,
-        )?;
+        let found_cmds_vec = fuzzy_search_help_commands(engine_state, head, &f.item);
+        return Ok(Value::list(found_cmds_vec, head).into_pipeline_data());
+    }
+
+    if let Some(term) = search_term {
+        let found_cmds_vec = build_help_commands(engine_state, head)
+            .into_iter()
+            .filter(|cmd| command_matches_search_term(cmd, &term.item))
+            .collect();
 
         return Ok(Value::list(found_cmds_vec, head).into_pipeline_data());
     }
@@ -89,7 +100,18 @@ pub fn help_commands(
 
         if let Some(decl) = engine_state.find_decl(name.as_bytes(), &[]) {
             let cmd = engine_state.get_decl(decl);
-            let help_text = get_full_help(cmd, engine_state, stack);
+            let help_text = match format {
+                Some(format) => {
+                    let doc_format =
+                        DocFormat::from_str(&format.item).ok_or(ShellError::IncorrectValue {
+                            msg: "expected one of `markdown`, `html`, `man`".into(),
+                            val_span: format.span,
+                            call_span: call.head,
+                        })?;
+                    render_command_doc(cmd, doc_format)
+                }
+                None => get_full_help(cmd, engine_state, stack),
+            };
             Ok(Value::string(help_text, call.head).into_pipeline_data())
         } else {
             Err(ShellError::CommandNotFound {
@@ -101,121 +123,178 @@ pub fn help_commands(
 
 fn build_help_commands(engine_state: &EngineState, span: Span) -> Vec<Value> {
     let commands = engine_state.get_decls_sorted(false);
-    let mut found_cmds_vec = Vec::new();
 
-    for (_, decl_id) in commands {
-        let decl = engine_state.get_decl(decl_id);
-        let sig = decl.signature().update_from_command(decl);
+    commands
+        .into_iter()
+        .map(|(_, decl_id)| {
+            let decl = engine_state.get_decl(decl_id);
+            build_command_record(decl, span)
+        })
+        .collect()
+}
 
-        let key = sig.name;
-        let description = sig.description;
-        let search_terms = sig.search_terms;
-
-        let command_type = decl.command_type().to_string();
-
-        // Build table of parameters
-        let param_table = {
-            let mut vals = vec![];
-
-            for required_param in &sig.required_positional {
-                vals.push(Value::record(
-                    record! {
-                        "name" => Value::string(&required_param.name, span),
-                        "type" => Value::string(required_param.shape.to_string(), span),
-                        "required" => Value::bool(true, span),
-                        "description" => Value::string(&required_param.desc, span),
-                    },
-                    span,
-                ));
-            }
+fn build_command_record(decl: &dyn Command, span: Span) -> Value {
+    let sig = decl.signature().update_from_command(decl);
 
-            for optional_param in &sig.optional_positional {
-                vals.push(Value::record(
-                    record! {
-                        "name" => Value::string(&optional_param.name, span),
-                        "type" => Value::string(optional_param.shape.to_string(), span),
-                        "required" => Value::bool(false, span),
-                        "description" => Value::string(&optional_param.desc, span),
-                    },
-                    span,
-                ));
-            }
+    let key = sig.name;
+    let description = sig.description;
+    let extra_description = sig.extra_description;
+    let search_terms = sig.search_terms;
 
-            if let Some(rest_positional) = &sig.rest_positional {
-                vals.push(Value::record(
-                    record! {
-                        "name" => Value::string(format!("...{}", rest_positional.name), span),
-                        "type" => Value::string(rest_positional.shape.to_string(), span),
-                        "required" => Value::bool(false, span),
-                        "description" => Value::string(&rest_positional.desc, span),
-                    },
-                    span,
-                ));
-            }
+    let command_type = decl.command_type().to_string();
 
-            for named_param in &sig.named {
-                let name = if let Some(short) = named_param.short {
-                    if named_param.long.is_empty() {
-                        format!("-{}", short)
-                    } else {
-                        format!("--{}(-{})", named_param.long, short)
-                    }
-                } else {
-                    format!("--{}", named_param.long)
-                };
+    // Build table of parameters
+    let param_table = {
+        let mut vals = vec![];
+
+        for required_param in &sig.required_positional {
+            vals.push(Value::record(
+                record! {
+                    "name" => Value::string(&required_param.name, span),
+                    "type" => Value::string(required_param.shape.to_string(), span),
+                    "required" => Value::bool(true, span),
+                    "description" => Value::string(&required_param.desc, span),
+                },
+                span,
+            ));
+        }
+
+        for optional_param in &sig.optional_positional {
+            vals.push(Value::record(
+                record! {
+                    "name" => Value::string(&optional_param.name, span),
+                    "type" => Value::string(optional_param.shape.to_string(), span),
+                    "required" => Value::bool(false, span),
+                    "description" => Value::string(&optional_param.desc, span),
+                },
+                span,
+            ));
+        }
 
-                let typ = if let Some(arg) = &named_param.arg {
-                    arg.to_string()
+        if let Some(rest_positional) = &sig.rest_positional {
+            vals.push(Value::record(
+                record! {
+                    "name" => Value::string(format!("...{}", rest_positional.name), span),
+                    "type" => Value::string(rest_positional.shape.to_string(), span),
+                    "required" => Value::bool(false, span),
+                    "description" => Value::string(&rest_positional.desc, span),
+                },
+                span,
+            ));
+        }
+
+        for named_param in &sig.named {
+            let name = if let Some(short) = named_param.short {
+                if named_param.long.is_empty() {
+                    format!("-{}", short)
                 } else {
-                    "switch".to_string()
-                };
-
-                vals.push(Value::record(
-                    record! {
-                        "name" => Value::string(name, span),
-                        "type" => Value::string(typ, span),
-                        "required" => Value::bool(named_param.required, span),
-                        "description" => Value::string(&named_param.desc, span),
-                    },
-                    span,
-                ));
-            }
+                    format!("--{}(-{})", named_param.long, short)
+                }
+            } else {
+                format!("--{}", named_param.long)
+            };
 
-            Value::list(vals, span)
-        };
-
-        // Build the signature input/output table
-        let input_output_table = {
-            let mut vals = vec![];
-
-            for (input_type, output_type) in sig.input_output_types {
-                vals.push(Value::record(
-                    record! {
-                        "input" => Value::string(input_type.to_string(), span),
-                        "output" => Value::string(output_type.to_string(), span),
-                    },
-                    span,
-                ));
-            }
+            let typ = if let Some(arg) = &named_param.arg {
+                arg.to_string()
+            } else {
+                "switch".to_string()
+            };
+
+            vals.push(Value::record(
+                record! {
+                    "name" => Value::string(name, span),
+                    "type" => Value::string(typ, span),
+                    "required" => Value::bool(named_param.required, span),
+                    "description" => Value::string(&named_param.desc, span),
+                },
+                span,
+            ));
+        }
+
+        Value::list(vals, span)
+    };
+
+    // Build the signature input/output table
+    let input_output_table = {
+        let mut vals = vec![];
+
+        for (input_type, output_type) in sig.input_output_types {
+            vals.push(Value::record(
+                record! {
+                    "input" => Value::string(input_type.to_string(), span),
+                    "output" => Value::string(output_type.to_string(), span),
+                },
+                span,
+            ));
+        }
+
+        Value::list(vals, span)
+    };
+
+    let record = record! {
+        "name" => Value::string(key, span),
+        "category" => Value::string(sig.category.to_string(), span),
+        "command_type" => Value::string(command_type, span),
+        "description" => Value::string(description, span),
+        "extra_description" => Value::string(extra_description, span),
+        "params" => param_table,
+        "input_output" => input_output_table,
+        "search_terms" => Value::string(search_terms.join(", "), span),
+        "is_const" => Value::bool(decl.is_const(), span),
+    };
+
+    Value::record(record, span)
+}
+
+fn fuzzy_search_help_commands(engine_state: &EngineState, head: Span, needle: &str) -> Vec<Value> {
+    let mut matcher = Matcher::new(Config::DEFAULT);
+    let atom = Atom::new(
+        needle,
+        CaseMatching::Ignore,
+        Normalization::Smart,
+        AtomKind::Fuzzy,
+        false,
+    );
 
-            Value::list(vals, span)
-        };
-
-        let record = record! {
-            "name" => Value::string(key, span),
-            "category" => Value::string(sig.category.to_string(), span),
-            "command_type" => Value::string(command_type, span),
-            "description" => Value::string(description, span),
-            "params" => param_table,
-            "input_output" => input_output_table,
-            "search_terms" => Value::string(search_terms.join(", "), span),
-            "is_const" => Value::bool(decl.is_const(), span),
-        };
-
-        found_cmds_vec.push(Value::record(record, span));
+    let commands = engine_state.get_decls_sorted(false);
+    let mut scored_cmds = Vec::new();
+
+    for (_, decl_id) in commands {
+        let decl = engine_state.get_decl(decl_id);
+        let sig = decl.signature().update_from_command(decl);
+
+        let mut haystack = String::new();
+        haystack.push_str(&sig.name);
+        haystack.push(' ');
+        haystack.push_str(&sig.description);
+        haystack.push(' ');
+        haystack.push_str(&sig.extra_description);
+        haystack.push(' ');
+        haystack.push_str(&sig.search_terms.join(" "));
+
+        for example in decl.examples() {
+            haystack.push(' ');
+            haystack.push_str(example.description);
+            haystack.push(' ');
+            haystack.push_str(example.example);
+        }
+
+        let mut haystack_buf = Vec::new();
+        let haystack_utf32 = Utf32Str::new(&haystack, &mut haystack_buf);
+        let mut indices_buf = Vec::new();
+        if let Some(score) = atom.indices(haystack_utf32, &mut matcher, &mut indices_buf) {
+            scored_cmds.push((score, build_command_record(decl, head)));
+        }
     }
 
-    found_cmds_vec
+    scored_cmds.sort_by(|(a, _), (b, _)| b.cmp(a));
+    scored_cmds.into_iter().map(|(_, cmd)| cmd).collect()
+}
+
+fn command_matches_search_term(cmd: &Value, term: &str) -> bool {
+    cmd.get_data_by_key("search_terms")
+        .and_then(|val| val.as_str().ok().map(|s| s.to_string()))
+        .is_some_and(|search_terms| search_terms.to_folded_case().contains(&term.to_folded_case()))
 }
 
 #[cfg(test)]
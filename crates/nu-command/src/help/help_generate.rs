@@ -0,0 +1,101 @@
+use crate::help::help_render::{render_command_doc, DocFormat};
+#[allow(deprecated)]
+use nu_engine::{command_prelude::*, current_dir};
+use nu_protocol::shell_error::io::IoError;
+use std::fs;
+
+#[derive(Clone)]
+pub struct HelpGenerate;
+
+impl Command for HelpGenerate {
+    fn name(&self) -> &str {
+        "help generate"
+    }
+
+    fn description(&self) -> &str {
+        "Render documentation for every command to files on disk."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Writes one file per command, named after the command, into `--output`. Useful for \
+        publishing offline docs or man pages outside of the interactive `help` experience."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("help generate")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing)])
+            .named(
+                "output",
+                SyntaxShape::Directory,
+                "directory to write the rendered files into",
+                Some('o'),
+            )
+            .named(
+                "format",
+                SyntaxShape::String,
+                "output format: `markdown` (default), `html`, or `man`",
+                Some('f'),
+            )
+            .category(Category::Core)
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        #[allow(deprecated)]
+        let cwd = current_dir(engine_state, stack)?;
+
+        let output: Option<Spanned<String>> = call.get_flag(engine_state, stack, "output")?;
+        let output_dir = match output {
+            Some(output) => nu_path::expand_path_with(&output.item, &cwd, true),
+            None => cwd,
+        };
+
+        let format: Option<Spanned<String>> = call.get_flag(engine_state, stack, "format")?;
+        let format = match format {
+            Some(format) => {
+                DocFormat::from_str(&format.item).ok_or(ShellError::IncorrectValue {
+                    msg: "expected one of `markdown`, `html`, `man`".into(),
+                    val_span: format.span,
+                    call_span: head,
+                })?
+            }
+            None => DocFormat::Markdown,
+        };
+
+        fs::create_dir_all(&output_dir)
+            .map_err(|err| IoError::new(err.kind(), head, output_dir.clone()))?;
+
+        for (name, decl_id) in engine_state.get_decls_sorted(false) {
+            let decl = engine_state.get_decl(decl_id);
+            let doc = render_command_doc(decl, format);
+
+            let file_name = String::from_utf8_lossy(&name).replace(' ', "_");
+            let path = output_dir.join(format!("{file_name}.{}", format.extension()));
+
+            fs::write(&path, doc).map_err(|err| IoError::new(err.kind(), head, path))?;
+        }
+
+        Ok(PipelineData::empty())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Write markdown docs for every command into ./docs",
+                example: "help generate --output docs",
+                result: None,
+            },
+            Example {
+                description: "Write man pages for every command into ./man",
+                example: "help generate --output man --format man",
+                result: None,
+            },
+        ]
+    }
+}
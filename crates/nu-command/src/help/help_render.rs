@@ -0,0 +1,230 @@
+use nu_engine::command_prelude::*;
+use std::fmt::Write;
+
+/// Output formats supported by `help commands --format` and `help generate`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DocFormat {
+    Markdown,
+    Html,
+    Man,
+}
+
+impl DocFormat {
+    pub fn from_str(format: &str) -> Option<Self> {
+        match format {
+            "markdown" | "md" => Some(DocFormat::Markdown),
+            "html" => Some(DocFormat::Html),
+            "man" => Some(DocFormat::Man),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            DocFormat::Markdown => "md",
+            DocFormat::Html => "html",
+            DocFormat::Man => "1",
+        }
+    }
+}
+
+/// Render a command's documentation from its signature and examples.
+///
+/// This intentionally works from the same [`Signature`]/[`Example`] data that `help commands`
+/// exposes, rather than the ANSI-styled renderer used for interactive `help <command>`, so the
+/// output is plain, structured text suitable for offline docs or man pages.
+pub fn render_command_doc(decl: &dyn Command, format: DocFormat) -> String {
+    let sig = decl.signature().update_from_command(decl);
+    let examples = decl.examples();
+
+    match format {
+        DocFormat::Markdown => render_markdown(&sig, &examples),
+        DocFormat::Html => render_html(&sig, &examples),
+        DocFormat::Man => render_man(&sig, &examples),
+    }
+}
+
+fn positional_rows(sig: &Signature) -> Vec<(String, String, bool, String)> {
+    let mut rows = vec![];
+
+    for p in &sig.required_positional {
+        rows.push((p.name.clone(), p.shape.to_string(), true, p.desc.clone()));
+    }
+    for p in &sig.optional_positional {
+        rows.push((p.name.clone(), p.shape.to_string(), false, p.desc.clone()));
+    }
+    if let Some(p) = &sig.rest_positional {
+        rows.push((
+            format!("...{}", p.name),
+            p.shape.to_string(),
+            false,
+            p.desc.clone(),
+        ));
+    }
+    for named in &sig.named {
+        let name = if let Some(short) = named.short {
+            if named.long.is_empty() {
+                format!("-{short}")
+            } else {
+                format!("--{}(-{short})", named.long)
+            }
+        } else {
+            format!("--{}", named.long)
+        };
+        let ty = named
+            .arg
+            .as_ref()
+            .map(|a| a.to_string())
+            .unwrap_or_else(|| "switch".to_string());
+        rows.push((name, ty, named.required, named.desc.clone()));
+    }
+
+    rows
+}
+
+fn render_markdown(sig: &Signature, examples: &[Example]) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# {}\n", sig.name);
+    if !sig.description.is_empty() {
+        let _ = writeln!(out, "{}\n", sig.description);
+    }
+    if !sig.extra_description.is_empty() {
+        let _ = writeln!(out, "{}\n", sig.extra_description);
+    }
+
+    let _ = writeln!(out, "## Usage\n");
+    let _ = writeln!(out, "```\n> {}\n```\n", sig.call_signature());
+
+    let rows = positional_rows(sig);
+    if !rows.is_empty() {
+        let _ = writeln!(out, "## Parameters\n");
+        let _ = writeln!(out, "| Name | Type | Required | Description |");
+        let _ = writeln!(out, "| --- | --- | --- | --- |");
+        for (name, ty, required, desc) in &rows {
+            let _ = writeln!(out, "| `{name}` | {ty} | {required} | {desc} |");
+        }
+        out.push('\n');
+    }
+
+    if !examples.is_empty() {
+        let _ = writeln!(out, "## Examples\n");
+        for example in examples {
+            let _ = writeln!(out, "{}:\n", example.description);
+            let _ = writeln!(out, "```nu\n{}\n```\n", example.example);
+        }
+    }
+
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_html(sig: &Signature, examples: &[Example]) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html><head><meta charset=\"utf-8\"><title>{}</title></head><body>", html_escape(&sig.name));
+    let _ = writeln!(out, "<h1>{}</h1>", html_escape(&sig.name));
+    if !sig.description.is_empty() {
+        let _ = writeln!(out, "<p>{}</p>", html_escape(&sig.description));
+    }
+    if !sig.extra_description.is_empty() {
+        let _ = writeln!(out, "<p>{}</p>", html_escape(&sig.extra_description));
+    }
+
+    let _ = writeln!(out, "<h2>Usage</h2>");
+    let _ = writeln!(
+        out,
+        "<pre><code>&gt; {}</code></pre>",
+        html_escape(&sig.call_signature())
+    );
+
+    let rows = positional_rows(sig);
+    if !rows.is_empty() {
+        let _ = writeln!(out, "<h2>Parameters</h2>");
+        let _ = writeln!(out, "<table><tr><th>Name</th><th>Type</th><th>Required</th><th>Description</th></tr>");
+        for (name, ty, required, desc) in &rows {
+            let _ = writeln!(
+                out,
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                html_escape(name),
+                html_escape(ty),
+                required,
+                html_escape(desc)
+            );
+        }
+        let _ = writeln!(out, "</table>");
+    }
+
+    if !examples.is_empty() {
+        let _ = writeln!(out, "<h2>Examples</h2>");
+        for example in examples {
+            let _ = writeln!(out, "<p>{}</p>", html_escape(&example.description));
+            let _ = writeln!(
+                out,
+                "<pre><code>{}</code></pre>",
+                html_escape(example.example)
+            );
+        }
+    }
+
+    let _ = writeln!(out, "</body></html>");
+    out
+}
+
+fn man_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('-', "\\-")
+}
+
+fn render_man(sig: &Signature, examples: &[Example]) -> String {
+    let mut out = String::new();
+
+    let title = sig.name.to_uppercase();
+    let _ = writeln!(out, ".TH \"{title}\" 1");
+    let _ = writeln!(out, ".SH NAME");
+    let _ = writeln!(out, "{}", man_escape(&sig.name));
+    let _ = writeln!(out, ".SH SYNOPSIS");
+    let _ = writeln!(out, ".B {}", man_escape(&sig.call_signature()));
+    if !sig.description.is_empty() || !sig.extra_description.is_empty() {
+        let _ = writeln!(out, ".SH DESCRIPTION");
+        if !sig.description.is_empty() {
+            let _ = writeln!(out, "{}", man_escape(&sig.description));
+        }
+        if !sig.extra_description.is_empty() {
+            let _ = writeln!(out, ".PP");
+            let _ = writeln!(out, "{}", man_escape(&sig.extra_description));
+        }
+    }
+
+    let rows = positional_rows(sig);
+    if !rows.is_empty() {
+        let _ = writeln!(out, ".SH PARAMETERS");
+        for (name, ty, required, desc) in &rows {
+            let _ = writeln!(out, ".TP");
+            let _ = writeln!(
+                out,
+                "\\fB{}\\fR ({ty}, {})",
+                man_escape(name),
+                if *required { "required" } else { "optional" }
+            );
+            let _ = writeln!(out, "{}", man_escape(desc));
+        }
+    }
+
+    if !examples.is_empty() {
+        let _ = writeln!(out, ".SH EXAMPLES");
+        for example in examples {
+            let _ = writeln!(out, ".TP");
+            let _ = writeln!(out, "{}", man_escape(&example.description));
+            let _ = writeln!(out, ".B {}", man_escape(example.example));
+        }
+    }
+
+    out
+}
@@ -1,17 +1,22 @@
 mod help_;
 mod help_aliases;
+mod help_all;
 mod help_commands;
 mod help_escapes;
 mod help_externs;
+mod help_generate;
 mod help_modules;
 mod help_operators;
 mod help_pipe_and_redirect;
+mod help_render;
 
 pub use help_::Help;
 pub use help_aliases::HelpAliases;
+pub use help_all::HelpAll;
 pub use help_commands::HelpCommands;
 pub use help_escapes::HelpEscapes;
 pub use help_externs::HelpExterns;
+pub use help_generate::HelpGenerate;
 pub use help_modules::HelpModules;
 pub use help_operators::HelpOperators;
 pub use help_pipe_and_redirect::HelpPipeAndRedirect;
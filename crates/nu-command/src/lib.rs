@@ -26,6 +26,8 @@ mod progress_bar;
 #[cfg(feature = "rand")]
 mod random;
 mod removed;
+#[cfg(feature = "sqlite")]
+mod secret;
 mod shells;
 mod sort_utils;
 #[cfg(feature = "sqlite")]
@@ -61,6 +63,8 @@ pub use platform::*;
 #[cfg(feature = "rand")]
 pub use random::*;
 pub use removed::*;
+#[cfg(feature = "sqlite")]
+pub use secret::*;
 pub use shells::*;
 pub use sort_utils::*;
 #[cfg(feature = "sqlite")]
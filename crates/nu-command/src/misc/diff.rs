@@ -0,0 +1,168 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::ast::{CellPath, PathMember};
+
+#[derive(Clone)]
+pub struct Diff;
+
+impl Command for Diff {
+    fn name(&self) -> &str {
+        "diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("diff")
+            .input_output_types(vec![
+                (Type::Nothing, Type::table()),
+                (Type::Nothing, Type::String),
+            ])
+            .required("before", SyntaxShape::Any, "The value to diff from.")
+            .required("after", SyntaxShape::Any, "The value to diff to.")
+            .switch(
+                "text",
+                "Diff both values as text and produce a unified diff, instead of comparing them structurally",
+                Some('t'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Compare two values and show what changed."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Without `--text`, records, tables, and lists are compared structurally: each leaf
+that changed is reported as its own row, and each added or removed field or list element is
+reported as one row holding its whole value, tagged with the cell path that reaches it. With `--text`, both values are coerced to strings and compared line by line,
+producing a unified diff — useful for comparing file contents opened with `open`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["compare", "changes", "unified", "drift"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Show which fields changed between two records",
+                example: "diff {a: 1, b: 2} {a: 1, b: 3}",
+                result: None,
+            },
+            Example {
+                description: "Show a unified diff between two pieces of text",
+                example: "diff --text \"foo\nbar\n\" \"foo\nbaz\n\"",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let before: Value = call.req(engine_state, stack, 0)?;
+        let after: Value = call.req(engine_state, stack, 1)?;
+
+        if call.has_flag(engine_state, stack, "text")? {
+            let before_str = before.coerce_into_string()?;
+            let after_str = after.coerce_into_string()?;
+            let text_diff = similar::TextDiff::from_lines(&before_str, &after_str);
+            let unified = text_diff
+                .unified_diff()
+                .header("before", "after")
+                .to_string();
+            Ok(Value::string(unified, head).into_pipeline_data())
+        } else {
+            let mut rows = Vec::new();
+            diff_values(&before, &after, Vec::new(), head, &mut rows);
+            Ok(Value::list(rows, head).into_pipeline_data())
+        }
+    }
+}
+
+fn diff_values(before: &Value, after: &Value, path: Vec<PathMember>, span: Span, rows: &mut Vec<Value>) {
+    if before == after {
+        return;
+    }
+
+    match (before, after) {
+        (Value::Record { val: b, .. }, Value::Record { val: a, .. }) => {
+            let mut keys: Vec<&String> = b.columns().chain(a.columns()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let mut child_path = path.clone();
+                child_path.push(PathMember::string(key.clone(), false, span));
+                match (b.get(key), a.get(key)) {
+                    (Some(bv), Some(av)) => diff_values(bv, av, child_path, span, rows),
+                    (Some(bv), None) => {
+                        push_row(rows, child_path, "removed", Some(bv.clone()), None, span)
+                    }
+                    (None, Some(av)) => {
+                        push_row(rows, child_path, "added", None, Some(av.clone()), span)
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::List { vals: b, .. }, Value::List { vals: a, .. }) => {
+            for i in 0..b.len().max(a.len()) {
+                let mut child_path = path.clone();
+                child_path.push(PathMember::int(i, false, span));
+                match (b.get(i), a.get(i)) {
+                    (Some(bv), Some(av)) => diff_values(bv, av, child_path, span, rows),
+                    (Some(bv), None) => {
+                        push_row(rows, child_path, "removed", Some(bv.clone()), None, span)
+                    }
+                    (None, Some(av)) => {
+                        push_row(rows, child_path, "added", None, Some(av.clone()), span)
+                    }
+                    (None, None) => {}
+                }
+            }
+        }
+        _ => push_row(
+            rows,
+            path,
+            "changed",
+            Some(before.clone()),
+            Some(after.clone()),
+            span,
+        ),
+    }
+}
+
+fn push_row(
+    rows: &mut Vec<Value>,
+    path: Vec<PathMember>,
+    status: &str,
+    before: Option<Value>,
+    after: Option<Value>,
+    span: Span,
+) {
+    rows.push(Value::record(
+        record! {
+            "path" => Value::cell_path(CellPath { members: path }, span),
+            "status" => Value::string(status, span),
+            "before" => before.unwrap_or(Value::nothing(span)),
+            "after" => after.unwrap_or(Value::nothing(span)),
+        },
+        span,
+    ));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Diff {})
+    }
+}
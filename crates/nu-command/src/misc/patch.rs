@@ -0,0 +1,292 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::{
+    ast::{CellPath, PathMember},
+    Record,
+};
+
+#[derive(Clone)]
+pub struct Patch;
+
+impl Command for Patch {
+    fn name(&self) -> &str {
+        "patch"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("patch")
+            .input_output_types(vec![(Type::Any, Type::Any)])
+            .required(
+                "changes",
+                SyntaxShape::Any,
+                "The change set to apply — either rows produced by `diff`, or an RFC 6902 JSON Patch document.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Apply a structured change set to a value."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Accepts two change set formats, detected per row: rows with `path`/`status`/`before`/
+`after` columns (as produced by `diff`), and rows with `op`/`path` columns (an RFC 6902 JSON
+Patch document, with `op` one of `add`, `remove`, `replace`, `move`, `copy`, or `test`; JSON
+Patch paths are `/`-separated pointers, with `~1` and `~0` escaping `/` and `~`).
+
+Before writing a `removed` or `changed` row, the value currently at that path is checked
+against the row's `before`, and a JSON Patch `test` checks it against `value`: if it doesn't
+match, the underlying data has drifted since the change set was built, and `patch` errors
+instead of silently overwriting it. JSON Patch `remove` and `replace` only check that the path
+exists; they do not compare the current value."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["diff", "apply", "json patch", "merge"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Apply a change set produced by `diff`",
+                example: "let changes = diff {a: 1, b: 2} {a: 1, b: 3}; {a: 1, b: 2} | patch $changes",
+                result: None,
+            },
+            Example {
+                description: "Apply an RFC 6902 JSON Patch document",
+                example: r#"{a: 1} | patch [{op: "replace", path: "/a", value: 2}]"#,
+                result: Some(Value::test_record(record! { "a" => Value::test_int(2) })),
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let changes: Value = call.req(engine_state, stack, 0)?;
+        let mut value = input.into_value(head)?;
+
+        for change in changes.into_list()? {
+            let record = change.into_record()?;
+            if record.contains("op") {
+                apply_json_patch_op(&mut value, &record, head)?;
+            } else if record.contains("path") && record.contains("status") {
+                apply_diff_row(&mut value, &record, head)?;
+            } else {
+                return Err(ShellError::GenericError {
+                    error: "Unrecognized change set row".into(),
+                    msg: "expected a `diff` row (`path`/`status`/`before`/`after`) or a JSON Patch row (`op`/`path`)".into(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+        }
+
+        Ok(value.into_pipeline_data())
+    }
+}
+
+fn apply_diff_row(value: &mut Value, record: &Record, head: Span) -> Result<(), ShellError> {
+    let path = get_field(record, "path", head)?;
+    let members = path.into_cell_path()?.members;
+    let status = get_field(record, "status", head)?.coerce_into_string()?;
+    let before = record.get("before").cloned();
+    let after = record.get("after").cloned();
+
+    match status.as_str() {
+        "added" => {
+            let new_val = after.ok_or_else(|| missing_field("after", head))?;
+            value.upsert_data_at_cell_path(&members, new_val)?;
+        }
+        "changed" => {
+            check_conflict(value, &members, before.as_ref(), head)?;
+            let new_val = after.ok_or_else(|| missing_field("after", head))?;
+            value.upsert_data_at_cell_path(&members, new_val)?;
+        }
+        "removed" => {
+            check_conflict(value, &members, before.as_ref(), head)?;
+            value.remove_data_at_cell_path(&members)?;
+        }
+        other => {
+            return Err(ShellError::GenericError {
+                error: "Unrecognized diff status".into(),
+                msg: format!("expected `added`, `changed`, or `removed`, got '{other}'"),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_json_patch_op(value: &mut Value, record: &Record, head: Span) -> Result<(), ShellError> {
+    let op = get_field(record, "op", head)?.coerce_into_string()?;
+    let path = get_field(record, "path", head)?.coerce_into_string()?;
+    let members = parse_json_pointer(&path, head)?;
+
+    match op.as_str() {
+        "add" => {
+            let new_val = get_field(record, "value", head)?;
+            value.upsert_data_at_cell_path(&members, new_val)?;
+        }
+        "replace" => {
+            require_exists(value, &members, head)?;
+            let new_val = get_field(record, "value", head)?;
+            value.upsert_data_at_cell_path(&members, new_val)?;
+        }
+        "remove" => {
+            require_exists(value, &members, head)?;
+            value.remove_data_at_cell_path(&members)?;
+        }
+        "test" => {
+            let expected = get_field(record, "value", head)?;
+            let current = current_value(value, &members, head);
+            if current != expected {
+                return Err(patch_conflict(&expected, &current, head));
+            }
+        }
+        "move" => {
+            let from = get_field(record, "from", head)?.coerce_into_string()?;
+            let from_members = parse_json_pointer(&from, head)?;
+            let moved = current_value(value, &from_members, head);
+            value.remove_data_at_cell_path(&from_members)?;
+            value.upsert_data_at_cell_path(&members, moved)?;
+        }
+        "copy" => {
+            let from = get_field(record, "from", head)?.coerce_into_string()?;
+            let from_members = parse_json_pointer(&from, head)?;
+            let copied = current_value(value, &from_members, head);
+            value.upsert_data_at_cell_path(&members, copied)?;
+        }
+        other => {
+            return Err(ShellError::GenericError {
+                error: "Unrecognized JSON Patch operation".into(),
+                msg: format!(
+                    "expected `add`, `remove`, `replace`, `move`, `copy`, or `test`, got '{other}'"
+                ),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })
+        }
+    }
+
+    Ok(())
+}
+
+/// Parses a JSON Pointer (RFC 6901) into cell path members, guessing that a purely numeric
+/// segment addresses a list index rather than a record key — the common case for JSON/YAML data.
+pub(crate) fn parse_json_pointer(pointer: &str, span: Span) -> Result<Vec<PathMember>, ShellError> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rest = pointer.strip_prefix('/').ok_or_else(|| ShellError::GenericError {
+        error: "Invalid JSON Pointer".into(),
+        msg: format!("'{pointer}' must be empty or start with '/'"),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })?;
+
+    Ok(rest
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .map(|segment| match segment.parse::<usize>() {
+            Ok(index) => PathMember::int(index, false, span),
+            Err(_) => PathMember::string(segment, false, span),
+        })
+        .collect())
+}
+
+fn current_value(value: &Value, members: &[PathMember], head: Span) -> Value {
+    value
+        .clone()
+        .follow_cell_path(members, false)
+        .unwrap_or(Value::nothing(head))
+}
+
+fn require_exists(value: &Value, members: &[PathMember], head: Span) -> Result<(), ShellError> {
+    value
+        .clone()
+        .follow_cell_path(members, false)
+        .map(|_| ())
+        .map_err(|_| ShellError::GenericError {
+            error: "Patch conflict".into(),
+            msg: format!(
+                "path {} does not exist",
+                CellPath {
+                    members: members.to_vec()
+                }
+            ),
+            span: Some(head),
+            help: Some("the value has changed since this change set was built".into()),
+            inner: vec![],
+        })
+}
+
+fn check_conflict(
+    value: &Value,
+    members: &[PathMember],
+    expected: Option<&Value>,
+    head: Span,
+) -> Result<(), ShellError> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+    let current = current_value(value, members, head);
+    if &current != expected {
+        return Err(patch_conflict(expected, &current, head));
+    }
+    Ok(())
+}
+
+fn patch_conflict(expected: &Value, current: &Value, head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Patch conflict".into(),
+        msg: format!(
+            "expected {} at this path but found {}",
+            expected.to_debug_string(),
+            current.to_debug_string()
+        ),
+        span: Some(head),
+        help: Some("the value has changed since this change set was built".into()),
+        inner: vec![],
+    }
+}
+
+fn missing_field(name: &str, head: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Missing field in change set row".into(),
+        msg: format!("expected a '{name}' column"),
+        span: Some(head),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn get_field(record: &Record, name: &str, head: Span) -> Result<Value, ShellError> {
+    record
+        .get(name)
+        .cloned()
+        .ok_or_else(|| missing_field(name, head))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Patch {})
+    }
+}
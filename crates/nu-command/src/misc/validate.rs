@@ -0,0 +1,123 @@
+use crate::formats::value_to_json_value;
+use nu_engine::command_prelude::*;
+use nu_path::expand_path_with;
+use nu_protocol::ast::CellPath;
+
+#[derive(Clone)]
+pub struct Validate;
+
+impl Command for Validate {
+    fn name(&self) -> &str {
+        "validate"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("validate")
+            .input_output_types(vec![(Type::Any, Type::table())])
+            .named(
+                "schema",
+                SyntaxShape::Filepath,
+                "Path to a JSON Schema file to validate the input against.",
+                Some('s'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Validate piped data against a JSON Schema."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Converts the piped value to JSON and checks it against the schema given with `--schema`,
+using the draft the schema declares via `$schema` (defaulting to the latest draft the validator
+supports, currently 2020-12). Returns a table with one row per violation, empty if the input is
+valid, each row giving the cell path into the input where the violation occurred and a
+description of what failed."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["json schema", "check", "lint", "conform"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Validate a record against a schema file",
+            example: "open config.json | validate --schema config.schema.json",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let cwd = engine_state.cwd(Some(stack))?;
+        let schema_file: Spanned<String> = call
+            .get_flag(engine_state, stack, "schema")?
+            .ok_or_else(|| ShellError::MissingParameter {
+                param_name: "schema".into(),
+                span: head,
+            })?;
+
+        let schema_path = expand_path_with(&schema_file.item, &cwd, true);
+        let schema_contents =
+            std::fs::read_to_string(&schema_path).map_err(|err| ShellError::GenericError {
+                error: format!("Could not read schema file '{}'", schema_path.display()),
+                msg: err.to_string(),
+                span: Some(schema_file.span),
+                help: None,
+                inner: vec![],
+            })?;
+        let schema_json: serde_json::Value =
+            serde_json::from_str(&schema_contents).map_err(|err| ShellError::GenericError {
+                error: "Invalid JSON Schema".into(),
+                msg: err.to_string(),
+                span: Some(schema_file.span),
+                help: None,
+                inner: vec![],
+            })?;
+        let validator = jsonschema::validator_for(&schema_json).map_err(|err| {
+            ShellError::GenericError {
+                error: "Invalid JSON Schema".into(),
+                msg: err.to_string(),
+                span: Some(schema_file.span),
+                help: None,
+                inner: vec![],
+            }
+        })?;
+
+        let value = input.into_value(head)?;
+        let instance = value_to_json_value(engine_state, &value, head, false)?;
+        let instance = serde_json::to_value(instance).map_err(|err| ShellError::GenericError {
+            error: "Could not convert input to JSON".into(),
+            msg: err.to_string(),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        })?;
+
+        let rows = validator
+            .iter_errors(&instance)
+            .map(|err| {
+                let members = crate::misc::patch::parse_json_pointer(
+                    err.instance_path().as_str(),
+                    head,
+                )
+                .unwrap_or_default();
+                Value::record(
+                    record! {
+                        "path" => Value::cell_path(CellPath { members }, head),
+                        "message" => Value::string(err.to_string(), head),
+                    },
+                    head,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Value::list(rows, head).into_pipeline_data())
+    }
+}
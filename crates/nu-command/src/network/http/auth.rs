@@ -0,0 +1,382 @@
+use nu_engine::{command_prelude::*, get_full_help};
+use rusqlite::{params, Connection};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TABLE_NAME: &str = "nu_oauth_tokens";
+
+#[derive(Clone)]
+pub struct HttpAuth;
+
+impl Command for HttpAuth {
+    fn name(&self) -> &str {
+        "http auth"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http auth")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Acquire and cache OAuth2 tokens for use with `http` requests."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only produce this help message."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+}
+
+/// The private, in-memory sqlite connection backing the OAuth2 token cache, held open for the
+/// life of the process.
+///
+/// This deliberately does *not* reuse `stor`'s shared `MEMORY_DB` connection: that database is
+/// reachable by name from any script (`stor open`, `open <path>`, `query db`), which would let
+/// any script read every cached token back out in plaintext. This connection has no name at all -
+/// it's a private Rust object, not a named shared-cache database - so there's no `stor`/`query db`
+/// incantation that can reach it.
+static STORE: LazyLock<Mutex<Connection>> = LazyLock::new(|| {
+    let conn = Connection::open_in_memory().expect("failed to open in-memory OAuth2 token cache");
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {TABLE_NAME} (
+                profile TEXT PRIMARY KEY,
+                access_token TEXT NOT NULL,
+                token_type TEXT NOT NULL,
+                expires_at INTEGER
+            )"
+        ),
+        [],
+    )
+    .expect("failed to initialize OAuth2 token cache table");
+    Mutex::new(conn)
+});
+
+/// Lock the private OAuth2 token cache connection.
+fn open_store() -> Result<MutexGuard<'static, Connection>, ShellError> {
+    STORE.lock().map_err(|_| ShellError::GenericError {
+        error: "OAuth2 token cache poisoned".into(),
+        msg: "a previous `http auth` command panicked while holding the cache lock".into(),
+        span: None,
+        help: None,
+        inner: vec![],
+    })
+}
+
+fn now_unix() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[derive(Clone)]
+pub struct HttpAuthOauth2;
+
+impl Command for HttpAuthOauth2 {
+    fn name(&self) -> &str {
+        "http auth oauth2"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http auth oauth2")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .named(
+                "token-url",
+                SyntaxShape::String,
+                "The OAuth2 token endpoint URL.",
+                None,
+            )
+            .named(
+                "client-id",
+                SyntaxShape::String,
+                "The OAuth2 client ID.",
+                None,
+            )
+            .named(
+                "client-secret",
+                SyntaxShape::String,
+                "The OAuth2 client secret.",
+                None,
+            )
+            .named(
+                "scope",
+                SyntaxShape::String,
+                "The OAuth2 scope(s) to request, space-separated.",
+                None,
+            )
+            .named(
+                "profile",
+                SyntaxShape::String,
+                "The name to cache the resulting token under, for use with `http auth token`. Defaults to 'default'.",
+                Some('p'),
+            )
+            .switch(
+                "client-credentials",
+                "Use the client credentials grant (the default, and currently the only grant this command supports).",
+                None,
+            )
+            .switch(
+                "device-code",
+                "Use the device authorization grant. Not yet implemented.",
+                None,
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Run an OAuth2 token flow and cache the resulting access token under a profile name."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Only the client credentials grant is implemented: it performs a single token request
+and requires no user interaction, which fits nushell's blocking command model. `--device-code`
+is accepted but not implemented, since polling for user approval doesn't fit a single
+non-interactive command well; use `secret set` to store a token obtained out of band instead.
+
+The resulting access token is cached in memory (never written to disk) under `--profile`, for
+the life of the current nushell process. Retrieve it with `http auth token --profile <name>` and
+splice it into a `-H`/`--headers` argument to `http get`/`http post`/etc — there is no automatic
+injection into `http` calls."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["oauth", "openid", "token", "bearer", "authentication"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Fetch and cache a token using the client credentials grant",
+            example: "http auth oauth2 --token-url https://auth.example.com/token --client-id my-app --client-secret $env.CLIENT_SECRET --profile myapi",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        if call.has_flag(engine_state, stack, "device-code")? {
+            return Err(ShellError::GenericError {
+                error: "Unsupported grant type".into(),
+                msg: "the device authorization grant is not yet implemented".into(),
+                span: Some(head),
+                help: Some("use --client-credentials, or obtain a token out of band and store it with `secret set`".into()),
+                inner: vec![],
+            });
+        }
+
+        let token_url: Spanned<String> = call
+            .get_flag(engine_state, stack, "token-url")?
+            .ok_or(ShellError::MissingParameter {
+                param_name: "token-url".into(),
+                span: head,
+            })?;
+        let client_id: String = call
+            .get_flag(engine_state, stack, "client-id")?
+            .ok_or(ShellError::MissingParameter {
+                param_name: "client-id".into(),
+                span: head,
+            })?;
+        let client_secret: Option<String> = call.get_flag(engine_state, stack, "client-secret")?;
+        let scope: Option<String> = call.get_flag(engine_state, stack, "scope")?;
+        let profile: String = call
+            .get_flag(engine_state, stack, "profile")?
+            .unwrap_or_else(|| "default".to_string());
+
+        let mut form: Vec<(&str, &str)> = vec![("grant_type", "client_credentials")];
+        form.push(("client_id", &client_id));
+        if let Some(client_secret) = &client_secret {
+            form.push(("client_secret", client_secret));
+        }
+        if let Some(scope) = &scope {
+            form.push(("scope", scope));
+        }
+
+        let agent = ureq::AgentBuilder::new().build();
+        let response = agent
+            .post(&token_url.item)
+            .set("Accept", "application/json")
+            .send_form(&form)
+            .map_err(|err| ShellError::GenericError {
+                error: "OAuth2 token request failed".into(),
+                msg: err.to_string(),
+                span: Some(token_url.span),
+                help: None,
+                inner: vec![],
+            })?;
+
+        #[derive(serde::Deserialize)]
+        struct TokenResponse {
+            access_token: String,
+            #[serde(default = "default_token_type")]
+            token_type: String,
+            expires_in: Option<i64>,
+        }
+        fn default_token_type() -> String {
+            "bearer".to_string()
+        }
+
+        let token: TokenResponse =
+            response
+                .into_json()
+                .map_err(|err| ShellError::GenericError {
+                    error: "Invalid token response".into(),
+                    msg: err.to_string(),
+                    span: Some(token_url.span),
+                    help: None,
+                    inner: vec![],
+                })?;
+
+        let expires_at = token.expires_in.map(|secs| now_unix() + secs);
+
+        let conn = open_store()?;
+        conn.execute(
+            "INSERT INTO nu_oauth_tokens (profile, access_token, token_type, expires_at)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(profile) DO UPDATE SET
+                access_token = excluded.access_token,
+                token_type = excluded.token_type,
+                expires_at = excluded.expires_at",
+            params![profile, token.access_token, token.token_type, expires_at],
+        )
+        .map_err(|e| ShellError::GenericError {
+            error: "Failed to cache OAuth2 token".into(),
+            msg: e.to_string(),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        })?;
+
+        Ok(Value::record(
+            record! {
+                "profile" => Value::string(profile, head),
+                "token_type" => Value::string(token.token_type, head),
+                "expires_at" => match expires_at {
+                    Some(secs) => Value::date(
+                        chrono::DateTime::from_timestamp(secs, 0).unwrap_or_default().into(),
+                        head,
+                    ),
+                    None => Value::nothing(head),
+                },
+            },
+            head,
+        )
+        .into_pipeline_data())
+    }
+}
+
+#[derive(Clone)]
+pub struct HttpAuthToken;
+
+impl Command for HttpAuthToken {
+    fn name(&self) -> &str {
+        "http auth token"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("http auth token")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .named(
+                "profile",
+                SyntaxShape::String,
+                "The profile to look up, as cached by `http auth oauth2`. Defaults to 'default'.",
+                Some('p'),
+            )
+            .category(Category::Network)
+    }
+
+    fn description(&self) -> &str {
+        "Retrieve a cached OAuth2 access token."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Errors if the profile was never cached with `http auth oauth2`, or if its token has
+expired. Intended to be spliced into a request's headers, e.g.
+`http get $url --headers [Authorization $"Bearer (http auth token --profile myapi)"]`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["oauth", "bearer", "token"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Use a cached token as a bearer header",
+            example: r#"http get https://api.example.com/me --headers [Authorization $"Bearer (http auth token --profile myapi)"]"#,
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let profile: String = call
+            .get_flag(engine_state, stack, "profile")?
+            .unwrap_or_else(|| "default".to_string());
+
+        let conn = open_store()?;
+        let result = conn.query_row(
+            "SELECT access_token, expires_at FROM nu_oauth_tokens WHERE profile = ?1",
+            params![profile],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<i64>>(1)?)),
+        );
+
+        let (access_token, expires_at) = match result {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => {
+                return Err(ShellError::GenericError {
+                    error: "No cached OAuth2 token".into(),
+                    msg: format!("no token cached for profile '{profile}'"),
+                    span: Some(head),
+                    help: Some("run `http auth oauth2 --profile ...` first".into()),
+                    inner: vec![],
+                });
+            }
+            Err(e) => {
+                return Err(ShellError::GenericError {
+                    error: "Failed to read OAuth2 token cache".into(),
+                    msg: e.to_string(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                });
+            }
+        };
+
+        if let Some(expires_at) = expires_at {
+            if expires_at <= now_unix() {
+                return Err(ShellError::GenericError {
+                    error: "OAuth2 token expired".into(),
+                    msg: format!("the cached token for profile '{profile}' has expired"),
+                    span: Some(head),
+                    help: Some("run `http auth oauth2 --profile ...` again to refresh it".into()),
+                    inner: vec![],
+                });
+            }
+        }
+
+        Ok(Value::string(access_token, head).into_pipeline_data())
+    }
+}
@@ -726,7 +726,13 @@ fn transform_response_using_content_type(
         _ => Some(content_type.subtype().to_string()),
     };
 
-    let output = response_to_buffer(resp, engine_state, span);
+    let output = response_to_buffer(resp, engine_state, span).set_metadata(Some(
+        nu_protocol::PipelineMetadata {
+            data_source: nu_protocol::DataSource::Url(requested_url.to_string()),
+            content_type: Some(content_type.essence_str().to_string()),
+            custom: None,
+        },
+    ));
     if flags.raw {
         Ok(output)
     } else if let Some(ext) = ext {
@@ -788,7 +794,13 @@ fn request_handle_response_content(
                 response,
                 &content_type,
             ),
-            None => Ok(response_to_buffer(response, engine_state, span)),
+            None => Ok(response_to_buffer(response, engine_state, span).set_metadata(Some(
+                nu_protocol::PipelineMetadata {
+                    data_source: nu_protocol::DataSource::Url(requested_url.to_string()),
+                    content_type: None,
+                    custom: None,
+                },
+            ))),
         }
     };
 
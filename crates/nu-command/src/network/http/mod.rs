@@ -1,3 +1,5 @@
+#[cfg(feature = "sqlite")]
+mod auth;
 mod client;
 mod delete;
 mod get;
@@ -8,6 +10,8 @@ mod patch;
 mod post;
 mod put;
 
+#[cfg(feature = "sqlite")]
+pub use auth::{HttpAuth, HttpAuthOauth2, HttpAuthToken};
 pub use delete::HttpDelete;
 pub use get::HttpGet;
 pub use head::HttpHead;
@@ -0,0 +1,109 @@
+use nu_engine::command_prelude::*;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone)]
+pub struct PathCommonPrefix;
+
+impl Command for PathCommonPrefix {
+    fn name(&self) -> &str {
+        "path common-prefix"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path common-prefix")
+            .input_output_types(vec![(Type::List(Box::new(Type::String)), Type::String)])
+            .category(Category::Path)
+    }
+
+    fn description(&self) -> &str {
+        "Find the longest common path prefix shared by a list of paths."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Comparison happens component by component, not character by character, so
+"/home/vi" is never returned as a prefix of "/home/viking" and "/home/vixen".
+Paths are compared lexically, without touching the filesystem."#
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+
+        let paths: Vec<Spanned<String>> = input
+            .into_iter()
+            .map(|value| {
+                let span = value.span();
+                value
+                    .into_string()
+                    .map(|item| Spanned { item, span })
+                    .map_err(|_| ShellError::OnlySupportsThisInputType {
+                        exp_input_type: "list<string>".into(),
+                        wrong_type: "other".into(),
+                        dst_span: head,
+                        src_span: span,
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let prefix = common_prefix(paths.iter().map(|p| Path::new(&p.item)));
+
+        Ok(Value::string(prefix.to_string_lossy(), head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Find the common prefix of several paths",
+                example: r#"[ /home/viking/spam /home/viking/eggs /home/viking/bacon/sausage ] | path common-prefix"#,
+                result: Some(Value::test_string("/home/viking")),
+            },
+            Example {
+                description: "Paths that only share a partial component don't match",
+                example: r#"[ /home/viking /home/vixen ] | path common-prefix"#,
+                result: Some(Value::test_string("/home")),
+            },
+        ]
+    }
+}
+
+fn common_prefix<'a>(paths: impl Iterator<Item = &'a Path>) -> PathBuf {
+    let mut result: Option<Vec<_>> = None;
+
+    for path in paths {
+        let components: Vec<_> = path.components().collect();
+        result = Some(match result {
+            None => components,
+            Some(prefix) => prefix
+                .into_iter()
+                .zip(components)
+                .take_while(|(a, b)| a == b)
+                .map(|(a, _)| a)
+                .collect(),
+        });
+    }
+
+    result
+        .unwrap_or_default()
+        .into_iter()
+        .fold(PathBuf::new(), |mut acc, component| {
+            acc.push(component);
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(PathCommonPrefix {})
+    }
+}
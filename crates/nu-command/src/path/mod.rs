@@ -1,8 +1,10 @@
 mod basename;
+mod common_prefix;
 mod dirname;
 mod exists;
 mod expand;
 mod join;
+mod normalize;
 mod parse;
 pub mod path_;
 mod relative_to;
@@ -11,10 +13,12 @@ mod split;
 mod r#type;
 
 pub use basename::PathBasename;
+pub use common_prefix::PathCommonPrefix;
 pub use dirname::PathDirname;
 pub use exists::PathExists;
 pub use expand::PathExpand;
 pub use join::PathJoin;
+pub use normalize::PathNormalize;
 pub use parse::PathParse;
 pub use path_::Path;
 pub use r#type::PathType;
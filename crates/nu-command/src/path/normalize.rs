@@ -0,0 +1,123 @@
+use super::PathSubcommandArguments;
+use nu_engine::command_prelude::*;
+use nu_protocol::engine::StateWorkingSet;
+use std::path::Path;
+
+struct Arguments;
+
+impl PathSubcommandArguments for Arguments {}
+
+#[derive(Clone)]
+pub struct PathNormalize;
+
+impl Command for PathNormalize {
+    fn name(&self) -> &str {
+        "path normalize"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("path normalize")
+            .input_output_types(vec![
+                (Type::String, Type::String),
+                (
+                    Type::List(Box::new(Type::String)),
+                    Type::List(Box::new(Type::String)),
+                ),
+            ])
+            .category(Category::Path)
+    }
+
+    fn description(&self) -> &str {
+        "Normalize a path, collapsing `.` and `..` components."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"This is purely lexical: it doesn't touch the filesystem, so it won't resolve
+symlinks or fail on paths that don't exist, unlike `path expand`. A leading
+".." is preserved, since it can't be collapsed without knowing what it points to."#
+    }
+
+    fn is_const(&self) -> bool {
+        true
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let args = Arguments;
+
+        // This doesn't match explicit nulls
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&normalize, &args, value, head),
+            engine_state.signals(),
+        )
+    }
+
+    fn run_const(
+        &self,
+        working_set: &StateWorkingSet,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let args = Arguments;
+
+        // This doesn't match explicit nulls
+        if matches!(input, PipelineData::Empty) {
+            return Err(ShellError::PipelineEmpty { dst_span: head });
+        }
+        input.map(
+            move |value| super::operate(&normalize, &args, value, head),
+            working_set.permanent().signals(),
+        )
+    }
+
+    #[cfg(windows)]
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Normalize a path without touching the filesystem",
+            example: r"'C:\Users\viking\..\spam\.\eggs' | path normalize",
+            result: Some(Value::test_string(r"C:\Users\spam\eggs")),
+        }]
+    }
+
+    #[cfg(not(windows))]
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Normalize a path without touching the filesystem",
+                example: "'/home/viking/../spam/./eggs' | path normalize",
+                result: Some(Value::test_string("/home/spam/eggs")),
+            },
+            Example {
+                description: "A leading `..` is kept, since it can't be resolved lexically",
+                example: "'../spam/../eggs' | path normalize",
+                result: Some(Value::test_string("../eggs")),
+            },
+        ]
+    }
+}
+
+fn normalize(path: &Path, span: Span, _: &Arguments) -> Value {
+    Value::string(nu_path::dots::expand_dots(path).to_string_lossy(), span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(PathNormalize {})
+    }
+}
@@ -2,10 +2,11 @@ use super::PathSubcommandArguments;
 use nu_engine::command_prelude::*;
 use nu_path::expand_to_real_path;
 use nu_protocol::engine::StateWorkingSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 struct Arguments {
     path: Spanned<String>,
+    walk_up: bool,
 }
 
 impl PathSubcommandArguments for Arguments {}
@@ -32,6 +33,11 @@ impl Command for PathRelativeTo {
                 SyntaxShape::String,
                 "Parent shared with the input path.",
             )
+            .switch(
+                "walk-up",
+                "Allow the argument path to be a non-parent, walking up with `..` as needed.",
+                None,
+            )
             .category(Category::Path)
     }
 
@@ -41,8 +47,10 @@ impl Command for PathRelativeTo {
 
     fn extra_description(&self) -> &str {
         r#"Can be used only when the input and the argument paths are either both
-absolute or both relative. The argument path needs to be a parent of the input
-path."#
+absolute or both relative. Without `--walk-up`, the argument path needs to be a
+parent of the input path. With `--walk-up`, the argument path can be any path
+that shares an ancestor with the input path, and the result is prefixed with
+`..` components to walk up from the argument path to that ancestor."#
     }
 
     fn is_const(&self) -> bool {
@@ -59,6 +67,7 @@ path."#
         let head = call.head;
         let args = Arguments {
             path: call.req(engine_state, stack, 0)?,
+            walk_up: call.has_flag(engine_state, stack, "walk-up")?,
         };
 
         // This doesn't match explicit nulls
@@ -80,6 +89,7 @@ path."#
         let head = call.head;
         let args = Arguments {
             path: call.req_const(working_set, 0)?,
+            walk_up: call.has_flag_const(working_set, "walk-up")?,
         };
 
         // This doesn't match explicit nulls
@@ -113,6 +123,11 @@ path."#
                 example: r"'eggs\bacon\sausage\spam' | path relative-to 'eggs\bacon\sausage'",
                 result: Some(Value::test_string(r"spam")),
             },
+            Example {
+                description: "Walk up to a shared ancestor when the argument isn't a parent",
+                example: r"'C:\Users\viking\spam' | path relative-to 'C:\Users\viking\eggs' --walk-up",
+                result: Some(Value::test_string(r"..\spam")),
+            },
         ]
     }
 
@@ -137,6 +152,11 @@ path."#
                 example: r"'eggs/bacon/sausage/spam' | path relative-to 'eggs/bacon/sausage'",
                 result: Some(Value::test_string(r"spam")),
             },
+            Example {
+                description: "Walk up to a shared ancestor when the argument isn't a parent",
+                example: r"'/home/viking/spam' | path relative-to '/home/viking/eggs' --walk-up",
+                result: Some(Value::test_string(r"../spam")),
+            },
         ]
     }
 }
@@ -144,18 +164,46 @@ path."#
 fn relative_to(path: &Path, span: Span, args: &Arguments) -> Value {
     let lhs = expand_to_real_path(path);
     let rhs = expand_to_real_path(&args.path.item);
-    match lhs.strip_prefix(&rhs) {
-        Ok(p) => Value::string(p.to_string_lossy(), span),
-        Err(e) => Value::error(
-            ShellError::CantConvert {
-                to_type: e.to_string(),
-                from_type: "string".into(),
+
+    if args.walk_up {
+        Value::string(walk_up_relative_to(&lhs, &rhs).to_string_lossy(), span)
+    } else {
+        match lhs.strip_prefix(&rhs) {
+            Ok(p) => Value::string(p.to_string_lossy(), span),
+            Err(e) => Value::error(
+                ShellError::CantConvert {
+                    to_type: e.to_string(),
+                    from_type: "string".into(),
+                    span,
+                    help: None,
+                },
                 span,
-                help: None,
-            },
-            span,
-        ),
+            ),
+        }
+    }
+}
+
+/// Express `path` as relative to `base`, walking up from `base` with `..`
+/// components as needed to reach their common ancestor.
+fn walk_up_relative_to(path: &Path, base: &Path) -> PathBuf {
+    let path_components: Vec<_> = path.components().collect();
+    let base_components: Vec<_> = base.components().collect();
+
+    let common_len = path_components
+        .iter()
+        .zip(base_components.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut result = PathBuf::new();
+    for _ in common_len..base_components.len() {
+        result.push("..");
+    }
+    for component in &path_components[common_len..] {
+        result.push(component);
     }
+
+    result
 }
 
 #[cfg(test)]
@@ -0,0 +1,77 @@
+use super::open_store;
+use nu_engine::command_prelude::*;
+use rusqlite::{params, OptionalExtension};
+
+#[derive(Clone)]
+pub struct SecretGet;
+
+impl Command for SecretGet {
+    fn name(&self) -> &str {
+        "secret get"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret get")
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+            .required(
+                "name",
+                SyntaxShape::String,
+                "Name the secret was stored under with `secret set`.",
+            )
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Retrieve a secret value previously stored with `secret set`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["secret", "keychain", "credential", "token", "password"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Use a stored secret as a header value",
+            example: "http get https://api.example.com --headers [Authorization $'Bearer (secret get github-token)']",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+
+        let conn = open_store()?;
+        let value: Option<String> = conn
+            .query_row(
+                "SELECT value FROM nu_secrets WHERE name = ?1",
+                params![name],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| ShellError::GenericError {
+                error: "Failed to read secret".into(),
+                msg: e.to_string(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })?;
+
+        match value {
+            Some(value) => Ok(Value::string(value, span).into_pipeline_data()),
+            None => Err(ShellError::GenericError {
+                error: format!("No secret named '{name}'"),
+                msg: "not found".into(),
+                span: Some(span),
+                help: Some("store one first with `secret set`".into()),
+                inner: vec![],
+            }),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::{open_store, redact};
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SecretList;
+
+impl Command for SecretList {
+    fn name(&self) -> &str {
+        "secret list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret list")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "List the names of stored secrets, with values redacted."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["secret", "keychain", "credential", "token", "password"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "List stored secrets without revealing their values",
+            example: "secret list",
+            result: None,
+        }]
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+
+        let conn = open_store()?;
+        let mut stmt = conn
+            .prepare("SELECT name, value FROM nu_secrets ORDER BY name")
+            .map_err(|e| ShellError::GenericError {
+                error: "Failed to list secrets".into(),
+                msg: e.to_string(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let rows = stmt
+            .query_map([], |row| {
+                let name: String = row.get(0)?;
+                let value: String = row.get(1)?;
+                Ok((name, value))
+            })
+            .map_err(|e| ShellError::GenericError {
+                error: "Failed to list secrets".into(),
+                msg: e.to_string(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })?;
+
+        let mut records = Vec::new();
+        for row in rows {
+            let (name, value) = row.map_err(|e| ShellError::GenericError {
+                error: "Failed to list secrets".into(),
+                msg: e.to_string(),
+                span: Some(span),
+                help: None,
+                inner: vec![],
+            })?;
+            records.push(Value::record(
+                record! {
+                    "name" => Value::string(name, span),
+                    "value" => Value::string(redact(&value), span),
+                },
+                span,
+            ));
+        }
+
+        Ok(Value::list(records, span).into_pipeline_data())
+    }
+}
@@ -0,0 +1,57 @@
+mod get;
+mod list;
+mod secret_;
+mod set;
+
+pub use get::SecretGet;
+pub use list::SecretList;
+pub use secret_::Secret;
+pub use set::SecretSet;
+
+use nu_protocol::ShellError;
+use rusqlite::Connection;
+use std::sync::{LazyLock, Mutex, MutexGuard};
+
+const TABLE_NAME: &str = "nu_secrets";
+
+/// The private, in-memory sqlite connection backing `secret get/set/list`, held open for the
+/// life of the process.
+///
+/// This deliberately does *not* reuse `stor`'s shared `MEMORY_DB` connection: that database is
+/// reachable by name from any script (`stor open`, `open <path>`, `query db`), which would let
+/// any script read every secret back out in plaintext. This connection has no name at all - it's
+/// a private Rust object, not a named shared-cache database - so there's no `stor`/`query db`
+/// incantation that can reach it.
+static STORE: LazyLock<Mutex<Connection>> = LazyLock::new(|| {
+    let conn = Connection::open_in_memory().expect("failed to open in-memory secrets store");
+    conn.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {TABLE_NAME} (name TEXT PRIMARY KEY, value TEXT NOT NULL)"
+        ),
+        [],
+    )
+    .expect("failed to initialize secrets table");
+    Mutex::new(conn)
+});
+
+/// Lock the private secrets store connection.
+fn open_store() -> Result<MutexGuard<'static, Connection>, ShellError> {
+    STORE.lock().map_err(|_| ShellError::GenericError {
+        error: "Secrets store poisoned".into(),
+        msg: "a previous `secret` command panicked while holding the store lock".into(),
+        span: None,
+        help: None,
+        inner: vec![],
+    })
+}
+
+/// A short, fixed-width stand-in for a secret value, used anywhere a secret would otherwise be
+/// printed (`secret list`, error messages) so that pasting terminal output or a history file
+/// doesn't leak it.
+fn redact(value: &str) -> String {
+    if value.is_empty() {
+        String::new()
+    } else {
+        "•".repeat(8)
+    }
+}
@@ -0,0 +1,48 @@
+use nu_engine::{command_prelude::*, get_full_help};
+
+#[derive(Clone)]
+pub struct Secret;
+
+impl Command for Secret {
+    fn name(&self) -> &str {
+        "secret"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret")
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn description(&self) -> &str {
+        "Various commands for keeping secrets out of plaintext env vars and scripts."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Secrets are held in a private in-memory database, separate from `stor`'s, so they live
+only for the current session and are never written to disk, shown in `$env`, or reachable via
+`stor open`/`query db`. This covers the common case of not wanting a token to leak through `history`
+or `$env` display, but it
+is not a substitute for a real OS-backed secret store: the value still lives in this process's
+memory for as long as the session runs, and a fresh session starts with nothing. Backends for the
+OS keychain, libsecret, Windows Credential Manager, or an age-encrypted file would need their own
+platform-specific dependencies and aren't implemented here.
+
+You must use one of the following subcommands. Using this command as-is will only produce this
+help message."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["secret", "keychain", "credential", "token", "password"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+}
@@ -0,0 +1,79 @@
+use super::open_store;
+use nu_engine::command_prelude::*;
+use rusqlite::params;
+
+#[derive(Clone)]
+pub struct SecretSet;
+
+impl Command for SecretSet {
+    fn name(&self) -> &str {
+        "secret set"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("secret set")
+            .input_output_types(vec![(Type::Nothing, Type::Nothing), (Type::String, Type::Nothing)])
+            .required("name", SyntaxShape::String, "Name to store the secret under.")
+            .optional(
+                "value",
+                SyntaxShape::String,
+                "The secret value. If omitted, it's read from pipeline input instead.",
+            )
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Store a secret value under a name, for later use with `secret get`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["secret", "keychain", "credential", "token", "password"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Store a token as a secret",
+                example: "secret set github-token abc123",
+                result: None,
+            },
+            Example {
+                description: "Store a secret read from pipeline input, e.g. from a prompt",
+                example: "input -s 'API key: ' | secret set api-key",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let span = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let value: Option<String> = call.opt(engine_state, stack, 1)?;
+        let value = match value {
+            Some(value) => value,
+            None => input.into_value(span)?.coerce_into_string()?,
+        };
+
+        let conn = open_store()?;
+        conn.execute(
+            "INSERT INTO nu_secrets (name, value) VALUES (?1, ?2)
+             ON CONFLICT(name) DO UPDATE SET value = excluded.value",
+            params![name, value],
+        )
+        .map_err(|e| ShellError::GenericError {
+            error: "Failed to store secret".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+
+        Ok(PipelineData::empty())
+    }
+}
@@ -1,7 +1,7 @@
 use fancy_regex::{Captures, Regex};
 use nu_engine::command_prelude::*;
 use nu_protocol::{engine::StateWorkingSet, ListStream, Signals};
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 #[derive(Clone)]
 pub struct Parse;
@@ -149,13 +149,15 @@ fn operate(
         build_regex(&pattern_item, pattern_span)?
     };
 
-    let regex = Regex::new(&item_to_parse).map_err(|e| ShellError::GenericError {
-        error: "Error with regular expression".into(),
-        msg: e.to_string(),
-        span: Some(pattern_span),
-        help: None,
-        inner: vec![],
-    })?;
+    let regex = engine_state
+        .cached_regex(&item_to_parse)
+        .map_err(|e| ShellError::GenericError {
+            error: "Error with regular expression".into(),
+            msg: e.to_string(),
+            span: Some(pattern_span),
+            help: None,
+            inner: vec![],
+        })?;
 
     let columns = regex
         .capture_names()
@@ -304,7 +306,7 @@ fn build_regex(input: &str, span: Span) -> Result<String, ShellError> {
 
 struct ParseIter<I: Iterator<Item = Result<String, ShellError>>> {
     captures: VecDeque<Value>,
-    regex: Regex,
+    regex: Arc<Regex>,
     columns: Vec<String>,
     iter: I,
     span: Span,
@@ -179,10 +179,10 @@ fn split_column(
 ) -> Result<PipelineData, ShellError> {
     let name_span = call.head;
     let regex = if args.has_regex {
-        Regex::new(&args.separator.item)
+        engine_state.cached_regex(&args.separator.item)
     } else {
         let escaped = escape(&args.separator.item);
-        Regex::new(&escaped)
+        engine_state.cached_regex(&escaped)
     }
     .map_err(|e| ShellError::GenericError {
         error: "Error with regular expression".into(),
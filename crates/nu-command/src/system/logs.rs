@@ -0,0 +1,187 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::ListStream;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, ChildStdout, Command as SysCommand, Stdio};
+
+#[derive(Clone)]
+pub struct LogsTail;
+
+impl Command for LogsTail {
+    fn name(&self) -> &str {
+        "logs tail"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("logs tail")
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "only show entries from this service unit",
+                Some('u'),
+            )
+            .switch(
+                "follow",
+                "keep streaming new entries as they are logged, instead of exiting once caught up",
+                Some('f'),
+            )
+            .named(
+                "lines",
+                SyntaxShape::Int,
+                "number of most recent log lines to start from (default 10)",
+                Some('n'),
+            )
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Tail the system log, emitting structured records as a stream."
+    }
+
+    fn extra_description(&self) -> &str {
+        "Only the systemd journal (Linux) is currently supported; macOS unified logging and the \
+Windows event log are not yet implemented. With `--follow`, this streams indefinitely, so pair \
+it with `first`/`take` or `ctrl+c` to stop."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["journalctl", "journald", "eventlog", "syslog"]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let unit: Option<String> = call.get_flag(engine_state, stack, "unit")?;
+        let follow = call.has_flag(engine_state, stack, "follow")?;
+        let lines: i64 = call.get_flag(engine_state, stack, "lines")?.unwrap_or(10);
+
+        if !cfg!(target_os = "linux") {
+            return Err(unsupported_platform(head));
+        }
+
+        let mut args = vec!["-o".to_string(), "json".to_string()];
+        if let Some(unit) = &unit {
+            args.push("-u".to_string());
+            args.push(unit.clone());
+        }
+        args.push("-n".to_string());
+        args.push(lines.to_string());
+        if follow {
+            args.push("-f".to_string());
+        } else {
+            args.push("--no-pager".to_string());
+        }
+
+        let mut child = SysCommand::new("journalctl")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| ShellError::GenericError {
+                error: "Could not run journalctl".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: Some("is journalctl installed and on PATH?".into()),
+                inner: vec![],
+            })?;
+
+        let stdout = child
+            .stdout
+            .take()
+            .expect("journalctl was spawned with piped stdout");
+        let signals = engine_state.signals().clone();
+
+        let stream = JournalctlStream {
+            child,
+            reader: BufReader::new(stdout),
+            head,
+        };
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Show the last 10 journal entries for the sshd service",
+                example: "logs tail --unit sshd",
+                result: None,
+            },
+            Example {
+                description: "Stream new journal entries for the sshd service as they arrive",
+                example: "logs tail --unit sshd --follow | where ($it.priority | into int) <= 3",
+                result: None,
+            },
+        ]
+    }
+}
+
+/// Reads line-delimited JSON journal entries from a `journalctl` child process, killing the
+/// process when the stream is dropped so a `--follow` tail doesn't outlive its consumer.
+struct JournalctlStream {
+    child: Child,
+    reader: BufReader<ChildStdout>,
+    head: Span,
+}
+
+impl Iterator for JournalctlStream {
+    type Item = Value;
+
+    fn next(&mut self) -> Option<Value> {
+        loop {
+            let mut line = String::new();
+            let bytes_read = self.reader.read_line(&mut line).ok()?;
+            if bytes_read == 0 {
+                return None;
+            }
+
+            let Ok(entry) = serde_json::from_str::<serde_json::Value>(line.trim_end()) else {
+                continue;
+            };
+
+            let head = self.head;
+            let get = |key: &str| {
+                entry
+                    .get(key)
+                    .and_then(|v| v.as_str())
+                    .map(|s| Value::string(s, head))
+                    .unwrap_or(Value::nothing(head))
+            };
+
+            return Some(Value::record(
+                record! {
+                    "timestamp" => get("__REALTIME_TIMESTAMP"),
+                    "unit" => get("_SYSTEMD_UNIT"),
+                    "message" => get("MESSAGE"),
+                    "priority" => get("PRIORITY"),
+                },
+                head,
+            ));
+        }
+    }
+}
+
+impl Drop for JournalctlStream {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn unsupported_platform(span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Unsupported platform".into(),
+        msg: "only the systemd journal (Linux) is currently supported; macOS unified logging and the Windows event log are not yet implemented".into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
@@ -1,5 +1,6 @@
 mod complete;
 mod exec;
+mod logs;
 mod nu_check;
 #[cfg(any(
     target_os = "android",
@@ -14,12 +15,14 @@ mod ps;
 #[cfg(windows)]
 mod registry_query;
 mod run_external;
+mod service;
 mod sys;
 mod uname;
 mod which_;
 
 pub use complete::Complete;
 pub use exec::Exec;
+pub use logs::LogsTail;
 pub use nu_check::NuCheck;
 #[cfg(any(
     target_os = "android",
@@ -34,6 +37,7 @@ pub use ps::Ps;
 #[cfg(windows)]
 pub use registry_query::RegistryQuery;
 pub use run_external::{command_not_found, eval_external_arguments, which, External};
+pub use service::{Service, ServiceList, ServiceLogs, ServiceStart, ServiceStatus, ServiceStop};
 pub use sys::*;
 pub use uname::UName;
 pub use which_::Which;
@@ -1,11 +1,12 @@
 use nu_cmd_base::hook::eval_hook;
-use nu_engine::{command_prelude::*, env_to_strings};
+use nu_engine::{command_prelude::*, env_to_strings, ClosureEvalOnce};
 use nu_path::{dots::expand_ndots_safe, expand_tilde, AbsolutePath};
 use nu_protocol::{
     did_you_mean,
+    engine::Closure,
     process::{ChildProcess, PostWaitCallback},
     shell_error::io::IoError,
-    ByteStream, NuGlob, OutDest, Signals, UseAnsiColoring,
+    ByteStream, FromValue, NuGlob, OutDest, Signals, UseAnsiColoring,
 };
 use nu_system::{kill_by_pid, ForegroundChild};
 use nu_utils::IgnoreCaseExt;
@@ -34,6 +35,19 @@ impl Command for External {
         "Runs external command."
     }
 
+    fn extra_description(&self) -> &str {
+        r#"If `$env.config.external_decoders` has an entry matching the external command's name,
+its stdout is passed through that closure instead of being returned as-is, so the command's
+output comes back as structured data rather than raw text/bytes.
+
+Use `--print-argv` to see the resolved executable and each argument exactly as they'll be passed
+to the OS, without running anything - useful when a glob or quoting is behaving unexpectedly.
+Since the process is always spawned with an argument list rather than a shell command line, no
+shell-quoting is actually involved in running it; `--escape` is there purely so `--print-argv`
+can additionally show what those arguments would look like typed into a POSIX shell, PowerShell,
+or `cmd.exe`, for copying elsewhere."#
+    }
+
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build(self.name())
             .input_output_types(vec![(Type::Any, Type::Any)])
@@ -42,6 +56,17 @@ impl Command for External {
                 SyntaxShape::OneOf(vec![SyntaxShape::GlobPattern, SyntaxShape::Any]),
                 "External command to run, with arguments.",
             )
+            .switch(
+                "print-argv",
+                "Print the executable and each argument exactly as they'd be passed to the OS, without running anything.",
+                None,
+            )
+            .named(
+                "escape",
+                SyntaxShape::String,
+                "Used with --print-argv: also show each argument quoted for a target shell (`posix`, `powershell`, or `windows-cmd`), for pasting into a script for that shell. Defaults to `windows-cmd` on Windows, `posix` elsewhere.",
+                None,
+            )
             .category(Category::System)
     }
 
@@ -146,6 +171,42 @@ impl Command for External {
             executable
         };
 
+        // Configure args.
+        let args = eval_external_arguments(engine_state, stack, call_args.to_vec())?;
+
+        if call.has_flag(engine_state, stack, "print-argv")? {
+            let escape: Option<Spanned<String>> = call.get_flag(engine_state, stack, "escape")?;
+            let profile = match &escape {
+                Some(profile) => profile.item.as_str(),
+                None if cfg!(windows) => "windows-cmd",
+                None => "posix",
+            };
+
+            let mut rows = vec![Value::record(
+                record! {
+                    "arg" => Value::string(executable.to_string_lossy().into_owned(), call.head),
+                    "escaped" => Value::string(
+                        escape_for_profile(profile, executable.as_os_str(), call.head)?,
+                        call.head,
+                    ),
+                },
+                call.head,
+            )];
+            for arg in &args {
+                rows.push(Value::record(
+                    record! {
+                        "arg" => Value::string(arg.item.to_string_lossy().into_owned(), arg.span),
+                        "escaped" => Value::string(
+                            escape_for_profile(profile, &arg.item, arg.span)?,
+                            arg.span,
+                        ),
+                    },
+                    arg.span,
+                ));
+            }
+            return Ok(Value::list(rows, call.head).into_pipeline_data());
+        }
+
         // Create the command.
         let mut command = std::process::Command::new(executable);
 
@@ -157,8 +218,6 @@ impl Command for External {
         command.env_clear();
         command.envs(envs);
 
-        // Configure args.
-        let args = eval_external_arguments(engine_state, stack, call_args.to_vec())?;
         #[cfg(windows)]
         if is_cmd_internal_command(&name_str) || potential_nuscript_in_windows {
             // The /D flag disables execution of AutoRun commands from registry.
@@ -331,10 +390,24 @@ impl Command for External {
             child.ignore_error(true);
         }
 
-        Ok(PipelineData::ByteStream(
-            ByteStream::child(child, call.head),
-            None,
-        ))
+        let stream = ByteStream::child(child, call.head);
+
+        // If the user has registered a decoder for this external command's name, run its
+        // stdout through the decoder closure instead of handing back the raw byte stream.
+        let decoder = engine_state
+            .get_config()
+            .external_decoders
+            .get(name_str.as_ref())
+            .cloned();
+
+        match decoder {
+            Some(decoder) => {
+                let closure = Closure::from_value(decoder)?;
+                ClosureEvalOnce::new(engine_state, stack, closure)
+                    .run_with_value(stream.into_value()?)
+            }
+            None => Ok(PipelineData::ByteStream(stream, None)),
+        }
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
@@ -354,6 +427,16 @@ impl Command for External {
                 example: r#"run-external "nu" "-c" "print -e hello" e>| split chars"#,
                 result: None,
             },
+            Example {
+                description: "See exactly what would be run, without running it",
+                example: r#"run-external --print-argv "rg" "-i" "foo bar""#,
+                result: None,
+            },
+            Example {
+                description: "See how each argument would need to be quoted for a POSIX shell",
+                example: r#"run-external --print-argv --escape posix "rg" "-i" "foo bar""#,
+                result: None,
+            },
         ]
     }
 }
@@ -524,6 +607,11 @@ pub fn command_not_found(
     stack: &mut Stack,
     cwd: &AbsolutePath,
 ) -> ShellError {
+    // Try a fuzzy search on the names of all existing commands, so the `command_not_found` hook
+    // can use the suggestion without having to redo this search itself.
+    let signatures = engine_state.get_signatures_and_declids(false);
+    let similar_command = did_you_mean(signatures.iter().map(|(sig, _)| &sig.name), name);
+
     // Run the `command_not_found` hook if there is one.
     if let Some(hook) = &stack.get_config(engine_state).hooks.command_not_found {
         let mut stack = stack.start_collect_value();
@@ -546,7 +634,16 @@ pub fn command_not_found(
             &mut engine_state.clone(),
             &mut stack,
             None,
-            vec![("cmd_name".into(), Value::string(name, span))],
+            vec![
+                ("cmd_name".into(), Value::string(name, span)),
+                (
+                    "similar_command".into(),
+                    match &similar_command {
+                        Some(cmd) => Value::string(cmd.clone(), span),
+                        None => Value::nothing(span),
+                    },
+                ),
+            ],
             hook,
             "command_not_found",
         );
@@ -601,7 +698,6 @@ pub fn command_not_found(
     }
 
     // Try to match the name with the search terms of existing commands.
-    let signatures = engine_state.get_signatures_and_declids(false);
     if let Some((sig, _)) = signatures.iter().find(|(sig, _)| {
         sig.search_terms
             .iter()
@@ -615,7 +711,7 @@ pub fn command_not_found(
     }
 
     // Try a fuzzy search on the names of all existing commands.
-    if let Some(cmd) = did_you_mean(signatures.iter().map(|(sig, _)| &sig.name), name) {
+    if let Some(cmd) = similar_command {
         // The user is invoking an external command with the same name as a
         // built-in command. Remind them of this.
         if cmd == name {
@@ -680,8 +776,45 @@ fn has_cmd_special_character(s: impl AsRef<[u8]>) -> bool {
         .any(|b| matches!(b, b'<' | b'>' | b'&' | b'|' | b'^'))
 }
 
+/// Quote `arg` the way it would need to be written to be passed as a single argument by
+/// `profile`'s shell, for `run-external --print-argv --escape <profile>`. This is purely for
+/// display: the actual child process is always spawned with an argument list, which sidesteps
+/// shell quoting entirely, so no escaping here can affect what's actually run.
+fn escape_for_profile(profile: &str, arg: &OsStr, span: Span) -> Result<String, ShellError> {
+    match profile {
+        "windows-cmd" => {
+            let spanned = Spanned {
+                item: arg.to_os_string(),
+                span,
+            };
+            Ok(escape_cmd_argument(&spanned)?.to_string_lossy().into_owned())
+        }
+        "powershell" => {
+            let text = arg.to_string_lossy();
+            Ok(format!("'{}'", text.replace('\'', "''")))
+        }
+        "posix" => {
+            let text = arg.to_string_lossy();
+            let needs_quoting = text.is_empty()
+                || !text.bytes().all(|b| {
+                    b.is_ascii_alphanumeric()
+                        || matches!(b, b'_' | b'-' | b'.' | b'/' | b',' | b':' | b'@' | b'=')
+                });
+            if needs_quoting {
+                Ok(format!("'{}'", text.replace('\'', "'\\''")))
+            } else {
+                Ok(text.into_owned())
+            }
+        }
+        other => Err(ShellError::InvalidValue {
+            valid: "one of `posix`, `powershell`, `windows-cmd`".into(),
+            actual: other.into(),
+            span,
+        }),
+    }
+}
+
 /// Escape an argument for CMD internal commands. The result can be safely passed to `raw_arg()`.
-#[cfg_attr(not(windows), allow(dead_code))]
 fn escape_cmd_argument(arg: &Spanned<OsString>) -> Result<Cow<'_, OsStr>, ShellError> {
     let Spanned { item: arg, span } = arg;
     let bytes = arg.as_encoded_bytes();
@@ -0,0 +1,468 @@
+use nu_engine::{command_prelude::*, get_full_help};
+use std::process::{Command as SysCommand, Stdio};
+
+#[derive(Clone)]
+pub struct Service;
+
+impl Command for Service {
+    fn name(&self) -> &str {
+        "service"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service")
+            .filter()
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::String)])
+    }
+
+    fn description(&self) -> &str {
+        "Query and control services managed by the system's service manager."
+    }
+
+    fn extra_description(&self) -> &str {
+        "You must use one of the following subcommands. Using this command as-is will only \
+produce this help message. Only systemd (Linux) is currently supported; launchd and Windows \
+services are not yet implemented."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(Value::string(get_full_help(self, engine_state, stack), call.head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Show info about the service command",
+            example: "service",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceList;
+
+impl Command for ServiceList {
+    fn name(&self) -> &str {
+        "service list"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service list")
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "List services known to the system's service manager."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let units = systemctl_json(
+            &["list-units", "--type=service", "--all", "--output=json", "--no-pager"],
+            head,
+        )?;
+        let Value::List { vals, .. } = units else {
+            return Ok(Value::list(vec![], head).into_pipeline_data());
+        };
+
+        let rows = vals
+            .into_iter()
+            .map(|unit| {
+                let get = |key: &str| {
+                    unit.as_record()
+                        .ok()
+                        .and_then(|r| r.get(key))
+                        .and_then(|v| v.as_str().ok())
+                        .map(|s| Value::string(s, head))
+                        .unwrap_or(Value::nothing(head))
+                };
+                Value::record(
+                    record! {
+                        "unit" => get("unit"),
+                        "load" => get("load"),
+                        "active" => get("active"),
+                        "sub" => get("sub"),
+                        "description" => get("description"),
+                    },
+                    head,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Value::list(rows, head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "List all known services",
+            example: "service list",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceStatus;
+
+impl Command for ServiceStatus {
+    fn name(&self) -> &str {
+        "service status"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service status")
+            .required("name", SyntaxShape::String, "the service unit name")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Show the structured status of a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let properties = "Id,LoadState,ActiveState,SubState,Description,MainPID,ExecMainStartTimestamp";
+        let status = systemctl_json(
+            &["show", &name, &format!("--property={properties}"), "--output=json"],
+            head,
+        )?;
+
+        let get = |key: &str| {
+            status
+                .as_record()
+                .ok()
+                .and_then(|r| r.get(key))
+                .and_then(|v| v.as_str().ok())
+                .map(|s| Value::string(s, head))
+                .unwrap_or(Value::nothing(head))
+        };
+
+        let record = record! {
+            "id" => get("Id"),
+            "load_state" => get("LoadState"),
+            "active_state" => get("ActiveState"),
+            "sub_state" => get("SubState"),
+            "description" => get("Description"),
+            "main_pid" => get("MainPID"),
+            "started_at" => get("ExecMainStartTimestamp"),
+        };
+
+        Ok(Value::record(record, head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Show the status of the sshd service",
+            example: "service status sshd",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceStart;
+
+impl Command for ServiceStart {
+    fn name(&self) -> &str {
+        "service start"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service start")
+            .required("name", SyntaxShape::String, "the service unit name")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Start a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        run_systemctl_action("start", &name, head)
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Start the sshd service",
+            example: "service start sshd",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceStop;
+
+impl Command for ServiceStop {
+    fn name(&self) -> &str {
+        "service stop"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service stop")
+            .required("name", SyntaxShape::String, "the service unit name")
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Stop a service."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        run_systemctl_action("stop", &name, head)
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Stop the sshd service",
+            example: "service stop sshd",
+            result: None,
+        }]
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceLogs;
+
+impl Command for ServiceLogs {
+    fn name(&self) -> &str {
+        "service logs"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("service logs")
+            .required("name", SyntaxShape::String, "the service unit name")
+            .named(
+                "lines",
+                SyntaxShape::Int,
+                "number of most recent log lines to return (default 50)",
+                Some('n'),
+            )
+            .input_output_types(vec![(Type::Nothing, Type::table())])
+            .category(Category::System)
+    }
+
+    fn description(&self) -> &str {
+        "Show recent structured log entries for a service, via the journal."
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let name: String = call.req(engine_state, stack, 0)?;
+        let lines: i64 = call.get_flag(engine_state, stack, "lines")?.unwrap_or(50);
+
+        if !cfg!(target_os = "linux") {
+            return Err(unsupported_platform(head));
+        }
+
+        let output = SysCommand::new("journalctl")
+            .args([
+                "-u",
+                &name,
+                "-o",
+                "json",
+                "-n",
+                &lines.to_string(),
+                "--no-pager",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|e| ShellError::GenericError {
+                error: "Could not run journalctl".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: Some("is journalctl installed and on PATH?".into()),
+                inner: vec![],
+            })?;
+
+        if !output.status.success() {
+            return Err(ShellError::GenericError {
+                error: "journalctl failed".into(),
+                msg: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            });
+        }
+
+        let rows = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .map(|entry| {
+                let get = |key: &str| {
+                    entry
+                        .get(key)
+                        .and_then(|v| v.as_str())
+                        .map(|s| Value::string(s, head))
+                        .unwrap_or(Value::nothing(head))
+                };
+                Value::record(
+                    record! {
+                        "timestamp" => get("__REALTIME_TIMESTAMP"),
+                        "message" => get("MESSAGE"),
+                        "priority" => get("PRIORITY"),
+                    },
+                    head,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Value::list(rows, head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Show the last 50 log lines for the sshd service",
+            example: "service logs sshd",
+            result: None,
+        }]
+    }
+}
+
+fn unsupported_platform(span: Span) -> ShellError {
+    ShellError::GenericError {
+        error: "Unsupported platform".into(),
+        msg: "only systemd (Linux) is currently supported; launchd and Windows services are not yet implemented".into(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    }
+}
+
+fn run_systemctl_action(action: &str, name: &str, span: Span) -> Result<PipelineData, ShellError> {
+    if !cfg!(target_os = "linux") {
+        return Err(unsupported_platform(span));
+    }
+
+    let output = SysCommand::new("systemctl")
+        .args([action, name])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| ShellError::GenericError {
+            error: "Could not run systemctl".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: Some("is systemctl installed and on PATH?".into()),
+            inner: vec![],
+        })?;
+
+    let record = record! {
+        "name" => Value::string(name, span),
+        "action" => Value::string(action, span),
+        "success" => Value::bool(output.status.success(), span),
+        "message" => Value::string(String::from_utf8_lossy(&output.stderr).trim().to_string(), span),
+    };
+
+    Ok(Value::record(record, span).into_pipeline_data())
+}
+
+fn systemctl_json(args: &[&str], span: Span) -> Result<Value, ShellError> {
+    if !cfg!(target_os = "linux") {
+        return Err(unsupported_platform(span));
+    }
+
+    let output = SysCommand::new("systemctl")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .map_err(|e| ShellError::GenericError {
+            error: "Could not run systemctl".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: Some("is systemctl installed and on PATH?".into()),
+            inner: vec![],
+        })?;
+
+    if !output.status.success() {
+        return Err(ShellError::GenericError {
+            error: "systemctl failed".into(),
+            msg: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        });
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).map_err(|e| ShellError::GenericError {
+            error: "Could not parse systemctl output".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: Some("this requires a systemd version new enough to support --output=json".into()),
+            inner: vec![],
+        })?;
+
+    Ok(json_to_value(json, span))
+}
+
+fn json_to_value(json: serde_json::Value, span: Span) -> Value {
+    match json {
+        serde_json::Value::Null => Value::nothing(span),
+        serde_json::Value::Bool(b) => Value::bool(b, span),
+        serde_json::Value::Number(n) => n
+            .as_i64()
+            .map(|i| Value::int(i, span))
+            .unwrap_or_else(|| Value::float(n.as_f64().unwrap_or(0.0), span)),
+        serde_json::Value::String(s) => Value::string(s, span),
+        serde_json::Value::Array(vals) => Value::list(
+            vals.into_iter().map(|v| json_to_value(v, span)).collect(),
+            span,
+        ),
+        serde_json::Value::Object(map) => {
+            let mut record = Record::new();
+            for (k, v) in map {
+                record.push(k, json_to_value(v, span));
+            }
+            Value::record(record, span)
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use nu_engine::command_prelude::*;
+
+#[derive(Clone)]
+pub struct SysContainer;
+
+impl Command for SysContainer {
+    fn name(&self) -> &str {
+        "sys container"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("sys container")
+            .filter()
+            .category(Category::System)
+            .input_output_types(vec![(Type::Nothing, Type::record())])
+    }
+
+    fn description(&self) -> &str {
+        "View information about the container/cgroup this process is running in, if any."
+    }
+
+    fn extra_description(&self) -> &str {
+        "On Linux, reports cgroup memory/cpu limits and container id so that resource-monitoring \
+scripts can tell host-wide numbers (from `sys mem`/`sys cpu`) apart from the limits actually \
+enforced on this process. All fields are null/false on non-Linux platforms; on Linux, a field \
+whose cgroup or namespace file cannot be read is null."
+    }
+
+    fn run(
+        &self,
+        _engine_state: &EngineState,
+        _stack: &mut Stack,
+        call: &Call,
+        _input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        Ok(container(call.head).into_pipeline_data())
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Show container/cgroup info for the current process",
+            example: "sys container",
+            result: None,
+        }]
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn container(span: Span) -> Value {
+    let info = linux::gather();
+
+    let record = record! {
+        "in_container" => Value::bool(info.in_container, span),
+        "container_id" => info
+            .container_id
+            .map(|id| Value::string(id, span))
+            .unwrap_or(Value::nothing(span)),
+        "cgroup_version" => info
+            .cgroup_version
+            .map(|v| Value::int(v, span))
+            .unwrap_or(Value::nothing(span)),
+        "memory_limit" => info
+            .memory_limit
+            .map(|v| Value::filesize(v, span))
+            .unwrap_or(Value::nothing(span)),
+        "cpu_quota" => info
+            .cpu_quota
+            .map(|v| Value::float(v, span))
+            .unwrap_or(Value::nothing(span)),
+        "pid_namespace" => info
+            .pid_namespace
+            .map(|v| Value::string(v, span))
+            .unwrap_or(Value::nothing(span)),
+        "net_namespace" => info
+            .net_namespace
+            .map(|v| Value::string(v, span))
+            .unwrap_or(Value::nothing(span)),
+    };
+
+    Value::record(record, span)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn container(span: Span) -> Value {
+    let record = record! {
+        "in_container" => Value::bool(false, span),
+        "container_id" => Value::nothing(span),
+        "cgroup_version" => Value::nothing(span),
+        "memory_limit" => Value::nothing(span),
+        "cpu_quota" => Value::nothing(span),
+        "pid_namespace" => Value::nothing(span),
+        "net_namespace" => Value::nothing(span),
+    };
+
+    Value::record(record, span)
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::fs;
+    use std::path::Path;
+
+    pub(super) struct ContainerInfo {
+        pub in_container: bool,
+        pub container_id: Option<String>,
+        pub cgroup_version: Option<i64>,
+        pub memory_limit: Option<i64>,
+        pub cpu_quota: Option<f64>,
+        pub pid_namespace: Option<String>,
+        pub net_namespace: Option<String>,
+    }
+
+    pub(super) fn gather() -> ContainerInfo {
+        let cgroup_path = fs::read_to_string("/proc/self/cgroup").ok();
+        let container_id = cgroup_path.as_deref().and_then(extract_container_id);
+        let dockerenv = Path::new("/.dockerenv").exists();
+
+        let cgroup_v2 = Path::new("/sys/fs/cgroup/cgroup.controllers").exists();
+        let cgroup_version = if cgroup_v2 {
+            Some(2)
+        } else if Path::new("/sys/fs/cgroup/memory").exists() {
+            Some(1)
+        } else {
+            None
+        };
+
+        let memory_limit = if cgroup_v2 {
+            read_u64("/sys/fs/cgroup/memory.max")
+        } else {
+            read_u64("/sys/fs/cgroup/memory/memory.limit_in_bytes")
+        }
+        // an unset cgroup limit is reported as a huge sentinel value; treat it as "no limit"
+        .filter(|&v| v < i64::MAX as u64)
+        .map(|v| v as i64);
+
+        let cpu_quota = if cgroup_v2 {
+            fs::read_to_string("/sys/fs/cgroup/cpu.max").ok().and_then(|s| {
+                let mut parts = s.split_whitespace();
+                let quota = parts.next()?;
+                let period: f64 = parts.next()?.parse().ok()?;
+                if quota == "max" {
+                    None
+                } else {
+                    Some(quota.parse::<f64>().ok()? / period)
+                }
+            })
+        } else {
+            read_i64("/sys/fs/cgroup/cpu/cpu.cfs_quota_us").and_then(|quota| {
+                let period = read_i64("/sys/fs/cgroup/cpu/cpu.cfs_period_us")?;
+                if quota <= 0 {
+                    None
+                } else {
+                    Some(quota as f64 / period as f64)
+                }
+            })
+        };
+
+        let pid_namespace = read_namespace("/proc/self/ns/pid");
+        let net_namespace = read_namespace("/proc/self/ns/net");
+
+        let in_container = dockerenv || container_id.is_some();
+
+        ContainerInfo {
+            in_container,
+            container_id,
+            cgroup_version,
+            memory_limit,
+            cpu_quota,
+            pid_namespace,
+            net_namespace,
+        }
+    }
+
+    fn extract_container_id(cgroup: &str) -> Option<String> {
+        cgroup.lines().find_map(|line| {
+            let path = line.rsplit(':').next()?;
+            let segment = path.rsplit('/').find(|s| s.len() >= 12 && s.chars().all(|c| c.is_ascii_hexdigit()))?;
+            Some(segment.to_string())
+        })
+    }
+
+    fn read_u64(path: &str) -> Option<u64> {
+        fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    fn read_i64(path: &str) -> Option<i64> {
+        fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    fn read_namespace(path: &str) -> Option<String> {
+        fs::read_link(path).ok().map(|p| p.to_string_lossy().into_owned())
+    }
+}
@@ -1,3 +1,4 @@
+mod container;
 mod cpu;
 mod disks;
 mod host;
@@ -7,6 +8,7 @@ mod sys_;
 mod temp;
 mod users;
 
+pub use container::SysContainer;
 pub use cpu::SysCpu;
 pub use disks::SysDisks;
 pub use host::SysHost;
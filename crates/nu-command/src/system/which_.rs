@@ -16,7 +16,11 @@ impl Command for Which {
             .allow_variants_without_examples(true)
             .required("application", SyntaxShape::String, "Application.")
             .rest("rest", SyntaxShape::String, "Additional applications.")
-            .switch("all", "list all executables", Some('a'))
+            .switch(
+                "all",
+                "list every matching alias, custom command, builtin, and executable in resolution order",
+                Some('a'),
+            )
             .category(Category::System)
     }
 
@@ -60,12 +64,27 @@ fn entry(
     path: impl Into<String>,
     cmd_type: CommandType,
     span: Span,
+) -> Value {
+    entry_with_origin(arg, path, cmd_type, None, None, span)
+}
+
+fn entry_with_origin(
+    arg: impl Into<String>,
+    path: impl Into<String>,
+    cmd_type: CommandType,
+    overlay: Option<&[u8]>,
+    plugin: Option<&str>,
+    span: Span,
 ) -> Value {
     Value::record(
         record! {
             "command" => Value::string(arg, span),
             "path" => Value::string(path, span),
             "type" => Value::string(cmd_type.to_string(), span),
+            "overlay" => overlay.map_or(Value::nothing(span), |name| {
+                Value::string(String::from_utf8_lossy(name), span)
+            }),
+            "plugin" => plugin.map_or(Value::nothing(span), |name| Value::string(name, span)),
         },
         span,
     )
@@ -80,6 +99,37 @@ fn get_entry_in_commands(engine_state: &EngineState, name: &str, span: Span) ->
     }
 }
 
+#[cfg(feature = "plugin")]
+fn plugin_name(decl: &dyn Command) -> Option<&str> {
+    decl.plugin_identity().map(|identity| identity.name())
+}
+
+#[cfg(not(feature = "plugin"))]
+fn plugin_name(_decl: &dyn Command) -> Option<&str> {
+    None
+}
+
+/// Get an entry for every declaration matching `name` in scope (alias, custom, builtin, plugin),
+/// in the order they would shadow each other, so `which -a` can explain the whole chain.
+fn get_all_entries_in_commands(engine_state: &EngineState, name: &str, span: Span) -> Vec<Value> {
+    engine_state
+        .find_decls_with_name(name.as_bytes(), &[])
+        .into_iter()
+        .map(|(decl_id, overlay_name)| {
+            let decl = engine_state.get_decl(decl_id);
+            let plugin = plugin_name(decl);
+            entry_with_origin(
+                name,
+                "",
+                decl.command_type(),
+                Some(overlay_name),
+                plugin,
+                span,
+            )
+        })
+        .collect()
+}
+
 fn get_entries_in_nu(
     engine_state: &EngineState,
     name: &str,
@@ -151,11 +201,10 @@ fn which_single(
         (true, true) => get_all_entries_in_path(&prog_name, application.span, cwd, paths),
         (true, false) => {
             let mut output: Vec<Value> = vec![];
-            output.extend(get_entries_in_nu(
+            output.extend(get_all_entries_in_commands(
                 engine_state,
                 &prog_name,
                 application.span,
-                false,
             ));
             output.extend(get_all_entries_in_path(
                 &prog_name,
@@ -1,5 +1,7 @@
 mod griddle;
 mod table;
+mod tree;
 
 pub use griddle::Griddle;
 pub use table::Table;
+pub use tree::Tree;
@@ -17,8 +17,8 @@ use nu_protocol::{
     Signals, TableMode, ValueIterator,
 };
 use nu_table::{
-    common::configure_table, CollapsedTable, ExpandedTable, JustTable, NuRecordsValue, NuTable,
-    StringResult, TableOpts, TableOutput,
+    common::configure_table, string_width, CollapsedTable, ExpandedTable, JustTable,
+    NuRecordsValue, NuTable, StringResult, TableOpts, TableOutput,
 };
 use nu_utils::{get_ls_colors, terminal_size};
 
@@ -101,6 +101,22 @@ impl Command for Table {
                 Some('a'),
             )
             .switch("list", "list available table modes/themes", Some('l'))
+            .named(
+                "max-col-width",
+                SyntaxShape::Int,
+                "don't let any column's content be wider than this many terminal columns",
+                None,
+            )
+            .switch(
+                "wrap",
+                "word-wrap cells that exceed 'max-col-width' instead of truncating them",
+                None,
+            )
+            .switch(
+                "strict-width",
+                "recompute column widths from every page of a streamed table, instead of reusing the widths sampled from the first page",
+                None,
+            )
             .category(Category::Viewers)
     }
 
@@ -205,6 +221,11 @@ impl Command for Table {
                 example: r#"[[a b]; [1 2] [2 [4 4]]] | table -i false"#,
                 result: None,
             },
+            Example {
+                description: "Keep every column under 20 terminal columns wide, wrapping instead of truncating",
+                example: r#"ls | table --max-col-width 20 --wrap"#,
+                result: None,
+            },
         ]
     }
 }
@@ -217,9 +238,13 @@ struct TableConfig {
     abbreviation: Option<usize>,
     index: Option<usize>,
     use_ansi_coloring: bool,
+    max_col_width: Option<usize>,
+    wrap: bool,
+    strict_width: bool,
 }
 
 impl TableConfig {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         view: TableView,
         width: usize,
@@ -227,6 +252,9 @@ impl TableConfig {
         abbreviation: Option<usize>,
         index: Option<usize>,
         use_ansi_coloring: bool,
+        max_col_width: Option<usize>,
+        wrap: bool,
+        strict_width: bool,
     ) -> Self {
         Self {
             view,
@@ -235,6 +263,9 @@ impl TableConfig {
             abbreviation,
             index,
             use_ansi_coloring,
+            max_col_width,
+            wrap,
+            strict_width,
         }
     }
 }
@@ -261,6 +292,9 @@ struct CLIArgs {
     collapse: bool,
     index: Option<usize>,
     use_ansi_coloring: bool,
+    max_col_width: Option<usize>,
+    wrap: bool,
+    strict_width: bool,
 }
 
 fn parse_table_config(
@@ -279,6 +313,9 @@ fn parse_table_config(
         args.abbrivation,
         args.index,
         args.use_ansi_coloring,
+        args.max_col_width,
+        args.wrap,
+        args.strict_width,
     );
 
     Ok(cfg)
@@ -312,6 +349,9 @@ fn get_cli_args(call: &Call<'_>, state: &EngineState, stack: &mut Stack) -> Shel
     let index = get_index_flag(call, state, stack)?;
 
     let use_ansi_coloring = stack.get_config(state).use_ansi_coloring.get(state);
+    let max_col_width: Option<usize> = call.get_flag(state, stack, "max-col-width")?;
+    let wrap: bool = call.has_flag(state, stack, "wrap")?;
+    let strict_width: bool = call.has_flag(state, stack, "strict-width")?;
 
     Ok(CLIArgs {
         theme,
@@ -324,6 +364,9 @@ fn get_cli_args(call: &Call<'_>, state: &EngineState, stack: &mut Stack) -> Shel
         width,
         index,
         use_ansi_coloring,
+        max_col_width,
+        wrap,
+        strict_width,
     })
 }
 
@@ -813,6 +856,7 @@ struct PagingTableCreator {
     table_config: TableConfig,
     row_offset: usize,
     config: std::sync::Arc<Config>,
+    sampled_max_col_width: Option<usize>,
 }
 
 impl PagingTableCreator {
@@ -834,6 +878,7 @@ impl PagingTableCreator {
             elements_displayed: 0,
             reached_end: false,
             row_offset: 0,
+            sampled_max_col_width: None,
         }
     }
 
@@ -842,22 +887,66 @@ impl PagingTableCreator {
             return Ok(None);
         }
 
+        // Recomputing exact column widths for every page is the dominant cost of printing a huge
+        // stream, so unless --strict-width was passed, pin the width to what the first page (our
+        // sampled prefix) needed and reuse it for the rest of the stream instead of asking every
+        // later page to search for its own fit from scratch.
+        if !self.table_config.strict_width
+            && self.table_config.max_col_width.is_none()
+            && matches!(self.table_config.view, TableView::General)
+            && self.sampled_max_col_width.is_none()
+        {
+            self.sampled_max_col_width = Some(sample_max_col_width(&batch, &self.config));
+        }
+
         let opts = self.create_table_opts();
         build_table_batch(batch, self.table_config.view.clone(), opts, self.head)
     }
 
     fn create_table_opts(&self) -> TableOpts<'_> {
-        create_table_opts(
+        let mut opts = create_table_opts(
             &self.engine_state,
             &self.stack,
             &self.config,
             &self.table_config,
             self.head,
             self.row_offset,
-        )
+        );
+
+        if opts.max_col_width.is_none() {
+            opts.max_col_width = self.sampled_max_col_width;
+        }
+
+        opts
     }
 }
 
+/// Find the display width of the widest cell across `batch`, to use as a `max_col_width` sampled
+/// from a prefix of a streamed table rather than paying to measure every page.
+fn sample_max_col_width(batch: &[Value], config: &Config) -> usize {
+    let columns = nu_engine::column::get_columns(batch);
+
+    let cell_width = |value: &Value| string_width(&value.to_expanded_string(", ", config));
+
+    batch
+        .iter()
+        .flat_map(|item| match item {
+            Value::Record { val, .. } => {
+                if columns.is_empty() {
+                    vec![cell_width(item)]
+                } else {
+                    columns
+                        .iter()
+                        .filter_map(|col| val.get(col).map(cell_width))
+                        .collect()
+                }
+            }
+            item => vec![cell_width(item)],
+        })
+        .max()
+        .unwrap_or(0)
+}
+
 impl Iterator for PagingTableCreator {
     type Item = ShellResult<Vec<u8>>;
 
@@ -1172,6 +1261,7 @@ fn create_table_opts<'a>(
     let theme = table_cfg.theme;
 
     TableOpts::new(cfg, comp, signals, span, width, theme, offset, index)
+        .with_max_col_width(table_cfg.max_col_width, table_cfg.wrap)
 }
 
 fn get_cwd(engine_state: &EngineState, stack: &mut Stack) -> ShellResult<Option<NuPathBuf>> {
@@ -0,0 +1,160 @@
+use nu_engine::command_prelude::*;
+use nu_protocol::Config;
+
+#[derive(Clone)]
+pub struct Tree;
+
+impl Command for Tree {
+    fn name(&self) -> &str {
+        "tree"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("tree")
+            .input_output_types(vec![(Type::Any, Type::String)])
+            .named(
+                "depth",
+                SyntaxShape::Int,
+                "Stop expanding records and lists past this depth, showing a summary instead.",
+                Some('d'),
+            )
+            .named(
+                "collapse",
+                SyntaxShape::Int,
+                "Show a summary instead of expanding a record or list with more than this many children.",
+                Some('c'),
+            )
+            .category(Category::Viewers)
+    }
+
+    fn description(&self) -> &str {
+        "Render nested data as a tree with guide lines."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Prints records and lists as an indented tree, using box-drawing guides to show nesting,
+which is often easier to scan than a wide table for deeply nested API responses. `--depth` and
+`--collapse` bound how much of a large or deep structure gets expanded, showing a
+"{record n fields}"/"[list n items]" summary instead once a branch would otherwise get overwhelming."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["display", "nested", "json", "guides"]
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Render a nested record as a tree",
+                example: "{name: nu, tags: [shell, rust], author: {name: nu-team}} | tree",
+                result: None,
+            },
+            Example {
+                description: "Limit how deep the tree expands",
+                example: "$env | tree --depth 1",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        engine_state: &EngineState,
+        stack: &mut Stack,
+        call: &Call,
+        input: PipelineData,
+    ) -> Result<PipelineData, ShellError> {
+        let head = call.head;
+        let config = stack.get_config(engine_state);
+        let depth: Option<usize> = call
+            .get_flag(engine_state, stack, "depth")?
+            .map(|v: i64| v.max(0) as usize);
+        let collapse: Option<usize> = call
+            .get_flag(engine_state, stack, "collapse")?
+            .map(|v: i64| v.max(0) as usize);
+
+        let value = input.into_value(head)?;
+        let mut out = String::new();
+        render(&value, "", "", 0, depth, collapse, &config, &mut out);
+        // Remove the trailing newline left by the last rendered line.
+        out.pop();
+
+        Ok(Value::string(out, head).into_pipeline_data())
+    }
+}
+
+fn render(
+    value: &Value,
+    prefix: &str,
+    label: &str,
+    depth: usize,
+    max_depth: Option<usize>,
+    collapse: Option<usize>,
+    config: &Config,
+    out: &mut String,
+) {
+    let children = match value {
+        Value::Record { val, .. } => Some(
+            val.iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<Vec<_>>(),
+        ),
+        Value::List { vals, .. } => Some(
+            vals.iter()
+                .enumerate()
+                .map(|(i, v)| (i.to_string(), v.clone()))
+                .collect::<Vec<_>>(),
+        ),
+        _ => None,
+    };
+
+    let Some(children) = children else {
+        out.push_str(label);
+        out.push_str(&value.to_abbreviated_string(config));
+        out.push('\n');
+        return;
+    };
+
+    let too_deep = max_depth.is_some_and(|max| depth >= max);
+    let too_wide = collapse.is_some_and(|max| children.len() > max);
+    if too_deep || too_wide {
+        out.push_str(label);
+        out.push_str(&value.to_abbreviated_string(config));
+        out.push('\n');
+        return;
+    }
+
+    out.push_str(label);
+    out.push_str(&value.to_abbreviated_string(config));
+    out.push('\n');
+
+    let last_index = children.len().saturating_sub(1);
+    for (i, (key, child)) in children.into_iter().enumerate() {
+        let is_last = i == last_index;
+        let connector = if is_last { "└── " } else { "├── " };
+        let child_prefix = format!("{prefix}{}", if is_last { "    " } else { "│   " });
+        let child_label = format!("{prefix}{connector}{key}: ");
+        render(
+            &child,
+            &child_prefix,
+            &child_label,
+            depth + 1,
+            max_depth,
+            collapse,
+            config,
+            out,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        use crate::test_examples;
+
+        test_examples(Tree {})
+    }
+}
@@ -0,0 +1,50 @@
+use nu_test_support::nu;
+
+#[test]
+fn assert_passes_silently() {
+    let actual = nu!("assert (3 == 3); print done");
+
+    assert_eq!(actual.out, "done");
+}
+
+#[test]
+fn assert_fails_with_message() {
+    let actual = nu!("assert (3 == 4)");
+
+    assert!(actual.err.contains("It is not true."));
+}
+
+#[test]
+fn assert_fails_with_custom_message() {
+    let actual = nu!(r#"assert (3 == 4) --message "three is not four""#);
+
+    assert!(actual.err.contains("three is not four"));
+}
+
+#[test]
+fn assert_equal_passes_silently() {
+    let actual = nu!("assert equal 1 1; print done");
+
+    assert_eq!(actual.out, "done");
+}
+
+#[test]
+fn assert_equal_fails() {
+    let actual = nu!("assert equal 1 2");
+
+    assert!(actual.err.contains("These are not equal."));
+}
+
+#[test]
+fn assert_error_passes_when_closure_errors() {
+    let actual = nu!("assert error {|| 1 / 0 }; print done");
+
+    assert_eq!(actual.out, "done");
+}
+
+#[test]
+fn assert_error_fails_when_closure_succeeds() {
+    let actual = nu!("assert error {|| 12 }");
+
+    assert!(actual.err.contains("There were no error during code execution."));
+}
@@ -0,0 +1,53 @@
+use nu_test_support::nu;
+
+#[test]
+fn defer_runs_after_command_body() {
+    let actual = nu!(r#"def foo [] { defer { print "cleanup" }; print "body" }; foo"#);
+
+    assert_eq!(actual.out, "body\ncleanup");
+}
+
+#[test]
+fn defer_runs_on_error() {
+    let actual = nu!(
+        r#"def foo [] { defer { print "cleanup" }; error make {msg: "boom"} }; try { foo }"#
+    );
+
+    assert_eq!(actual.out, "cleanup");
+}
+
+#[test]
+fn defer_runs_in_reverse_order() {
+    let actual = nu!(
+        r#"def foo [] { defer { print "first" }; defer { print "second" } }; foo"#
+    );
+
+    assert_eq!(actual.out, "second\nfirst");
+}
+
+#[test]
+fn defer_runs_on_early_return() {
+    let actual = nu!(
+        r#"def foo [] { defer { print "cleanup" }; return "early"; print "unreachable" }; foo"#
+    );
+
+    assert_eq!(actual.out, "cleanup\nearly");
+}
+
+#[test]
+fn defer_runs_inside_each_closure() {
+    let actual = nu!(
+        r#"[1 2] | each {|x| defer { print $"cleanup ($x)" }; print $"body ($x)" } | ignore"#
+    );
+
+    assert_eq!(actual.out, "body 1\ncleanup 1\nbody 2\ncleanup 2");
+}
+
+#[test]
+fn defer_runs_inside_where_closure() {
+    let actual = nu!(
+        r#"[1 2] | where {|x| defer { print $"cleanup ($x)" }; $x == 2 } | ignore"#
+    );
+
+    assert_eq!(actual.out, "cleanup 1\ncleanup 2");
+}
@@ -40,3 +40,33 @@ fn failed_for_should_break_running() {
         print 3");
     assert!(!actual.out.contains('3'));
 }
+
+#[test]
+fn for_else_runs_on_empty_collection() {
+    let actual = nu!(r#"for i in [] { print $i } else { print "empty" }"#);
+    assert_eq!(actual.out, "empty");
+}
+
+#[test]
+fn for_else_does_not_run_after_continue_only_loop() {
+    let actual = nu!(r#"for i in [1 2] { continue } else { print "empty" }"#);
+    assert_eq!(actual.out, "");
+}
+
+#[test]
+fn for_else_does_not_run_after_non_empty_range() {
+    let actual = nu!(r#"for i in 1..3 { print $i } else { print "empty" }"#);
+    assert_eq!(actual.out, "123");
+}
+
+#[test]
+fn for_index_counts_from_zero() {
+    let actual = nu!(r#"for x in [a b c] --index $i { print $"($i):($x)" }"#);
+    assert_eq!(actual.out, "0:a1:b2:c");
+}
+
+#[test]
+fn for_index_counts_range_items() {
+    let actual = nu!(r#"for x in 10..12 --index $i { print $"($i):($x)" }"#);
+    assert_eq!(actual.out, "0:101:112:12");
+}
@@ -34,6 +34,26 @@ fn match_for_record_shorthand() {
     assert_eq!(actual.out, "12");
 }
 
+#[test]
+fn match_for_record_rest() {
+    let actual = nu!(
+        "match {a: 1, b: 2, c: 3} { {a: $a, ..$rest} => { $rest | columns | str join ',' } }"
+    );
+    assert_eq!(actual.out, "b,c");
+}
+
+#[test]
+fn match_for_record_ignore_rest() {
+    let actual = nu!("match {a: 1, b: 2} { {a: $a, ..} => { print $a } }");
+    assert_eq!(actual.out, "1");
+}
+
+#[test]
+fn match_for_record_rest_empty() {
+    let actual = nu!("match {a: 1} { {a: $a, ..$rest} => { $rest | columns | length } }");
+    assert_eq!(actual.out, "0");
+}
+
 #[test]
 fn match_list() {
     let actual = nu!(
@@ -2,6 +2,7 @@ mod alias;
 mod all;
 mod any;
 mod append;
+mod assert;
 mod assignment;
 mod base;
 mod break_;
@@ -22,6 +23,7 @@ mod date;
 mod debug_info;
 mod def;
 mod default;
+mod defer;
 mod detect_columns;
 mod do_;
 mod drop;
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use mockito::Server;
 use nu_test_support::fs::Stub::EmptyFile;
 use nu_test_support::fs::Stub::FileWithContent;
 use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
@@ -419,6 +420,40 @@ fn test_content_types_with_open_raw() {
     })
 }
 
+#[test]
+fn open_dash_reads_stdin() {
+    let actual = nu!(stdin: "hello from stdin".to_string(), "open --raw -");
+    assert_eq!(actual.out, "hello from stdin");
+}
+
+#[test]
+fn open_remote_url_raw() {
+    let mut server = Server::new();
+    let _mock = server.mock("GET", "/").with_body("remote body").create();
+
+    let actual = nu!(pipeline(
+        format!("open --raw {url}", url = server.url()).as_str()
+    ));
+
+    assert_eq!(actual.out, "remote body");
+}
+
+#[test]
+fn open_remote_url_uses_content_type_to_pick_converter() {
+    let mut server = Server::new();
+    let _mock = server
+        .mock("GET", "/")
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"greeting": "hi"}"#)
+        .create();
+
+    let actual = nu!(pipeline(
+        format!("open {url} | get greeting", url = server.url()).as_str()
+    ));
+
+    assert_eq!(actual.out, "hi");
+}
+
 #[test]
 fn test_metadata_without_raw() {
     Playground::setup("open_files_content_type_test", |dirs, _| {
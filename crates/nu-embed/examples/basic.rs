@@ -1,7 +1,11 @@
 use nu_embed::Engine;
+use nu_protocol::Span;
 
 fn main() {
     let source = "http get https://api.github.com/repos/nushell/nushell | get license";
     println!("Running: {source}");
-    Engine::new().eval(source);
+    match Engine::new().eval(source) {
+        Ok(pipeline) => println!("{:?}", pipeline.into_value(Span::unknown())),
+        Err(err) => eprintln!("Error: {err}"),
+    }
 }
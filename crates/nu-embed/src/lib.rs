@@ -1,17 +1,88 @@
-use nu_cli::{eval_source, gather_parent_env_vars};
+use nu_cli::gather_parent_env_vars;
 use nu_cmd_lang::create_default_context;
 use nu_command::add_shell_command_context;
-use nu_protocol::engine::{Stack, StateWorkingSet};
+use nu_engine::{eval_block, eval_block_with_early_return};
+use nu_parser::parse;
+use nu_protocol::debugger::WithoutDebug;
+use miette::Diagnostic as MietteDiagnostic;
+use nu_plugin_engine::{load_plugin_file, load_plugin_registry_item, GetPlugin, PersistentPlugin};
+use nu_protocol::engine::{EngineState, Stack, StateWorkingSet};
+use nu_protocol::{
+    CompileError, DeclId, ParseError, PluginGcConfig, PluginIdentity, PluginRegistryFile,
+    PluginRegistryItem, RegisteredPlugin, ShellError, Signals, Span,
+};
 pub use nu_protocol::{PipelineData, Value, engine::Command};
-use std::{collections::HashMap, env::current_dir, fmt::Debug, path::PathBuf};
+use std::{
+    collections::HashMap,
+    env::current_dir,
+    fmt::Debug,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use thiserror::Error;
+
+/// A handle that can cancel an in-progress [`Engine::eval`] call from another thread.
+///
+/// Cancelling causes the running script to stop with [`ShellError::Interrupted`], the same way
+/// pressing ctrl+c does in the interactive shell.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    flag: Arc<AtomicBool>,
+}
+
+impl CancelHandle {
+    /// Abort whichever evaluation of the handle's [`Engine`] is currently running.
+    pub fn cancel(&self) {
+        self.flag.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// Which built-in commands a sandboxed [`Engine`] is permitted to run.
+///
+/// The default is [`CommandPolicy::All`]: every built-in command is available, matching the
+/// behavior before sandboxing was added.
+#[derive(Debug, Clone, Default)]
+enum CommandPolicy {
+    #[default]
+    All,
+    Allow(Vec<String>),
+    Deny(Vec<String>),
+}
+
+/// An error produced while parsing or evaluating a script through [`Engine`].
+#[derive(Debug, Error)]
+pub enum EmbedError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error(transparent)]
+    Compile(#[from] CompileError),
+    #[error(transparent)]
+    Shell(#[from] ShellError),
+}
 
 #[derive(Clone)]
 pub struct Engine {
     commands: Vec<Box<dyn Command>>,
     env_vars: HashMap<String, Value>,
+    plugins: Vec<PluginRegistryItem>,
+    plugin_registries: Vec<PluginRegistryFile>,
 
     allow_return: bool,
     name: String,
+    timeout: Option<Duration>,
+    cancel_flag: Arc<AtomicBool>,
+    /// Bumped on every [`Engine::build_engine_state`] call. A timeout thread spawned for an
+    /// earlier call captures the generation it was spawned for and checks it's still current
+    /// before firing, so a stale timer left over from a finished (or superseded) call can't
+    /// reach across and cancel an unrelated later evaluation.
+    generation: Arc<AtomicU64>,
+    command_policy: CommandPolicy,
+    allow_external: bool,
 }
 
 impl Debug for Engine {
@@ -19,6 +90,8 @@ impl Debug for Engine {
         f.debug_struct("Engine")
             .field("commands", &"<list of extra commands>")
             .field("env_vars", &self.env_vars)
+            .field("plugins", &self.plugins)
+            .field("plugin_registries", &"<list of plugin registry files>")
             .field("allow_return", &self.allow_return)
             .field("name", &self.name)
             .finish()
@@ -34,9 +107,16 @@ impl Engine {
         Self {
             commands: Vec::new(),
             env_vars: HashMap::new(),
+            plugins: Vec::new(),
+            plugin_registries: Vec::new(),
 
             allow_return: false,
             name: name.into(),
+            timeout: None,
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            generation: Arc::new(AtomicU64::new(0)),
+            command_policy: CommandPolicy::All,
+            allow_external: true,
         }
     }
 
@@ -50,6 +130,65 @@ impl Engine {
         self.env_vars.insert(name.into(), value);
     }
 
+    /// Register a nushell plugin (e.g. `nu_plugin_polars`) from its executable path, making its
+    /// commands available to scripts run by this [`Engine`].
+    ///
+    /// This runs the plugin once, the same way `plugin add` does, to ask it for its command
+    /// signatures. The plugin process itself isn't started again until one of its commands is
+    /// actually used.
+    pub fn add_plugin(&mut self, path: impl Into<PathBuf>) -> Result<(), EmbedError> {
+        let path = std::fs::canonicalize(path.into()).map_err(|err| ShellError::GenericError {
+            error: "Could not resolve plugin path".into(),
+            msg: err.to_string(),
+            span: None,
+            help: None,
+            inner: vec![],
+        })?;
+
+        let identity = PluginIdentity::new(path, None).map_err(|_| ShellError::GenericError {
+            error: "Plugin filename is invalid".into(),
+            msg: "plugin executable files must start with `nu_plugin_`".into(),
+            span: None,
+            help: None,
+            inner: vec![],
+        })?;
+
+        let plugin = Arc::new(PersistentPlugin::new(
+            identity,
+            PluginGcConfig {
+                enabled: true,
+                stop_after: 0,
+                restart_on_crash: true,
+            },
+        ));
+        let interface = plugin.clone().get_plugin(None)?;
+        let metadata = interface.get_metadata()?;
+        let commands = interface.get_signature()?;
+
+        self.plugins
+            .push(PluginRegistryItem::new(plugin.identity(), metadata, commands));
+        Ok(())
+    }
+
+    /// Load every plugin listed in a plugin registry file (e.g. `plugin.msgpackz`), the same file
+    /// format `nu` itself uses for `$nu.plugin-path`.
+    ///
+    /// Unlike [`Engine::add_plugin`], this doesn't run any plugin process; it only reads the
+    /// signatures that were cached the last time `plugin add` was run against the file.
+    pub fn load_plugin_registry(&mut self, path: impl Into<PathBuf>) -> Result<(), EmbedError> {
+        let path = path.into();
+        let mut file = std::fs::File::open(&path).map_err(|err| ShellError::GenericError {
+            error: "Could not open plugin registry file".into(),
+            msg: err.to_string(),
+            span: None,
+            help: None,
+            inner: vec![],
+        })?;
+        let contents = PluginRegistryFile::read_from(&mut file, None)?;
+        self.plugin_registries.push(contents);
+        Ok(())
+    }
+
     /// Set the script name.
     pub fn set_name(&mut self, name: impl Into<String>) {
         self.name = name.into();
@@ -59,13 +198,76 @@ impl Engine {
         self.allow_return = allow_return;
     }
 
-    pub fn eval(&self, source: impl Into<String>) {
-        self.eval_with_input(source, PipelineData::Empty);
+    /// Abort evaluation if it hasn't finished within `duration`.
+    ///
+    /// This guards against runaway scripts (an infinite loop, a slow `http get`) without killing
+    /// the host process. The running script stops with [`ShellError::Interrupted`], the same way
+    /// pressing ctrl+c does in the interactive shell.
+    pub fn set_timeout(&mut self, duration: Duration) {
+        self.timeout = Some(duration);
     }
 
-    pub fn eval_with_input(&self, source: impl Into<String>, input: PipelineData) {
+    /// Restrict evaluation to only the given built-in commands, hiding everything else.
+    ///
+    /// Names are matched exactly against a command's full name (e.g. `"str upcase"`), the same
+    /// way `hide` matches command names. Calling this replaces any previous call to
+    /// [`Engine::allow_commands`] or [`Engine::deny_commands`].
+    pub fn allow_commands(&mut self, names: &[&str]) {
+        self.command_policy =
+            CommandPolicy::Allow(names.iter().map(|name| name.to_string()).collect());
+    }
+
+    /// Forbid the given built-in commands, hiding them while leaving everything else available.
+    ///
+    /// Calling this replaces any previous call to [`Engine::allow_commands`] or
+    /// [`Engine::deny_commands`].
+    pub fn deny_commands(&mut self, names: &[&str]) {
+        self.command_policy =
+            CommandPolicy::Deny(names.iter().map(|name| name.to_string()).collect());
+    }
+
+    /// Disable running external programs (`run-external`, and any bare command that isn't a
+    /// built-in) entirely. Enabled by default.
+    pub fn set_allow_external(&mut self, allow_external: bool) {
+        self.allow_external = allow_external;
+    }
+
+    /// Get a handle that can cancel a running [`Engine::eval`] call from another thread.
+    ///
+    /// The handle stays valid across repeated calls to [`Engine::eval`] on this [`Engine`]
+    /// (or a clone of it), so it can be obtained once and used to cancel whichever evaluation
+    /// happens to be running when [`CancelHandle::cancel`] is called. A cancel made while no
+    /// evaluation is running has no effect: the next evaluation clears it when it starts.
+    pub fn cancel_handle(&self) -> CancelHandle {
+        CancelHandle {
+            flag: self.cancel_flag.clone(),
+        }
+    }
+
+    /// Build a fresh, fully configured [`EngineState`] (extra commands added, env vars applied,
+    /// sandboxing enforced) without parsing or evaluating any script.
+    fn build_engine_state(&self) -> EngineState {
         let mut engine_state = add_shell_command_context(create_default_context());
-        let mut stack = Stack::new();
+
+        self.cancel_flag.store(false, Ordering::Relaxed);
+        let my_generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        engine_state.set_signals(Signals::new(self.cancel_flag.clone()));
+
+        if let Some(duration) = self.timeout {
+            let flag = self.cancel_flag.clone();
+            let generation = self.generation.clone();
+            thread::Builder::new()
+                .name("nu-embed timeout".into())
+                .spawn(move || {
+                    thread::sleep(duration);
+                    // A newer call to `build_engine_state` means this timer outlived the
+                    // evaluation it was meant for; firing now would cancel someone else's.
+                    if generation.load(Ordering::SeqCst) == my_generation {
+                        flag.store(true, Ordering::Relaxed);
+                    }
+                })
+                .expect("failed to spawn timeout thread");
+        }
 
         engine_state.is_interactive = false;
         gather_parent_env_vars(
@@ -82,6 +284,36 @@ impl Engine {
                 working_set.add_decl(command);
             }
 
+            for plugin in &self.plugins {
+                if let Err(err) = load_plugin_registry_item(&mut working_set, plugin, None) {
+                    eprintln!("Error loading plugin into the engine: {err:?}");
+                }
+            }
+            for registry in &self.plugin_registries {
+                load_plugin_file(&mut working_set, registry, None);
+            }
+
+            let mut hidden: Vec<Vec<u8>> = match &self.command_policy {
+                CommandPolicy::All => Vec::new(),
+                // Computed over `working_set`, not `engine_state`, so plugin commands
+                // registered above are considered too -- otherwise they'd silently bypass
+                // `allow_commands`, since they don't exist in `engine_state` until the delta
+                // returned by this block is merged in below.
+                CommandPolicy::Allow(allowed) => (0..working_set.num_decls())
+                    .map(DeclId::new)
+                    .map(|id| working_set.get_decl(id).name().to_string())
+                    .filter(|name| !allowed.iter().any(|allowed| allowed == name))
+                    .map(String::into_bytes)
+                    .collect(),
+                CommandPolicy::Deny(denied) => {
+                    denied.iter().map(|name| name.clone().into_bytes()).collect()
+                }
+            };
+            if !self.allow_external {
+                hidden.push(b"run-external".to_vec());
+            }
+            working_set.hide_decls(&hidden);
+
             working_set.render()
         };
 
@@ -89,17 +321,106 @@ impl Engine {
             eprintln!("Error adding extra commands to the engine: {err:?}");
         }
 
+        engine_state
+    }
+
+    pub fn eval(&self, source: impl Into<String>) -> Result<PipelineData, EmbedError> {
+        self.eval_with_input(source, PipelineData::Empty)
+    }
+
+    pub fn eval_with_input(
+        &self,
+        source: impl Into<String>,
+        input: PipelineData,
+    ) -> Result<PipelineData, EmbedError> {
+        let mut engine_state = self.build_engine_state();
+        let mut stack = Stack::new();
+
         for env_var in self.env_vars.clone() {
             stack.add_env_var(env_var.0, env_var.1);
         }
 
-        eval_source(
-            &mut engine_state,
-            &mut stack,
-            source.into().as_bytes(),
-            &self.name,
-            input,
-            self.allow_return,
-        );
+        let source = source.into();
+        let block = {
+            let mut working_set = StateWorkingSet::new(&engine_state);
+            let block = parse(&mut working_set, Some(&self.name), source.as_bytes(), false);
+
+            if let Some(err) = working_set.parse_errors.first() {
+                return Err(err.clone().into());
+            }
+            if let Some(err) = working_set.compile_errors.first() {
+                return Err(err.clone().into());
+            }
+
+            let delta = working_set.render();
+            engine_state.merge_delta(delta)?;
+            block
+        };
+
+        let pipeline = if self.allow_return {
+            eval_block_with_early_return::<WithoutDebug>(&engine_state, &mut stack, &block, input)
+        } else {
+            eval_block::<WithoutDebug>(&engine_state, &mut stack, &block, input)
+        }?;
+
+        Ok(pipeline)
+    }
+
+    /// Parse (and type-check) a script without evaluating it, returning every diagnostic found.
+    ///
+    /// An empty result means the script parsed cleanly. This never runs any of the script's
+    /// code, so it's safe to use on untrusted input before deciding whether to call
+    /// [`Engine::eval`].
+    pub fn check(&self, source: impl Into<String>) -> Vec<Diagnostic> {
+        let engine_state = self.build_engine_state();
+        let source = source.into();
+        let mut working_set = StateWorkingSet::new(&engine_state);
+        parse(&mut working_set, Some(&self.name), source.as_bytes(), false);
+
+        working_set
+            .parse_errors
+            .iter()
+            .map(Diagnostic::from_miette)
+            .chain(working_set.compile_errors.iter().map(Diagnostic::from_miette))
+            .collect()
+    }
+}
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Advice,
+    Warning,
+    Error,
+}
+
+/// A single issue found by [`Engine::check`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// The location in the source that the diagnostic applies to, if known.
+    pub span: Option<Span>,
+    pub message: String,
+    pub severity: Severity,
+}
+
+impl Diagnostic {
+    fn from_miette(err: &(impl std::error::Error + MietteDiagnostic)) -> Self {
+        let severity = match err.severity() {
+            Some(miette::Severity::Advice) => Severity::Advice,
+            Some(miette::Severity::Warning) => Severity::Warning,
+            Some(miette::Severity::Error) | None => Severity::Error,
+        };
+
+        let span = err.labels().and_then(|mut labels| labels.next()).map(|label| {
+            let start = label.offset();
+            let end = start + label.len().max(1);
+            Span::new(start, end)
+        });
+
+        Diagnostic {
+            span,
+            message: err.to_string(),
+            severity,
+        }
     }
 }
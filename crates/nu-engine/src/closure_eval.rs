@@ -1,5 +1,6 @@
 use crate::{
-    eval_block_with_early_return, get_eval_block_with_early_return, EvalBlockWithEarlyReturnFn,
+    eval_block_with_early_return, get_eval_block_with_early_return, run_deferred_closures,
+    EvalBlockWithEarlyReturnFn,
 };
 use nu_protocol::{
     ast::Block,
@@ -146,7 +147,8 @@ impl ClosureEval {
     pub fn run_with_input(&mut self, input: PipelineData) -> Result<PipelineData, ShellError> {
         self.arg_index = 0;
         self.stack.with_env(&self.env_vars, &self.env_hidden);
-        (self.eval)(&self.engine_state, &mut self.stack, &self.block, input)
+        let result = (self.eval)(&self.engine_state, &mut self.stack, &self.block, input);
+        run_deferred_closures(&self.engine_state, &mut self.stack, self.eval, result)
     }
 
     /// Run the closure using the given [`Value`] as both the pipeline input and the first argument.
@@ -261,7 +263,8 @@ impl<'a> ClosureEvalOnce<'a> {
     ///
     /// Any arguments should be added beforehand via [`add_arg`](Self::add_arg).
     pub fn run_with_input(mut self, input: PipelineData) -> Result<PipelineData, ShellError> {
-        (self.eval)(self.engine_state, &mut self.stack, self.block, input)
+        let result = (self.eval)(self.engine_state, &mut self.stack, self.block, input);
+        run_deferred_closures(self.engine_state, &mut self.stack, self.eval, result)
     }
 
     /// Run the closure using the given [`Value`] as both the pipeline input and the first argument.
@@ -463,42 +463,63 @@ impl BlockBuilder {
     }
 
     /// Push a new loop state onto the builder. Creates new labels that must be set.
-    pub(crate) fn begin_loop(&mut self) -> Loop {
+    pub(crate) fn begin_loop(&mut self, label: Option<String>) -> Loop {
         let loop_ = Loop {
             break_label: self.label(None),
             continue_label: self.label(None),
+            label,
         };
-        self.loop_stack.push(loop_);
+        self.loop_stack.push(loop_.clone());
         loop_
     }
 
-    /// True if we are currently in a loop.
-    pub(crate) fn is_in_loop(&self) -> bool {
-        !self.loop_stack.is_empty()
+    /// True if we are currently in a loop, or, if `label` is given, in a loop with that label.
+    pub(crate) fn is_in_loop(&self, label: Option<&str>) -> bool {
+        self.find_loop(label).is_some()
+    }
+
+    /// Find the nearest loop on the stack, or the nearest one with a matching label if given.
+    fn find_loop(&self, label: Option<&str>) -> Option<&Loop> {
+        match label {
+            None => self.loop_stack.last(),
+            Some(label) => self
+                .loop_stack
+                .iter()
+                .rev()
+                .find(|loop_| loop_.label.as_deref() == Some(label)),
+        }
     }
 
     /// Add a loop breaking jump instruction.
-    pub(crate) fn push_break(&mut self, span: Span) -> Result<(), CompileError> {
-        let loop_ = self
-            .loop_stack
-            .last()
+    pub(crate) fn push_break(
+        &mut self,
+        label: Option<&str>,
+        span: Span,
+    ) -> Result<(), CompileError> {
+        let break_label = self
+            .find_loop(label)
             .ok_or_else(|| CompileError::NotInALoop {
                 msg: "`break` called from outside of a loop".into(),
                 span: Some(span),
-            })?;
-        self.jump(loop_.break_label, span)
+            })?
+            .break_label;
+        self.jump(break_label, span)
     }
 
     /// Add a loop continuing jump instruction.
-    pub(crate) fn push_continue(&mut self, span: Span) -> Result<(), CompileError> {
-        let loop_ = self
-            .loop_stack
-            .last()
+    pub(crate) fn push_continue(
+        &mut self,
+        label: Option<&str>,
+        span: Span,
+    ) -> Result<(), CompileError> {
+        let continue_label = self
+            .find_loop(label)
             .ok_or_else(|| CompileError::NotInALoop {
                 msg: "`continue` called from outside of a loop".into(),
                 span: Some(span),
-            })?;
-        self.jump(loop_.continue_label, span)
+            })?
+            .continue_label;
+        self.jump(continue_label, span)
     }
 
     /// Pop the loop state. Checks that the loop being ended is the same one that was expected.
@@ -580,10 +601,13 @@ impl BlockBuilder {
 }
 
 /// Keeps track of the `break` and `continue` target labels for a loop.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct Loop {
     pub(crate) break_label: LabelId,
     pub(crate) continue_label: LabelId,
+    /// The loop's `--label`, if it has one, so a `break`/`continue` in a nested loop can target
+    /// this one specifically instead of the nearest enclosing loop.
+    pub(crate) label: Option<String>,
 }
 
 /// Add a new comment to an existing one
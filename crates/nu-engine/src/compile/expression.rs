@@ -6,6 +6,7 @@ use super::{
 use nu_protocol::{
     ast::{CellPath, Expr, Expression, ListItem, RecordItem, ValueWithUnit},
     engine::StateWorkingSet,
+    eval_const::eval_constant,
     ir::{DataSlice, Instruction, Literal},
     IntoSpanned, RegId, Span, Value, ENV_VARIABLE_ID,
 };
@@ -165,6 +166,17 @@ pub(crate) fn compile_expression(
         }
         Expr::BinaryOp(lhs, op, rhs) => {
             if let Expr::Operator(operator) = op.expr {
+                // Fold operations on literal operands (e.g. `1 + 2`, `"a" ++ "b"`) into a single
+                // `LoadLiteral` at compile time, using the same evaluator as `const`. If the
+                // operation isn't actually foldable to a representable literal -- including if it
+                // would error at runtime, like `1 / 0` -- fall back to compiling it normally so
+                // the error is still reported the usual way.
+                if is_foldable_literal(lhs) && is_foldable_literal(rhs) {
+                    if let Some(literal) = fold_binary_op(working_set, builder, expr)? {
+                        return lit(builder, literal);
+                    }
+                }
+
                 drop_input(builder)?;
                 compile_binary_op(
                     working_set,
@@ -570,3 +582,46 @@ fn literal_from_value_with_unit(value_with_unit: &ValueWithUnit) -> Result<Liter
         }),
     }
 }
+
+/// True if `expr` is made up entirely of literals and operators on literals, so it's safe and
+/// cheap to evaluate at compile time with [`eval_constant`].
+pub(super) fn is_foldable_literal(expr: &Expression) -> bool {
+    match &expr.expr {
+        Expr::Bool(_) | Expr::Int(_) | Expr::Float(_) | Expr::String(_) | Expr::RawString(_) => {
+            true
+        }
+        Expr::FullCellPath(full_cell_path) => {
+            full_cell_path.tail.is_empty() && is_foldable_literal(&full_cell_path.head)
+        }
+        Expr::UnaryNot(subexpr) => is_foldable_literal(subexpr),
+        Expr::BinaryOp(lhs, op, rhs) => {
+            matches!(op.expr, Expr::Operator(_))
+                && is_foldable_literal(lhs)
+                && is_foldable_literal(rhs)
+        }
+        _ => false,
+    }
+}
+
+/// Try to fold a binary operation on literal operands into a single [`Literal`] by evaluating it
+/// the same way `const` expressions are evaluated. Returns `Ok(None)` if the result isn't a value
+/// that has a direct `Literal` representation, or if evaluating it would produce an error (in
+/// which case the error should surface at runtime instead, from the normally compiled
+/// instructions).
+fn fold_binary_op(
+    working_set: &StateWorkingSet,
+    builder: &mut BlockBuilder,
+    expr: &Expression,
+) -> Result<Option<Literal>, CompileError> {
+    let Ok(value) = eval_constant(working_set, expr) else {
+        return Ok(None);
+    };
+    Ok(match value {
+        Value::Bool { val, .. } => Some(Literal::Bool(val)),
+        Value::Int { val, .. } => Some(Literal::Int(val)),
+        Value::Float { val, .. } => Some(Literal::Float(val)),
+        Value::String { val, .. } => Some(Literal::String(builder.data(val)?)),
+        Value::Nothing { .. } => Some(Literal::Nothing),
+        _ => None,
+    })
+}
@@ -1,7 +1,7 @@
 use nu_protocol::{
-    ast::{Block, Call, Expr, Expression},
+    ast::{Block, Call, Comparison, Expr, Expression, Math, Operator},
     engine::StateWorkingSet,
-    ir::Instruction,
+    ir::{Instruction, Literal},
     IntoSpanned, RegId, Type, VarId,
 };
 
@@ -586,7 +586,9 @@ pub(crate) fn compile_loop(
     let block_id = block_arg.as_block().ok_or_else(invalid)?;
     let block = working_set.get_block(block_id);
 
-    let loop_ = builder.begin_loop();
+    let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+
+    let loop_ = builder.begin_loop(label);
     builder.load_empty(io_reg)?;
 
     builder.set_label(loop_.continue_label, builder.here())?;
@@ -644,7 +646,9 @@ pub(crate) fn compile_while(
     let block_id = block_arg.as_block().ok_or_else(invalid)?;
     let block = working_set.get_block(block_id);
 
-    let loop_ = builder.begin_loop();
+    let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+
+    let loop_ = builder.begin_loop(label);
     builder.set_label(loop_.continue_label, builder.here())?;
 
     let true_label = builder.label(None);
@@ -699,15 +703,23 @@ pub(crate) fn compile_for(
     _redirect_modes: RedirectModes,
     io_reg: RegId,
 ) -> Result<(), CompileError> {
-    // Pseudocode:
+    // Pseudocode (the `--index` and `else` bits only appear when used):
     //
     //       %stream_reg <- <in_expr>
+    //       %count_reg <- 0
     // LOOP: iterate %io_reg, %stream_reg, END
     //       store-variable $var, %io_reg
+    //       store-variable $index, %count_reg (cloned)
+    //       %count_reg <- %count_reg + 1
     //       %io_reg <- <...block...>
     //       drain %io_reg
     //       jump LOOP
-    // END:  drop %io_reg
+    // END:  %count_reg <- %count_reg == 0
+    //       branch-if %count_reg, RUN_ELSE
+    //       drop %io_reg
+    //       jump AFTER_ELSE
+    // RUN_ELSE: %io_reg <- <...else_block...>
+    // AFTER_ELSE:
     let invalid = || CompileError::InvalidKeywordCall {
         keyword: "for".into(),
         span: call.head,
@@ -728,6 +740,21 @@ pub(crate) fn compile_for(
     let block_id = block_arg.as_block().ok_or_else(invalid)?;
     let block = working_set.get_block(block_id);
 
+    let index_var_id = call.get_flag_expr("index").and_then(|expr| expr.as_var());
+
+    let else_block_id = call
+        .positional_nth(3)
+        .and_then(|else_case| else_case.as_keyword())
+        .and_then(|else_expr| else_expr.as_block());
+
+    // We need to keep track of how many items we've seen, both to feed the `--index` variable
+    // and to know whether the `else` branch should run.
+    let count_reg = if index_var_id.is_some() || else_block_id.is_some() {
+        Some(builder.literal(Literal::Int(0).into_spanned(call.head))?)
+    } else {
+        None
+    };
+
     // Ensure io_reg is marked so we don't use it
     builder.mark_register(io_reg)?;
 
@@ -743,7 +770,8 @@ pub(crate) fn compile_for(
     )?;
 
     // Set up loop state
-    let loop_ = builder.begin_loop();
+    let label = call.get_flag_expr("label").and_then(|expr| expr.as_string());
+    let loop_ = builder.begin_loop(label);
     builder.set_label(loop_.continue_label, builder.here())?;
 
     // This gets a value from the stream each time it's executed
@@ -767,6 +795,32 @@ pub(crate) fn compile_for(
         .into_spanned(var_decl_arg.span),
     )?;
 
+    // Bind the current index to the `--index` variable, if requested
+    if let Some(index_var_id) = index_var_id {
+        let count_reg = count_reg.expect("count_reg present when index_var_id is present");
+        let index_reg = builder.clone_reg(count_reg, var_decl_arg.span)?;
+        builder.push(
+            Instruction::StoreVariable {
+                var_id: index_var_id,
+                src: index_reg,
+            }
+            .into_spanned(var_decl_arg.span),
+        )?;
+    }
+
+    // Bump the running count, now that this item has been accounted for
+    if let Some(count_reg) = count_reg {
+        let one_reg = builder.literal(Literal::Int(1).into_spanned(call.head))?;
+        builder.push(
+            Instruction::BinaryOp {
+                lhs_dst: count_reg,
+                op: Operator::Math(Math::Add),
+                rhs: one_reg,
+            }
+            .into_spanned(call.head),
+        )?;
+    }
+
     // Do the body of the block
     compile_block(
         working_set,
@@ -788,28 +842,68 @@ pub(crate) fn compile_for(
     builder.end_loop(loop_)?;
 
     // We don't need stream_reg anymore, after the loop
-    // io_reg may or may not be empty, so be sure it is
     builder.free_register(stream_reg)?;
+
+    // io_reg may or may not be empty, so be sure it is, unless the `else` branch runs
     builder.mark_register(io_reg)?;
-    builder.load_empty(io_reg)?;
+
+    if let Some(else_block_id) = else_block_id {
+        let else_block = working_set.get_block(else_block_id);
+        let count_reg = count_reg.expect("count_reg present when else_block_id is present");
+
+        let zero_reg = builder.literal(Literal::Int(0).into_spanned(call.head))?;
+        builder.push(
+            Instruction::BinaryOp {
+                lhs_dst: count_reg,
+                op: Operator::Comparison(Comparison::Equal),
+                rhs: zero_reg,
+            }
+            .into_spanned(call.head),
+        )?;
+
+        let run_else_label = builder.label(None);
+        let after_else_label = builder.label(None);
+
+        builder.branch_if(count_reg, run_else_label, call.head)?;
+        builder.load_empty(io_reg)?;
+        builder.jump(after_else_label, call.head)?;
+
+        builder.set_label(run_else_label, builder.here())?;
+        compile_block(
+            working_set,
+            builder,
+            else_block,
+            RedirectModes::default(),
+            None,
+            io_reg,
+        )?;
+
+        builder.set_label(after_else_label, builder.here())?;
+    } else {
+        builder.load_empty(io_reg)?;
+    }
 
     Ok(())
 }
 
 /// Compile a call to `break`.
 pub(crate) fn compile_break(
-    _working_set: &StateWorkingSet,
+    working_set: &StateWorkingSet,
     builder: &mut BlockBuilder,
     call: &Call,
     _redirect_modes: RedirectModes,
     io_reg: RegId,
 ) -> Result<(), CompileError> {
-    if builder.is_in_loop() {
+    let label_arg = call.positional_nth(0);
+    let label = label_arg.and_then(|expr| expr.as_string());
+    if builder.is_in_loop(label.as_deref()) {
         builder.load_empty(io_reg)?;
-        builder.push_break(call.head)?;
+        builder.push_break(label.as_deref(), call.head)?;
         builder.add_comment("break");
     } else {
-        // Fall back to calling the command if we can't find the loop target statically
+        // Fall back to calling the command if we can't find the loop target statically. This
+        // also handles the (rare) case of a non-literal label, which we can't resolve here.
+        push_flow_control_label_arg(working_set, builder, label_arg)?;
         builder.push(
             Instruction::Call {
                 decl_id: call.decl_id,
@@ -823,18 +917,22 @@ pub(crate) fn compile_break(
 
 /// Compile a call to `continue`.
 pub(crate) fn compile_continue(
-    _working_set: &StateWorkingSet,
+    working_set: &StateWorkingSet,
     builder: &mut BlockBuilder,
     call: &Call,
     _redirect_modes: RedirectModes,
     io_reg: RegId,
 ) -> Result<(), CompileError> {
-    if builder.is_in_loop() {
+    let label_arg = call.positional_nth(0);
+    let label = label_arg.and_then(|expr| expr.as_string());
+    if builder.is_in_loop(label.as_deref()) {
         builder.load_empty(io_reg)?;
-        builder.push_continue(call.head)?;
+        builder.push_continue(label.as_deref(), call.head)?;
         builder.add_comment("continue");
     } else {
-        // Fall back to calling the command if we can't find the loop target statically
+        // Fall back to calling the command if we can't find the loop target statically. This
+        // also handles the (rare) case of a non-literal label, which we can't resolve here.
+        push_flow_control_label_arg(working_set, builder, label_arg)?;
         builder.push(
             Instruction::Call {
                 decl_id: call.decl_id,
@@ -846,6 +944,28 @@ pub(crate) fn compile_continue(
     Ok(())
 }
 
+/// Compile and push the optional `label` positional argument of `break`/`continue` onto the
+/// argument stack, for the dynamic call fallback used when the loop target isn't statically known.
+fn push_flow_control_label_arg(
+    working_set: &StateWorkingSet,
+    builder: &mut BlockBuilder,
+    label_arg: Option<&Expression>,
+) -> Result<(), CompileError> {
+    if let Some(label_arg) = label_arg {
+        let label_reg = builder.next_register()?;
+        compile_expression(
+            working_set,
+            builder,
+            label_arg,
+            RedirectModes::value(label_arg.span),
+            None,
+            label_reg,
+        )?;
+        builder.push(Instruction::PushPositional { src: label_reg }.into_spanned(label_arg.span))?;
+    }
+    Ok(())
+}
+
 /// Compile a call to `return` as a `return-early` instruction.
 ///
 /// This is not strictly necessary, but it is more efficient.
@@ -1,6 +1,7 @@
 use nu_protocol::{
     ast::{Block, Pipeline, PipelineRedirection, RedirectionSource, RedirectionTarget},
     engine::StateWorkingSet,
+    eval_const::eval_constant,
     ir::{Instruction, IrBlock, RedirectMode},
     CompileError, IntoSpanned, RegId, Span,
 };
@@ -14,7 +15,7 @@ mod redirect;
 
 use builder::BlockBuilder;
 use call::*;
-use expression::compile_expression;
+use expression::{compile_expression, is_foldable_literal};
 use operator::*;
 use redirect::*;
 
@@ -58,6 +59,17 @@ fn compile_block(
     if !block.pipelines.is_empty() {
         let last_index = block.pipelines.len() - 1;
         for (index, pipeline) in block.pipelines.iter().enumerate() {
+            // Pipelines other than the first never receive input from a previous register, so a
+            // pipeline made up of just a literal (`42`, `"foo" ++ "bar"`, ...) has no way to have
+            // any effect before its result is thrown away by the following `;`. Skip compiling it
+            // rather than loading the literal just to immediately drain it.
+            if index != 0
+                && index != last_index
+                && is_dead_literal_pipeline(working_set, pipeline)
+            {
+                continue;
+            }
+
             compile_pipeline(
                 working_set,
                 builder,
@@ -93,6 +105,23 @@ fn compile_block(
     }
 }
 
+/// True if `pipeline` is a single expression made up entirely of literals, so it can't have any
+/// effect and its instructions can be skipped entirely when its result would just be discarded.
+///
+/// This also requires the literal to actually evaluate without error: something shaped like a
+/// foldable literal (e.g. `1 / 0`) can still error when evaluated, and skipping it outright would
+/// silently swallow that error instead of raising it the way the un-skipped instructions would.
+fn is_dead_literal_pipeline(working_set: &StateWorkingSet, pipeline: &Pipeline) -> bool {
+    match pipeline.elements.as_slice() {
+        [element] => {
+            element.redirection.is_none()
+                && is_foldable_literal(&element.expr)
+                && eval_constant(working_set, &element.expr).is_ok()
+        }
+        _ => false,
+    }
+}
+
 fn compile_pipeline(
     working_set: &StateWorkingSet,
     builder: &mut BlockBuilder,
@@ -541,6 +541,12 @@ fn write_positional(
                     stack
                 )
             );
+        } else if let Some(expr) = &positional.default_value_expr {
+            let _ = write!(
+                long_desc,
+                " (optional, default: {})",
+                String::from_utf8_lossy(engine_state.get_span_contents(expr.span))
+            );
         } else {
             long_desc.push_str(" (optional)");
         };
@@ -575,9 +581,18 @@ where
         if !flag.long.is_empty() {
             let _ = write!(long_desc, "{help_subcolor_one}--{}{RESET}", flag.long);
         }
+        for alias in &flag.aliases {
+            let _ = write!(
+                long_desc,
+                "{DEFAULT_COLOR},{RESET} {help_subcolor_one}--{alias}{RESET}"
+            );
+        }
         if flag.required {
             long_desc.push_str(" (required parameter)")
         }
+        if flag.multiple {
+            long_desc.push_str(" (multiple)")
+        }
         // Type/Syntax shape info
         if let Some(arg) = &flag.arg {
             let _ = write!(
@@ -591,6 +606,8 @@ where
         }
         if let Some(value) = &flag.default_value {
             let _ = write!(long_desc, " (default: {})", &value_formatter(value));
+        } else if flag.default_value_expr.is_some() {
+            long_desc.push_str(" (default: computed at call time)");
         }
         long_desc.push('\n');
     }
@@ -76,6 +76,9 @@ pub fn eval_call<D: DebugContext>(
                 callee_stack.add_var(var_id, result);
             } else if let Some(value) = &param.default_value {
                 callee_stack.add_var(var_id, value.to_owned());
+            } else if let Some(expr) = &param.default_value_expr {
+                let value = eval_expression::<D>(engine_state, &mut callee_stack, expr)?;
+                callee_stack.add_var(var_id, value);
             } else {
                 callee_stack.add_var(var_id, Value::nothing(call.head));
             }
@@ -108,49 +111,52 @@ pub fn eval_call<D: DebugContext>(
 
         for named in decl.signature().named {
             if let Some(var_id) = named.var_id {
-                let mut found = false;
+                let mut occurrences = vec![];
                 for call_named in call.named_iter() {
-                    if let (Some(spanned), Some(short)) = (&call_named.1, named.short) {
-                        if spanned.item == short.to_string() {
-                            if let Some(arg) = &call_named.2 {
-                                let result = eval_expression::<D>(engine_state, caller_stack, arg)?;
-
-                                callee_stack.add_var(var_id, result);
-                            } else if let Some(value) = &named.default_value {
-                                callee_stack.add_var(var_id, value.to_owned());
-                            } else {
-                                callee_stack.add_var(var_id, Value::bool(true, call.head))
-                            }
-                            found = true;
-                        }
-                    } else if call_named.0.item == named.long {
+                    let matches = if let (Some(spanned), Some(short)) =
+                        (&call_named.1, named.short)
+                    {
+                        spanned.item == short.to_string()
+                    } else {
+                        call_named.0.item == named.long
+                    };
+
+                    if matches {
                         if let Some(arg) = &call_named.2 {
                             let result = eval_expression::<D>(engine_state, caller_stack, arg)?;
-
-                            callee_stack.add_var(var_id, result);
-                        } else if let Some(value) = &named.default_value {
-                            callee_stack.add_var(var_id, value.to_owned());
+                            occurrences.push(result);
                         } else {
-                            callee_stack.add_var(var_id, Value::bool(true, call.head))
+                            occurrences.push(Value::bool(true, call.head));
                         }
-                        found = true;
                     }
                 }
 
-                if !found {
-                    if named.arg.is_none() {
-                        callee_stack.add_var(var_id, Value::bool(false, call.head))
-                    } else if let Some(value) = named.default_value {
-                        callee_stack.add_var(var_id, value);
-                    } else {
-                        callee_stack.add_var(var_id, Value::nothing(call.head))
-                    }
+                if named.multiple {
+                    let span = occurrences.first().map(|v| v.span()).unwrap_or(call.head);
+                    callee_stack.add_var(var_id, Value::list(occurrences, span));
+                } else if let Some(result) = occurrences.into_iter().last() {
+                    callee_stack.add_var(var_id, result);
+                } else if let Some(value) = &named.default_value {
+                    callee_stack.add_var(var_id, value.to_owned());
+                } else if let Some(expr) = &named.default_value_expr {
+                    let value = eval_expression::<D>(engine_state, &mut callee_stack, expr)?;
+                    callee_stack.add_var(var_id, value);
+                } else if named.arg.is_none() {
+                    callee_stack.add_var(var_id, Value::bool(false, call.head))
+                } else {
+                    callee_stack.add_var(var_id, Value::nothing(call.head))
                 }
             }
         }
 
         let result =
             eval_block_with_early_return::<D>(engine_state, &mut callee_stack, block, input);
+        let result = crate::run_deferred_closures(
+            engine_state,
+            &mut callee_stack,
+            eval_block_with_early_return::<D>,
+            result,
+        );
 
         if block.redirect_env {
             redirect_env(engine_state, caller_stack, &callee_stack);
@@ -328,6 +334,7 @@ pub fn eval_collect<D: DebugContext>(
         Some(PipelineMetadata {
             data_source: DataSource::FilePath(_),
             content_type: None,
+            custom: None,
         }) => None,
         other => other,
     };
@@ -91,3 +91,39 @@ pub fn get_eval_subexpression(engine_state: &EngineState) -> EvalSubexpressionFn
         eval_subexpression::<WithoutDebug>
     }
 }
+
+/// Run every closure registered on `stack` via `defer` since the current call frame started,
+/// in reverse registration order, then return `result`.
+///
+/// Deferred closures run even if `result` is an error, so that cleanup always happens. If a
+/// deferred closure itself errors, that error takes precedence only when `result` was `Ok`;
+/// the block's own error always wins over a defer's error.
+pub fn run_deferred_closures(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    eval_block_with_early_return: EvalBlockWithEarlyReturnFn,
+    result: Result<PipelineData, ShellError>,
+) -> Result<PipelineData, ShellError> {
+    let deferred = stack.take_deferred();
+    if deferred.is_empty() {
+        return result;
+    }
+
+    let mut result = result;
+    for closure in deferred.into_iter().rev() {
+        let mut callee_stack = stack.captures_to_stack_preserve_out_dest(closure.captures);
+        let block = engine_state.get_block(closure.block_id);
+        let defer_result = eval_block_with_early_return(
+            engine_state,
+            &mut callee_stack,
+            block,
+            PipelineData::empty(),
+        )
+        .and_then(|data| data.drain_to_out_dests(engine_state, &mut callee_stack));
+
+        if let (Err(err), true) = (defer_result, result.is_ok()) {
+            result = Err(err);
+        }
+    }
+    result
+}
@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fs::File, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, fs::File, sync::Arc};
 
 use nu_path::{expand_path_with, AbsolutePathBuf};
 use nu_protocol::{
@@ -16,7 +16,8 @@ use nu_protocol::{
 use nu_utils::IgnoreCaseExt;
 
 use crate::{
-    convert_env_vars, eval::is_automatic_env_var, eval_block_with_early_return, ENV_CONVERSIONS,
+    convert_env_vars, eval::is_automatic_env_var, eval_block_with_early_return, get_eval_expression,
+    ENV_CONVERSIONS,
 };
 
 /// Evaluate the compiled representation of a [`Block`].
@@ -1053,6 +1054,12 @@ fn eval_call<D: DebugContext>(
 
             let result =
                 eval_block_with_early_return::<D>(engine_state, &mut callee_stack, block, input);
+            let result = crate::run_deferred_closures(
+                engine_state,
+                &mut callee_stack,
+                eval_block_with_early_return::<D>,
+                result,
+            );
 
             // Move environment variables back into the caller stack scope if requested to do so
             if block.redirect_env {
@@ -1096,12 +1103,12 @@ fn eval_call<D: DebugContext>(
     result
 }
 
-fn find_named_var_id(
-    sig: &Signature,
+fn find_named_flag<'a>(
+    sig: &'a Signature,
     name: &[u8],
     short: &[u8],
     span: Span,
-) -> Result<VarId, ShellError> {
+) -> Result<&'a Flag, ShellError> {
     sig.named
         .iter()
         .find(|n| {
@@ -1120,7 +1127,6 @@ fn find_named_var_id(
             ),
             span: Some(span),
         })
-        .and_then(|flag| expect_named_var_id(flag, span))
 }
 
 fn expect_named_var_id(arg: &Flag, span: Span) -> Result<VarId, ShellError> {
@@ -1172,6 +1178,9 @@ fn gather_arguments(
     // If we encounter a spread, all further positionals should go to rest
     let mut always_spread = false;
 
+    // Occurrences collected so far for flags declared with `multiple`, keyed by var id
+    let mut multiple_occurrences: HashMap<VarId, Vec<Value>> = HashMap::new();
+
     for arg in caller_stack.arguments.drain_args(args_base, args_len) {
         match arg {
             Argument::Positional { span, val, .. } => {
@@ -1207,8 +1216,16 @@ fn gather_arguments(
                 short,
                 span,
             } => {
-                let var_id = find_named_var_id(&block.signature, &data[name], &data[short], span)?;
-                callee_stack.add_var(var_id, Value::bool(true, span))
+                let flag = find_named_flag(&block.signature, &data[name], &data[short], span)?;
+                let var_id = expect_named_var_id(flag, span)?;
+                if flag.multiple {
+                    multiple_occurrences
+                        .entry(var_id)
+                        .or_default()
+                        .push(Value::bool(true, span));
+                } else {
+                    callee_stack.add_var(var_id, Value::bool(true, span))
+                }
             }
             Argument::Named {
                 data,
@@ -1218,8 +1235,13 @@ fn gather_arguments(
                 val,
                 ..
             } => {
-                let var_id = find_named_var_id(&block.signature, &data[name], &data[short], span)?;
-                callee_stack.add_var(var_id, val)
+                let flag = find_named_flag(&block.signature, &data[name], &data[short], span)?;
+                let var_id = expect_named_var_id(flag, span)?;
+                if flag.multiple {
+                    multiple_occurrences.entry(var_id).or_default().push(val);
+                } else {
+                    callee_stack.add_var(var_id, val)
+                }
             }
             Argument::ParserInfo { .. } => (),
         }
@@ -1232,16 +1254,24 @@ fn gather_arguments(
         callee_stack.add_var(var_id, Value::list(rest, rest_span));
     }
 
+    // Collapse each `multiple` flag's occurrences into a single list value
+    for (var_id, occurrences) in multiple_occurrences {
+        let span = occurrences.first().map(|v| v.span()).unwrap_or(call_head);
+        callee_stack.add_var(var_id, Value::list(occurrences, span));
+    }
+
     // Check for arguments that haven't yet been set and set them to their defaults
+    let eval_expression = get_eval_expression(engine_state);
     for (positional_arg, _) in positional_iter {
         let var_id = expect_positional_var_id(positional_arg, call_head)?;
-        callee_stack.add_var(
-            var_id,
-            positional_arg
-                .default_value
-                .clone()
-                .unwrap_or(Value::nothing(call_head)),
-        );
+        let val = if let Some(value) = &positional_arg.default_value {
+            value.clone()
+        } else if let Some(expr) = &positional_arg.default_value_expr {
+            eval_expression(engine_state, callee_stack, expr)?
+        } else {
+            Value::nothing(call_head)
+        };
+        callee_stack.add_var(var_id, val);
     }
 
     for named_arg in &block.signature.named {
@@ -1250,10 +1280,14 @@ fn gather_arguments(
             // the stack. This assumes that the stack's variables was previously empty, but that's a
             // fair assumption for a brand new callee stack.
             if !callee_stack.vars.iter().any(|(id, _)| *id == var_id) {
-                let val = if named_arg.arg.is_none() {
+                let val = if named_arg.multiple {
+                    Value::list(vec![], call_head)
+                } else if named_arg.arg.is_none() {
                     Value::bool(false, call_head)
                 } else if let Some(value) = &named_arg.default_value {
                     value.clone()
+                } else if let Some(expr) = &named_arg.default_value_expr {
+                    eval_expression(engine_state, callee_stack, expr)?
                 } else {
                     Value::nothing(call_head)
                 };
@@ -1460,6 +1494,7 @@ fn collect(data: PipelineData, fallback_span: Span) -> Result<PipelineData, Shel
         Some(PipelineMetadata {
             data_source: DataSource::FilePath(_),
             content_type: None,
+            custom: None,
         }) => None,
         other => other,
     };
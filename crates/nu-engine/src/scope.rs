@@ -1,5 +1,5 @@
 use nu_protocol::{
-    ast::Expr,
+    ast::{Expr, Expression},
     engine::{Command, EngineState, Stack, Visibility},
     record, DeclId, ModuleId, Signature, Span, SyntaxShape, Type, Value, VarId,
 };
@@ -207,6 +207,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                 "description" => Value::nothing(span),
                 "custom_completion" => Value::nothing(span),
                 "parameter_default" => Value::nothing(span),
+                "flag_aliases" => Value::list(vec![], span),
+                "multiple" => Value::bool(false, span),
             },
             span,
         ));
@@ -225,6 +227,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                     "description" => Value::string(&req.desc, span),
                     "custom_completion" => Value::string(custom, span),
                     "parameter_default" => Value::nothing(span),
+                    "flag_aliases" => Value::list(vec![], span),
+                    "multiple" => Value::bool(false, span),
                 },
                 span,
             ));
@@ -235,6 +239,8 @@ impl<'e, 's> ScopeData<'e, 's> {
             let custom = extract_custom_completion_from_arg(self.engine_state, &opt.shape);
             let default = if let Some(val) = &opt.default_value {
                 val.clone()
+            } else if let Some(expr) = &opt.default_value_expr {
+                Value::string(self.default_value_expr_source(expr), span)
             } else {
                 Value::nothing(span)
             };
@@ -249,6 +255,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                     "description" => Value::string(&opt.desc, span),
                     "custom_completion" => Value::string(custom, span),
                     "parameter_default" => default,
+                    "flag_aliases" => Value::list(vec![], span),
+                    "multiple" => Value::bool(false, span),
                 },
                 span,
             ));
@@ -270,6 +278,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                     "custom_completion" => Value::string(custom, span),
                     // rest_positional does have default, but parser prohibits specifying it?!
                     "parameter_default" => Value::nothing(span),
+                    "flag_aliases" => Value::list(vec![], span),
+                    "multiple" => Value::bool(true, span),
                 },
                 span,
             ));
@@ -303,6 +313,8 @@ impl<'e, 's> ScopeData<'e, 's> {
 
             let default = if let Some(val) = &named.default_value {
                 val.clone()
+            } else if let Some(expr) = &named.default_value_expr {
+                Value::string(self.default_value_expr_source(expr), span)
             } else {
                 Value::nothing(span)
             };
@@ -317,6 +329,15 @@ impl<'e, 's> ScopeData<'e, 's> {
                     "description" => Value::string(&named.desc, span),
                     "custom_completion" => Value::string(custom_completion_command_name, span),
                     "parameter_default" => default,
+                    "flag_aliases" => Value::list(
+                        named
+                            .aliases
+                            .iter()
+                            .map(|alias| Value::string(alias, span))
+                            .collect(),
+                        span,
+                    ),
+                    "multiple" => Value::bool(named.multiple, span),
                 },
                 span,
             ));
@@ -333,6 +354,8 @@ impl<'e, 's> ScopeData<'e, 's> {
                 "description" => Value::nothing(span),
                 "custom_completion" => Value::nothing(span),
                 "parameter_default" => Value::nothing(span),
+                "flag_aliases" => Value::list(vec![], span),
+                "multiple" => Value::bool(false, span),
             },
             span,
         ));
@@ -340,6 +363,12 @@ impl<'e, 's> ScopeData<'e, 's> {
         sig_records
     }
 
+    /// Render the source text of a non-constant default value expression, for display purposes
+    /// only (introspection has no call context to evaluate it against).
+    fn default_value_expr_source(&self, expr: &Expression) -> String {
+        String::from_utf8_lossy(self.engine_state.get_span_contents(expr.span)).into_owned()
+    }
+
     pub fn collect_externs(&self, span: Span) -> Vec<Value> {
         let mut externals = vec![];
 
@@ -0,0 +1,117 @@
+use super::SimpleCommand;
+use crate::{
+    nu_common::collect_input,
+    pager::{Pager, Transition},
+};
+use anyhow::{bail, Result};
+use nu_protocol::{
+    engine::{EngineState, Stack},
+    Config, Value,
+};
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+/// Writes the value currently shown in the pager out to a file, inferring the format (csv or
+/// json) from the file extension. Meant for saving whatever a `where`/`sort-by` chain of `try`
+/// commands narrowed the view down to, without leaving explore to do it.
+#[derive(Debug, Default, Clone)]
+pub struct ExportCmd {
+    path: Option<PathBuf>,
+}
+
+impl ExportCmd {
+    pub const NAME: &'static str = "export";
+}
+
+impl SimpleCommand for ExportCmd {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn description(&self) -> &'static str {
+        "Export the current view to a csv or json file (:export <path>)"
+    }
+
+    fn parse(&mut self, args: &str) -> Result<()> {
+        let path = args.trim();
+        if path.is_empty() {
+            bail!("expected a file path, e.g. :export out.csv");
+        }
+
+        self.path = Some(PathBuf::from(path));
+        Ok(())
+    }
+
+    fn react(
+        &mut self,
+        _: &EngineState,
+        _: &mut Stack,
+        _: &mut Pager<'_>,
+        value: Option<Value>,
+    ) -> Result<Transition> {
+        let path = self
+            .path
+            .take()
+            .ok_or_else(|| anyhow::anyhow!("no destination path was given"))?;
+
+        let value = value.unwrap_or_default();
+        write_export(&path, value)?;
+
+        Ok(Transition::Ok)
+    }
+}
+
+fn write_export(path: &Path, value: Value) -> Result<()> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => write_json(path, value),
+        Some("csv") | None => write_csv(path, value),
+        Some(ext) => bail!("unsupported export format '{ext}', expected csv or json"),
+    }
+}
+
+fn write_json(path: &Path, value: Value) -> Result<()> {
+    let json = nu_json::to_string(&value_to_json(&value))?;
+    File::create(path)?.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+fn value_to_json(value: &Value) -> nu_json::Value {
+    match value {
+        Value::Bool { val, .. } => nu_json::Value::Bool(*val),
+        Value::Int { val, .. } => nu_json::Value::I64(*val),
+        Value::Float { val, .. } => nu_json::Value::F64(*val),
+        Value::String { val, .. } => nu_json::Value::String(val.clone()),
+        Value::List { vals, .. } => nu_json::Value::Array(vals.iter().map(value_to_json).collect()),
+        Value::Record { val, .. } => nu_json::Value::Object(
+            val.iter()
+                .map(|(k, v)| (k.clone(), value_to_json(v)))
+                .collect(),
+        ),
+        Value::Nothing { .. } => nu_json::Value::Null,
+        other => nu_json::Value::String(other.to_expanded_string(", ", &Config::default())),
+    }
+}
+
+fn write_csv(path: &Path, value: Value) -> Result<()> {
+    let (columns, rows) = collect_input(value)?;
+
+    let mut writer = csv::Writer::from_path(path)?;
+    if !columns.is_empty() {
+        writer.write_record(&columns)?;
+    }
+
+    let config = Config::default();
+    for row in rows {
+        let record: Vec<String> = row
+            .iter()
+            .map(|v| v.to_expanded_string(", ", &config))
+            .collect();
+        writer.write_record(&record)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
@@ -8,6 +8,7 @@ use nu_protocol::{
 };
 
 mod expand;
+mod export;
 mod help;
 mod nu;
 mod quit;
@@ -15,6 +16,7 @@ mod table;
 mod r#try;
 
 pub use expand::ExpandCmd;
+pub use export::ExportCmd;
 pub use help::HelpCmd;
 pub use nu::NuCmd;
 pub use quit::QuitCmd;
@@ -8,7 +8,7 @@ mod registry;
 mod views;
 
 use anyhow::Result;
-use commands::{ExpandCmd, HelpCmd, NuCmd, QuitCmd, TableCmd, TryCmd};
+use commands::{ExpandCmd, ExportCmd, HelpCmd, NuCmd, QuitCmd, TableCmd, TryCmd};
 use crossterm::terminal::size;
 pub use default_context::add_explore_context;
 pub use explore::Explore;
@@ -121,6 +121,7 @@ fn create_commands(registry: &mut CommandRegistry) {
     registry.register_command_view(HelpCmd::default(), false);
 
     registry.register_command_reactive(QuitCmd);
+    registry.register_command_reactive(ExportCmd::default());
 }
 
 fn create_aliases(registry: &mut CommandRegistry) {
@@ -128,4 +129,5 @@ fn create_aliases(registry: &mut CommandRegistry) {
     registry.create_aliases("e", ExpandCmd::NAME);
     registry.create_aliases("q", QuitCmd::NAME);
     registry.create_aliases("q!", QuitCmd::NAME);
+    registry.create_aliases("ex", ExportCmd::NAME);
 }
@@ -5,7 +5,7 @@ use lsp_types::{
     MarkupKind, Position, Range,
 };
 use nu_protocol::{
-    ast::{Argument, Block, Expr, Expression, Operator, Traverse},
+    ast::{Argument, Block, Call, Expr, Expression, Operator, Traverse},
     engine::StateWorkingSet,
     Type,
 };
@@ -82,6 +82,9 @@ fn extract_inlay_hints_from_expression(
         }
         Expr::Call(call) => {
             let decl = working_set.get_decl(call.decl_id);
+            if matches!(decl.name(), "def" | "export def") {
+                return extract_output_type_hint(call, working_set, offset, file);
+            }
             // skip those defined outside of the project
             let Some(block_id) = decl.block_id() else {
                 return vec![];
@@ -136,6 +139,54 @@ fn extract_inlay_hints_from_expression(
     }
 }
 
+/// For `def`/`export def` without an explicit `-> type` in their signature, hint the output type
+/// the parser inferred from the last pipeline of the command's body.
+fn extract_output_type_hint(
+    call: &Call,
+    working_set: &StateWorkingSet,
+    offset: &usize,
+    file: &FullTextDocument,
+) -> Vec<InlayHint> {
+    let Some(signature_expr) = call.positional_nth(1) else {
+        return vec![];
+    };
+    // skip if an output type is already written in the signature
+    if working_set
+        .get_span_contents(signature_expr.span)
+        .windows(2)
+        .any(|w| w == b"->")
+    {
+        return vec![];
+    }
+    let Some(block_expr) = call.positional_nth(2) else {
+        return vec![];
+    };
+    let block_id = match &block_expr.expr {
+        Expr::Closure(block_id) | Expr::Block(block_id) => *block_id,
+        _ => return vec![],
+    };
+    let block = working_set.get_block(block_id);
+    let Some(output_ty) = block
+        .pipelines
+        .last()
+        .and_then(|pipeline| pipeline.elements.last())
+        .map(|element| &element.expr.ty)
+    else {
+        return vec![];
+    };
+
+    vec![InlayHint {
+        kind: Some(InlayHintKind::TYPE),
+        label: InlayHintLabel::String(format!(" -> {}", type_short_name(output_ty))),
+        position: span_to_range(&signature_expr.span, file, *offset).end,
+        text_edits: None,
+        tooltip: None,
+        data: None,
+        padding_left: None,
+        padding_right: None,
+    }]
+}
+
 impl LanguageServer {
     pub(crate) fn get_inlay_hints(&mut self, params: &InlayHintParams) -> Option<Vec<InlayHint>> {
         self.inlay_hints.get(&params.text_document.uri).cloned()
@@ -313,6 +364,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inlay_hint_output_type() {
+        let (client_connection, _recv) = initialize_language_server(None, None);
+
+        let mut script = fixtures();
+        script.push("lsp");
+        script.push("hints");
+        script.push("output_type.nu");
+        let script = path_to_uri(&script);
+
+        open_unchecked(&client_connection, script.clone());
+        let resp = send_inlay_hint_request(&client_connection, script);
+
+        assert_json_eq!(
+            result_from_message(resp),
+            serde_json::json!([
+                { "position": { "line": 0, "character": 20 }, "label": " -> int", "kind": 1 },
+                { "position": { "line": 4, "character": 31 }, "label": " -> string", "kind": 1 }
+            ])
+        );
+    }
+
     #[test]
     /// https://github.com/nushell/nushell/pull/15071
     fn inlay_hint_for_nu_script_loaded_on_init() {
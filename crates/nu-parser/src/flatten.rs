@@ -18,10 +18,12 @@ pub enum FlatShape {
     Custom(DeclId),
     DateTime,
     Directory,
+    DirectoryNotFound,
     External,
     ExternalArg,
     ExternalResolved,
     Filepath,
+    FilepathNotFound,
     Flag,
     Float,
     Garbage,
@@ -58,10 +60,12 @@ impl FlatShape {
             FlatShape::Custom(_) => "shape_custom",
             FlatShape::DateTime => "shape_datetime",
             FlatShape::Directory => "shape_directory",
+            FlatShape::DirectoryNotFound => "shape_directory_not_found",
             FlatShape::External => "shape_external",
             FlatShape::ExternalArg => "shape_externalarg",
             FlatShape::ExternalResolved => "shape_external_resolved",
             FlatShape::Filepath => "shape_filepath",
+            FlatShape::FilepathNotFound => "shape_filepath_not_found",
             FlatShape::Flag => "shape_flag",
             FlatShape::Float => "shape_float",
             FlatShape::Garbage => "shape_garbage",
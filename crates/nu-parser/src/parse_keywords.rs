@@ -350,10 +350,17 @@ pub fn parse_for(working_set: &mut StateWorkingSet, lite_command: &LiteCommand)
                 shape: var_type.to_shape(),
                 var_id: Some(*var_id),
                 default_value: None,
+                default_value_expr: None,
             },
         );
     }
 
+    // The optional `--index` variable, if present, always holds the position of the
+    // current item as an int, regardless of what's being iterated over.
+    if let Some(index_var_id) = call.get_flag_expr("index").and_then(|expr| expr.as_var()) {
+        working_set.set_variable_type(index_var_id, Type::Int);
+    }
+
     Expression::new(working_set, Expr::Call(call), call_span, Type::Nothing)
 }
 
@@ -1066,6 +1073,60 @@ pub fn parse_alias(
     lite_command: &LiteCommand,
     module_name: Option<&[u8]>,
 ) -> Pipeline {
+    let mut attributes = vec![];
+    let mut attribute_vals = vec![];
+
+    for attr_cmd in lite_command.attribute_commands() {
+        let (attr, name) = parse_attribute(working_set, &attr_cmd);
+        if let Some(name) = name {
+            let val = eval_constant(working_set, &attr.expr);
+            match val {
+                Ok(val) => attribute_vals.push((name, val)),
+                Err(e) => working_set.error(e.wrap(working_set, attr.expr.span)),
+            }
+        }
+        attributes.push(attr);
+    }
+
+    let pipeline = parse_alias_inner(working_set, attribute_vals, lite_command, module_name);
+
+    if attributes.is_empty() {
+        return pipeline;
+    }
+
+    let Some(element) = pipeline.elements.into_iter().next() else {
+        return Pipeline::from_vec(vec![]);
+    };
+    let expr = element.expr;
+    let ty = expr.ty.clone();
+
+    let attr_block_span = Span::merge_many(
+        attributes
+            .first()
+            .map(|x| x.expr.span)
+            .into_iter()
+            .chain(Some(expr.span)),
+    );
+
+    Pipeline::from_vec(vec![Expression::new(
+        working_set,
+        Expr::AttributeBlock(AttributeBlock {
+            attributes,
+            item: Box::new(expr),
+        }),
+        attr_block_span,
+        ty,
+    )])
+}
+
+fn parse_alias_inner(
+    working_set: &mut StateWorkingSet,
+    attribute_vals: Vec<(String, Value)>,
+    lite_command: &LiteCommand,
+    module_name: Option<&[u8]>,
+) -> Pipeline {
+    let (_, examples, _, _) = handle_special_attributes(attribute_vals, working_set);
+
     let spans = &lite_command.parts;
 
     let (name_span, split_id) =
@@ -1278,6 +1339,7 @@ pub fn parse_alias(
                 wrapped_call,
                 description,
                 extra_description,
+                examples,
             };
 
             working_set.add_decl(Box::new(decl));
@@ -1398,12 +1460,12 @@ pub fn parse_export_in_block(
     }
 
     match full_name {
-        // `parse_def` and `parse_extern` work both with and without attributes
+        // `parse_def`, `parse_extern`, and `parse_alias` work both with and without attributes
         "export def" => parse_def(working_set, lite_command, None).0,
         "export extern" => parse_extern(working_set, lite_command, None),
+        "export alias" => parse_alias(working_set, lite_command, None),
         // Other definitions can't have attributes, so we handle attributes here with parse_attribute_block
         _ if lite_command.has_attributes() => parse_attribute_block(working_set, lite_command),
-        "export alias" => parse_alias(working_set, lite_command, None),
         "export const" => parse_const(working_set, &lite_command.parts[1..]).0,
         "export use" => parse_use(working_set, lite_command, None).0,
         "export module" => parse_module(working_set, lite_command, None).0,
@@ -2071,13 +2133,7 @@ pub fn parse_module_block(
 
                     block.pipelines.push(pipe)
                 }
-                // Other definitions can't have attributes, so we handle attributes here with parse_attribute_block
-                _ if command.has_attributes() => block
-                    .pipelines
-                    .push(parse_attribute_block(working_set, command)),
-                b"const" => block
-                    .pipelines
-                    .push(parse_const(working_set, &command.parts).0),
+                // `parse_alias` also works both with and without attributes
                 b"alias" => {
                     block.pipelines.push(parse_alias(
                         working_set,
@@ -2085,6 +2141,13 @@ pub fn parse_module_block(
                         None, // using aliases named as the module locally is OK
                     ))
                 }
+                // Other definitions can't have attributes, so we handle attributes here with parse_attribute_block
+                _ if command.has_attributes() => block
+                    .pipelines
+                    .push(parse_attribute_block(working_set, command)),
+                b"const" => block
+                    .pipelines
+                    .push(parse_const(working_set, &command.parts).0),
                 b"use" => {
                     let (pipeline, _) = parse_use(working_set, command, Some(&mut module));
 
@@ -2248,6 +2311,14 @@ pub fn parse_module_file_or_dir(
         return None;
     }
 
+    if is_url(&module_path_str) {
+        working_set.error(ParseError::ModuleUrlNotSupported(
+            path_span,
+            module_path_str,
+        ));
+        return None;
+    }
+
     #[allow(deprecated)]
     let cwd = working_set.get_cwd();
 
@@ -3795,6 +3866,11 @@ pub fn parse_source(working_set: &mut StateWorkingSet, lite_command: &LiteComman
                     }
                 };
 
+                if is_url(&filename) {
+                    working_set.error(ParseError::ModuleUrlNotSupported(spans[1], filename));
+                    return garbage_pipeline(working_set, spans);
+                }
+
                 if let Some(path) = find_in_dirs(&filename, working_set, &cwd, Some(LIB_DIRS_VAR)) {
                     if let Some(contents) = path.read(working_set) {
                         // Add the file to the stack of files being processed.
@@ -4057,6 +4133,15 @@ pub fn find_dirs_var(working_set: &StateWorkingSet, var_name: &str) -> Option<Va
         .filter(|var_id| working_set.get_variable(*var_id).const_val.is_some())
 }
 
+/// Whether `path` looks like a URL rather than a filesystem path.
+///
+/// Module and script paths are resolved at parse time, which runs synchronously and shouldn't
+/// perform network requests, so `use`/`source`/`module` reject URLs with a specific error instead
+/// of failing with a confusing "file not found".
+fn is_url(path: &str) -> bool {
+    path.starts_with("https://") || path.starts_with("http://")
+}
+
 /// This helper function is used to find files during parsing
 ///
 /// First, the actual current working directory is selected as
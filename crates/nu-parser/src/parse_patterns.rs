@@ -190,7 +190,33 @@ pub fn parse_record_pattern(working_set: &mut StateWorkingSet, span: Span) -> Ma
 
     while idx < tokens.len() {
         let bytes = working_set.get_span_contents(tokens[idx].span);
-        let (field, pattern) = if !bytes.is_empty() && bytes[0] == b'$' {
+        let (field, pattern) = if bytes == b".." {
+            // `..` captures the rest of the record's fields without binding them
+            let pattern = MatchPattern {
+                pattern: Pattern::IgnoreRest,
+                guard: None,
+                span: tokens[idx].span,
+            };
+
+            (String::new(), pattern)
+        } else if bytes.starts_with(b"..$") {
+            // `..$rest` captures the remaining, unmatched fields into a record
+            let pattern = if let Some(var_id) = parse_variable_pattern_helper(
+                working_set,
+                Span::new(tokens[idx].span.start + 2, tokens[idx].span.end),
+            ) {
+                MatchPattern {
+                    pattern: Pattern::Rest(var_id),
+                    guard: None,
+                    span: tokens[idx].span,
+                }
+            } else {
+                working_set.error(ParseError::Expected("valid variable name", tokens[idx].span));
+                garbage(tokens[idx].span)
+            };
+
+            (String::new(), pattern)
+        } else if !bytes.is_empty() && bytes[0] == b'$' {
             // If this is a variable, treat it as both the name of the field and the pattern
             let field = String::from_utf8_lossy(&bytes[1..]).to_string();
 
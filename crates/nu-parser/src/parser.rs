@@ -545,6 +545,10 @@ fn parse_long_flag(
         if let Ok(long_name) = long_name {
             let long_name = long_name[2..].to_string();
             if let Some(flag) = sig.get_long_flag(&long_name) {
+                // Normalize to the canonical long name, in case `long_name` is actually
+                // one of the flag's aliases, so downstream lookups (e.g. in `eval_call`)
+                // that compare against `flag.long` still succeed.
+                let long_name = flag.long.clone();
                 if let Some(arg_shape) = &flag.arg {
                     if split.len() > 1 {
                         // and we also have the argument
@@ -1065,8 +1069,11 @@ pub fn parse_internal_call(
                     arg: None,
                     required: false,
                     desc: "".to_string(),
+                    aliases: Vec::new(),
+                    multiple: false,
                     var_id: None,
                     default_value: None,
+                    default_value_expr: None,
                 })
             }
 
@@ -3598,6 +3605,7 @@ pub fn parse_row_condition(working_set: &mut StateWorkingSet, spans: &[Span]) ->
                 shape: SyntaxShape::Any,
                 var_id: Some(var_id),
                 default_value: None,
+                default_value_expr: None,
             });
 
             compile_block(working_set, &mut block);
@@ -3745,15 +3753,23 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                 } else {
                     match parse_mode {
                         ParseMode::Arg | ParseMode::AfterCommaArg | ParseMode::AfterType => {
-                            // Long flag with optional short form following with no whitespace, e.g. --output, --age(-a)
+                            // Long flag with optional short form and aliases following with no
+                            // whitespace, e.g. --output, --age(-a), --include...(-i, --inc)
                             if contents.starts_with(b"--") && contents.len() > 2 {
-                                // Split the long flag from the short flag with the ( character as delimiter.
-                                // The trailing ) is removed further down.
+                                // Split the long flag from the alternatives with the ( character as
+                                // delimiter. The trailing ) is removed further down.
                                 let flags: Vec<_> =
                                     contents.split(|x| x == &b'(').map(|x| x.to_vec()).collect();
 
-                                let long = String::from_utf8_lossy(&flags[0][2..]).to_string();
-                                let mut variable_name = flags[0][2..].to_vec();
+                                let mut long_bytes = flags[0][2..].to_vec();
+                                // A trailing `...` marks the flag as repeatable, collecting each
+                                // occurrence's argument into a list, e.g. --include...
+                                let multiple = long_bytes.ends_with(b"...");
+                                if multiple {
+                                    long_bytes.truncate(long_bytes.len() - 3);
+                                }
+                                let long = String::from_utf8_lossy(&long_bytes).to_string();
+                                let mut variable_name = long_bytes.clone();
                                 // Replace the '-' in a variable name with '_'
                                 (0..variable_name.len()).for_each(|idx| {
                                     if variable_name[idx] == b'-' {
@@ -3771,7 +3787,7 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                 let var_id =
                                     working_set.add_variable(variable_name, span, Type::Any, false);
 
-                                // If there's no short flag, exit now. Otherwise, parse it.
+                                // If there are no alternatives, exit now. Otherwise, parse them.
                                 if flags.len() == 1 {
                                     args.push(Arg::Flag {
                                         flag: Flag {
@@ -3780,75 +3796,84 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                             long,
                                             short: None,
                                             required: false,
+                                            aliases: Vec::new(),
+                                            multiple,
                                             var_id: Some(var_id),
                                             default_value: None,
+                                            default_value_expr: None,
                                         },
                                         type_annotated: false,
                                     });
                                 } else if flags.len() >= 3 {
                                     working_set.error(ParseError::Expected(
-                                        "only one short flag alternative",
+                                        "only one parenthesized group of alternatives",
                                         span,
                                     ));
                                 } else {
-                                    let short_flag = &flags[1];
-                                    let short_flag = if !short_flag.starts_with(b"-")
-                                        || !short_flag.ends_with(b")")
-                                    {
+                                    let alternatives = &flags[1];
+                                    let alternatives = if !alternatives.ends_with(b")") {
                                         working_set.error(ParseError::Expected(
-                                            "short flag alternative for the long flag",
+                                            "short flag or alias alternatives for the long flag",
                                             span,
                                         ));
-                                        short_flag
+                                        alternatives
                                     } else {
-                                        // Obtain the flag's name by removing the starting - and trailing )
-                                        &short_flag[1..(short_flag.len() - 1)]
+                                        // Remove the trailing )
+                                        &alternatives[..(alternatives.len() - 1)]
                                     };
-                                    // Note that it is currently possible to make a short flag with non-alphanumeric characters,
-                                    // like -).
-
-                                    let short_flag =
-                                        String::from_utf8_lossy(short_flag).to_string();
-                                    let chars: Vec<char> = short_flag.chars().collect();
-                                    let long = String::from_utf8_lossy(&flags[0][2..]).to_string();
-                                    let mut variable_name = flags[0][2..].to_vec();
-
-                                    (0..variable_name.len()).for_each(|idx| {
-                                        if variable_name[idx] == b'-' {
-                                            variable_name[idx] = b'_';
+
+                                    let mut short = None;
+                                    let mut aliases = Vec::new();
+
+                                    for alternative in alternatives.split(|x| *x == b',') {
+                                        let alternative =
+                                            String::from_utf8_lossy(alternative).trim().to_string();
+                                        if alternative.is_empty() {
+                                            continue;
                                         }
-                                    });
 
-                                    if !is_variable(&variable_name) {
-                                        working_set.error(ParseError::Expected(
-                                            "valid variable name for this short flag",
-                                            span,
-                                        ))
+                                        if let Some(alias) = alternative.strip_prefix("--") {
+                                            aliases.push(alias.to_string());
+                                        } else if let Some(short_flag) =
+                                            alternative.strip_prefix('-')
+                                        {
+                                            // Note that it is currently possible to make a short
+                                            // flag with non-alphanumeric characters, like -).
+                                            let chars: Vec<char> = short_flag.chars().collect();
+                                            if chars.len() == 1 && short.is_none() {
+                                                short = Some(chars[0]);
+                                            } else if short.is_some() {
+                                                working_set.error(ParseError::Expected(
+                                                    "only one short flag alternative",
+                                                    span,
+                                                ));
+                                            } else {
+                                                working_set
+                                                    .error(ParseError::Expected("short flag", span));
+                                            }
+                                        } else {
+                                            working_set.error(ParseError::Expected(
+                                                "short flag (-x) or long alias (--name)",
+                                                span,
+                                            ));
+                                        }
                                     }
 
-                                    let var_id = working_set.add_variable(
-                                        variable_name,
-                                        span,
-                                        Type::Any,
-                                        false,
-                                    );
-
-                                    if chars.len() == 1 {
-                                        args.push(Arg::Flag {
-                                            flag: Flag {
-                                                arg: None,
-                                                desc: String::new(),
-                                                long,
-                                                short: Some(chars[0]),
-                                                required: false,
-                                                var_id: Some(var_id),
-                                                default_value: None,
-                                            },
-                                            type_annotated: false,
-                                        });
-                                    } else {
-                                        working_set.error(ParseError::Expected("short flag", span));
-                                    }
+                                    args.push(Arg::Flag {
+                                        flag: Flag {
+                                            arg: None,
+                                            desc: String::new(),
+                                            long,
+                                            short,
+                                            required: false,
+                                            aliases,
+                                            multiple,
+                                            var_id: Some(var_id),
+                                            default_value: None,
+                                            default_value_expr: None,
+                                        },
+                                        type_annotated: false,
+                                    });
                                 }
                                 parse_mode = ParseMode::Arg;
                             }
@@ -3883,8 +3908,11 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                         long: String::new(),
                                         short: Some(chars[0]),
                                         required: false,
+                                        aliases: Vec::new(),
+                                        multiple: false,
                                         var_id: Some(var_id),
                                         default_value: None,
+                                        default_value_expr: None,
                                     },
                                     type_annotated: false,
                                 });
@@ -3952,6 +3980,7 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                         shape: SyntaxShape::Any,
                                         var_id: Some(var_id),
                                         default_value: None,
+                                        default_value_expr: None,
                                     },
                                     required: false,
                                     type_annotated: false,
@@ -3979,6 +4008,7 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                     shape: SyntaxShape::Any,
                                     var_id: Some(var_id),
                                     default_value: None,
+                                    default_value_expr: None,
                                 }));
                                 parse_mode = ParseMode::Arg;
                             }
@@ -4005,6 +4035,7 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                         shape: SyntaxShape::Any,
                                         var_id: Some(var_id),
                                         default_value: None,
+                                        default_value_expr: None,
                                     },
                                     required: true,
                                     type_annotated: false,
@@ -4038,10 +4069,21 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                         *shape = syntax_shape;
                                     }
                                     Arg::Flag {
-                                        flag: Flag { arg, var_id, .. },
+                                        flag:
+                                            Flag {
+                                                arg,
+                                                var_id,
+                                                multiple,
+                                                ..
+                                            },
                                         type_annotated,
                                     } => {
-                                        working_set.set_variable_type(var_id.expect("internal error: all custom parameters must have var_ids"), syntax_shape.to_type());
+                                        let var_type = if *multiple {
+                                            Type::List(Box::new(syntax_shape.to_type()))
+                                        } else {
+                                            syntax_shape.to_type()
+                                        };
+                                        working_set.set_variable_type(var_id.expect("internal error: all custom parameters must have var_ids"), var_type);
                                         if syntax_shape == SyntaxShape::Boolean {
                                             working_set.error(ParseError::LabeledError(
                                                 "Type annotations are not allowed for boolean switches.".to_string(),
@@ -4068,6 +4110,7 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                                 shape,
                                                 var_id,
                                                 default_value,
+                                                default_value_expr,
                                                 ..
                                             },
                                         required,
@@ -4099,15 +4142,18 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                             }
                                         }
 
-                                        *default_value = if let Ok(constant) =
+                                        if let Ok(constant) =
                                             eval_constant(working_set, &expression)
                                         {
-                                            Some(constant)
+                                            *default_value = Some(constant);
+                                            *default_value_expr = None;
                                         } else {
-                                            working_set.error(ParseError::NonConstantDefaultValue(
-                                                expression.span,
-                                            ));
-                                            None
+                                            // Not a compile-time constant (e.g. `def f [x = (date
+                                            // now)]`): keep the expression around to be evaluated
+                                            // fresh in the caller's scope on every call where the
+                                            // parameter is omitted.
+                                            *default_value = None;
+                                            *default_value_expr = Some(Box::new(expression.clone()));
                                         };
 
                                         if !*type_annotated {
@@ -4128,21 +4174,21 @@ pub fn parse_signature_helper(working_set: &mut StateWorkingSet, span: Span) ->
                                                 arg,
                                                 var_id,
                                                 default_value,
+                                                default_value_expr,
                                                 ..
                                             },
                                         type_annotated,
                                     } => {
                                         let expression_span = expression.span;
 
-                                        *default_value = if let Ok(value) =
-                                            eval_constant(working_set, &expression)
-                                        {
-                                            Some(value)
+                                        if let Ok(value) = eval_constant(working_set, &expression) {
+                                            *default_value = Some(value);
+                                            *default_value_expr = None;
                                         } else {
-                                            working_set.error(ParseError::NonConstantDefaultValue(
-                                                expression_span,
-                                            ));
-                                            None
+                                            // Not a compile-time constant: evaluate fresh in the
+                                            // caller's scope each time the flag is omitted.
+                                            *default_value = None;
+                                            *default_value_expr = Some(Box::new(expression.clone()));
                                         };
 
                                         let var_id = var_id.expect("internal error: all custom parameters must have var_ids");
@@ -5853,11 +5899,12 @@ pub fn parse_builtin_commands(
         .unwrap_or(b"");
 
     match name {
-        // `parse_def` and `parse_extern` work both with and without attributes
+        // `parse_def`, `parse_extern`, and `parse_alias` work both with and without attributes
         b"def" => parse_def(working_set, lite_command, None).0,
         b"extern" => parse_extern(working_set, lite_command, None),
         // `parse_export_in_block` also handles attributes by itself
         b"export" => parse_export_in_block(working_set, lite_command),
+        b"alias" => parse_alias(working_set, lite_command, None),
         // Other definitions can't have attributes, so we handle attributes here with parse_attribute_block
         _ if lite_command.has_attributes() => parse_attribute_block(working_set, lite_command),
         b"let" => parse_let(
@@ -5877,7 +5924,6 @@ pub fn parse_builtin_commands(
             let expr = parse_for(working_set, lite_command);
             Pipeline::from_vec(vec![expr])
         }
-        b"alias" => parse_alias(working_set, lite_command, None),
         b"module" => parse_module(working_set, lite_command, None).0,
         b"use" => parse_use(working_set, lite_command, None).0,
         b"overlay" => {
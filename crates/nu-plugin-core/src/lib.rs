@@ -17,7 +17,8 @@ pub use interface::{
     Interface, InterfaceManager, PipelineDataWriter, PluginRead, PluginWrite,
 };
 pub use serializers::{
-    json::JsonSerializer, msgpack::MsgPackSerializer, Encoder, EncodingType, PluginEncoder,
+    json::JsonSerializer, msgpack::MsgPackSerializer, msgpackz::MsgPackZSerializer, Encoder,
+    EncodingType, PluginEncoder,
 };
 
 #[doc(hidden)]
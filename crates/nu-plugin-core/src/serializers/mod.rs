@@ -3,6 +3,7 @@ use nu_protocol::ShellError;
 
 pub mod json;
 pub mod msgpack;
+pub mod msgpackz;
 
 #[cfg(test)]
 mod tests;
@@ -36,15 +37,17 @@ pub trait PluginEncoder: Encoder<PluginInput> + Encoder<PluginOutput> {
 pub enum EncodingType {
     Json(json::JsonSerializer),
     MsgPack(msgpack::MsgPackSerializer),
+    MsgPackZ(msgpackz::MsgPackZSerializer),
 }
 
 impl EncodingType {
-    /// Determine the plugin encoding type from the provided byte string (either `b"json"` or
-    /// `b"msgpack"`).
+    /// Determine the plugin encoding type from the provided byte string (`b"json"`,
+    /// `b"msgpack"`, or `b"msgpackz"`).
     pub fn try_from_bytes(bytes: &[u8]) -> Option<Self> {
         match bytes {
             b"json" => Some(Self::Json(json::JsonSerializer {})),
             b"msgpack" => Some(Self::MsgPack(msgpack::MsgPackSerializer {})),
+            b"msgpackz" => Some(Self::MsgPackZ(msgpackz::MsgPackZSerializer {})),
             _ => None,
         }
     }
@@ -54,11 +57,13 @@ impl<T> Encoder<T> for EncodingType
 where
     json::JsonSerializer: Encoder<T>,
     msgpack::MsgPackSerializer: Encoder<T>,
+    msgpackz::MsgPackZSerializer: Encoder<T>,
 {
     fn encode(&self, data: &T, writer: &mut impl std::io::Write) -> Result<(), ShellError> {
         match self {
             EncodingType::Json(encoder) => encoder.encode(data, writer),
             EncodingType::MsgPack(encoder) => encoder.encode(data, writer),
+            EncodingType::MsgPackZ(encoder) => encoder.encode(data, writer),
         }
     }
 
@@ -66,6 +71,7 @@ where
         match self {
             EncodingType::Json(encoder) => encoder.decode(reader),
             EncodingType::MsgPack(encoder) => encoder.decode(reader),
+            EncodingType::MsgPackZ(encoder) => encoder.decode(reader),
         }
     }
 }
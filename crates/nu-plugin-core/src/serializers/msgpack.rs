@@ -60,7 +60,7 @@ impl Encoder<PluginOutput> for MsgPackSerializer {
 }
 
 /// Handle a msgpack encode error
-fn rmp_encode_err(err: rmp_serde::encode::Error) -> ShellError {
+pub(super) fn rmp_encode_err(err: rmp_serde::encode::Error) -> ShellError {
     match err {
         rmp_serde::encode::Error::InvalidValueWrite(_) => {
             // I/O error
@@ -81,7 +81,7 @@ fn rmp_encode_err(err: rmp_serde::encode::Error) -> ShellError {
 }
 
 /// Handle a msgpack decode error. Returns `Ok(None)` on eof
-fn rmp_decode_err<T>(err: rmp_serde::decode::Error) -> Result<Option<T>, ShellError> {
+pub(super) fn rmp_decode_err<T>(err: rmp_serde::decode::Error) -> Result<Option<T>, ShellError> {
     match err {
         rmp_serde::decode::Error::InvalidMarkerRead(err)
         | rmp_serde::decode::Error::InvalidDataRead(err) => {
@@ -0,0 +1,167 @@
+use std::io::{Cursor, ErrorKind, Read, Write};
+
+use nu_plugin_protocol::{PluginInput, PluginOutput};
+use nu_protocol::{location, shell_error::io::IoError, ShellError};
+use serde::{Deserialize, Serialize};
+
+use super::msgpack::rmp_encode_err;
+use crate::{Encoder, PluginEncoder};
+
+const BUFFER_SIZE: usize = 65536;
+// Lower than the default (11) quality used for `to msgpackz`: this runs on every message rather
+// than once per file, so speed matters more than ratio here.
+const QUALITY: u32 = 3;
+const WINDOW_SIZE: u32 = 20;
+
+/// A `PluginEncoder` that communicates with Nushell using brotli-compressed MessagePack.
+///
+/// Each message is compressed independently as its own complete brotli stream, length-prefixed so
+/// that messages can still be read one at a time from the shared connection. There's no
+/// dictionary or window shared between messages, which costs some ratio on small ones, but keeps
+/// this a drop-in replacement for `msgpack` that doesn't need any connection-wide state.
+///
+/// This trades CPU time for wire size, which is worth it for plugins that move large values (e.g.
+/// big dataframes converted with `polars into-nu`) and are stalled on how much there is to send
+/// down a pipe, but likely isn't worth it for plugins that mostly exchange small values -- for
+/// those, the compression overhead outweighs the savings and plain `msgpack` is a better fit.
+#[derive(Clone, Copy, Debug)]
+pub struct MsgPackZSerializer;
+
+impl PluginEncoder for MsgPackZSerializer {
+    fn name(&self) -> &str {
+        "msgpackz"
+    }
+}
+
+impl Encoder<PluginInput> for MsgPackZSerializer {
+    fn encode(
+        &self,
+        plugin_input: &PluginInput,
+        writer: &mut impl std::io::Write,
+    ) -> Result<(), ShellError> {
+        encode_compressed(plugin_input, writer)
+    }
+
+    fn decode(
+        &self,
+        reader: &mut impl std::io::BufRead,
+    ) -> Result<Option<PluginInput>, ShellError> {
+        decode_compressed(reader)
+    }
+}
+
+impl Encoder<PluginOutput> for MsgPackZSerializer {
+    fn encode(
+        &self,
+        plugin_output: &PluginOutput,
+        writer: &mut impl std::io::Write,
+    ) -> Result<(), ShellError> {
+        encode_compressed(plugin_output, writer)
+    }
+
+    fn decode(
+        &self,
+        reader: &mut impl std::io::BufRead,
+    ) -> Result<Option<PluginOutput>, ShellError> {
+        decode_compressed(reader)
+    }
+}
+
+fn encode_compressed<T: Serialize>(
+    value: &T,
+    writer: &mut impl std::io::Write,
+) -> Result<(), ShellError> {
+    let mut compressed = vec![];
+    {
+        let mut compressor =
+            brotli::CompressorWriter::new(&mut compressed, BUFFER_SIZE, QUALITY, WINDOW_SIZE);
+        rmp_serde::encode::write_named(&mut compressor, value).map_err(rmp_encode_err)?;
+        compressor.flush().map_err(|err| {
+            ShellError::Io(IoError::new_internal(
+                err.kind(),
+                "Could not flush brotli compressor",
+                location!(),
+            ))
+        })?;
+    }
+
+    let len = u32::try_from(compressed.len()).map_err(|_| ShellError::PluginFailedToEncode {
+        msg: format!(
+            "message is too large to send compressed: {} bytes",
+            compressed.len()
+        ),
+    })?;
+
+    writer.write_all(&len.to_le_bytes()).map_err(|err| {
+        ShellError::Io(IoError::new_internal(
+            err.kind(),
+            "Could not write compressed message length",
+            location!(),
+        ))
+    })?;
+    writer.write_all(&compressed).map_err(|err| {
+        ShellError::Io(IoError::new_internal(
+            err.kind(),
+            "Could not write compressed message",
+            location!(),
+        ))
+    })
+}
+
+fn decode_compressed<T>(reader: &mut impl std::io::BufRead) -> Result<Option<T>, ShellError>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    let mut len_buf = [0u8; 4];
+    match reader.read_exact(&mut len_buf) {
+        Ok(()) => (),
+        Err(err) if err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => {
+            return Err(ShellError::Io(IoError::new_internal(
+                err.kind(),
+                "Could not read compressed message length",
+                location!(),
+            )))
+        }
+    }
+    let len = u32::from_le_bytes(len_buf) as u64;
+
+    // Read up to `len` bytes, rather than allocating `len` bytes up front: `len` came off the
+    // wire and hasn't been validated against anything, so trusting it directly as an allocation
+    // size would let a corrupt or malicious length field force an arbitrarily large allocation.
+    let mut compressed = Vec::new();
+    let read = reader
+        .take(len)
+        .read_to_end(&mut compressed)
+        .map_err(|err| {
+            ShellError::Io(IoError::new_internal(
+                err.kind(),
+                "Could not read compressed message body",
+                location!(),
+            ))
+        })?;
+    if read as u64 != len {
+        return Err(ShellError::PluginFailedToDecode {
+            msg: "message ended before the compressed body was fully read".into(),
+        });
+    }
+
+    let mut decompressed = vec![];
+    brotli::Decompressor::new(Cursor::new(compressed), BUFFER_SIZE)
+        .read_to_end(&mut decompressed)
+        .map_err(|err| ShellError::PluginFailedToDecode {
+            msg: format!("failed to decompress message: {err}"),
+        })?;
+
+    rmp_serde::from_slice(&decompressed)
+        .map(Some)
+        .map_err(|err| ShellError::PluginFailedToDecode {
+            msg: err.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    crate::serializers::tests::generate_tests!(MsgPackZSerializer {});
+}
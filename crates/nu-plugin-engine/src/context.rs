@@ -30,6 +30,8 @@ pub trait PluginExecutionContext: Send + Sync {
     fn get_env_vars(&self) -> Result<HashMap<String, Value>, ShellError>;
     /// Get current working directory
     fn get_current_dir(&self) -> Result<Spanned<String>, ShellError>;
+    /// Get the plugin's managed cache directory, creating it if it doesn't already exist
+    fn get_plugin_cache_dir(&self) -> Result<Spanned<String>, ShellError>;
     /// Set an environment variable
     fn add_env_var(&mut self, name: String, value: Value) -> Result<(), ShellError>;
     /// Get help for the current command
@@ -143,6 +145,34 @@ impl PluginExecutionContext for PluginExecutionCommandContext<'_> {
         Ok(cwd.into_spanned(self.call.head))
     }
 
+    fn get_plugin_cache_dir(&self) -> Result<Spanned<String>, ShellError> {
+        let mut dir = nu_path::cache_dir()
+            .ok_or_else(|| ShellError::GenericError {
+                error: "Could not get cache directory".into(),
+                msg: "failed to determine the platform cache directory".into(),
+                span: Some(self.call.head),
+                help: None,
+                inner: vec![],
+            })?
+            .into_std_path_buf();
+        dir.push("nushell");
+        dir.push("plugins");
+        dir.push(self.identity.name());
+
+        std::fs::create_dir_all(&dir).map_err(|err| {
+            ShellError::Io(nu_protocol::shell_error::io::IoError::new(
+                err.kind(),
+                self.call.head,
+                dir.clone(),
+            ))
+        })?;
+
+        Ok(dir
+            .to_string_lossy()
+            .into_owned()
+            .into_spanned(self.call.head))
+    }
+
     fn add_env_var(&mut self, name: String, value: Value) -> Result<(), ShellError> {
         self.stack.add_env_var(name, value);
         Ok(())
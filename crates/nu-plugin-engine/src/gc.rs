@@ -124,9 +124,8 @@ impl PluginGcState {
         }
     }
 
-    // returns `Some()` if the GC should not continue to operate, with `true` if it should stop the
-    // plugin, or `false` if it should not
-    fn handle_message(&mut self, msg: PluginGcMsg) -> Option<bool> {
+    // returns `Some()` if the GC should not continue to operate
+    fn handle_message(&mut self, msg: PluginGcMsg) -> Option<GcExit> {
         match msg {
             PluginGcMsg::SetConfig(config) => {
                 self.config = config;
@@ -154,18 +153,19 @@ impl PluginGcState {
             }
             PluginGcMsg::StopTracking => {
                 // Immediately exit without stopping the plugin
-                return Some(false);
+                return Some(GcExit::StopTracking);
             }
             PluginGcMsg::Exited => {
-                // Exit and stop the plugin
-                return Some(true);
+                // The plugin's process exited on its own (e.g. it crashed) - exit and tell the
+                // plugin so it can apply its restart_on_crash policy
+                return Some(GcExit::Crashed);
             }
         }
         None
     }
 
     fn run(&mut self, receiver: mpsc::Receiver<PluginGcMsg>) {
-        let mut always_stop = false;
+        let mut crashed = false;
 
         loop {
             let Some(msg) = (match self.next_timeout(Instant::now()) {
@@ -178,40 +178,57 @@ impl PluginGcState {
 
             log::trace!("Plugin GC ({name}) message: {msg:?}", name = self.name);
 
-            if let Some(should_stop) = self.handle_message(msg) {
-                // Exit the GC
-                if should_stop {
-                    // If should_stop = true, attempt to stop the plugin
-                    always_stop = true;
-                    break;
-                } else {
-                    // Don't stop the plugin
+            match self.handle_message(msg) {
+                Some(GcExit::StopTracking) => {
+                    // Don't stop the plugin, just stop the GC
                     return;
                 }
+                Some(GcExit::Crashed) => {
+                    crashed = true;
+                    break;
+                }
+                None => (),
             }
         }
 
-        // Upon exiting the loop, if the timeout reached zero, or we are exiting due to an Exited
-        // message, stop the plugin
-        if always_stop
-            || self
-                .next_timeout(Instant::now())
-                .is_some_and(|t| t.is_zero())
+        // We only hold a weak reference, and it's not an error if we fail to upgrade it - that
+        // just means the plugin is definitely stopped anyway.
+        let Some(plugin) = self.plugin.upgrade() else {
+            return;
+        };
+        let name = &self.name;
+
+        if crashed {
+            // The process is already gone - stop tracking it, and disable restarting it
+            // automatically if configured to do so
+            if let Err(err) = plugin.crashed() {
+                log::warn!("Plugin `{name}` failed to be stopped by GC after crashing: {err}");
+            } else {
+                log::debug!("Plugin `{name}` stopped by GC after crashing");
+            }
+        } else if self
+            .next_timeout(Instant::now())
+            .is_some_and(|t| t.is_zero())
         {
-            // We only hold a weak reference, and it's not an error if we fail to upgrade it -
-            // that just means the plugin is definitely stopped anyway.
-            if let Some(plugin) = self.plugin.upgrade() {
-                let name = &self.name;
-                if let Err(err) = plugin.stop() {
-                    log::warn!("Plugin `{name}` failed to be stopped by GC: {err}");
-                } else {
-                    log::debug!("Plugin `{name}` successfully stopped by GC");
-                }
+            // Upon exiting the loop, if the timeout reached zero, stop the plugin for inactivity
+            if let Err(err) = plugin.stop() {
+                log::warn!("Plugin `{name}` failed to be stopped by GC: {err}");
+            } else {
+                log::debug!("Plugin `{name}` successfully stopped by GC");
             }
         }
     }
 }
 
+/// Reason the GC's main loop exited
+#[derive(Debug, Clone, Copy)]
+enum GcExit {
+    /// Stop tracking the plugin without stopping it
+    StopTracking,
+    /// The plugin's process exited unexpectedly
+    Crashed,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,8 +189,8 @@ pub fn make_plugin_interface_with_streams(
 
 /// Determine the plugin's encoding from a freshly opened stream.
 ///
-/// The plugin is expected to send a 1-byte length and either `json` or `msgpack`, so this reads
-/// that and determines the right length.
+/// The plugin is expected to send a 1-byte length and one of `json`, `msgpack`, or `msgpackz`, so
+/// this reads that and determines the right length.
 pub fn get_plugin_encoding(
     child_stdout: &mut impl std::io::Read,
 ) -> Result<EncodingType, ShellError> {
@@ -1293,6 +1293,13 @@ pub(crate) fn handle_engine_call(
                 current_dir.span,
             )))
         }
+        EngineCall::GetPluginCacheDir => {
+            let cache_dir = context.get_plugin_cache_dir()?;
+            Ok(EngineCallResponse::value(Value::string(
+                cache_dir.item,
+                cache_dir.span,
+            )))
+        }
         EngineCall::AddEnvVar(name, value) => {
             context.add_env_var(name, value)?;
             Ok(EngineCallResponse::empty())
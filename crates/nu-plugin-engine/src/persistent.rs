@@ -41,6 +41,9 @@ struct MutableState {
     gc_config: PluginGcConfig,
     /// RAII guard for this plugin's signal handler
     signal_guard: Option<HandlerGuard>,
+    /// Set after the plugin's process exits unexpectedly while `gc_config.restart_on_crash` is
+    /// `false`, to keep [`.get()`](PersistentPlugin::get) from spawning it again automatically.
+    crash_disabled: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -69,6 +72,7 @@ impl PersistentPlugin {
                 preferred_mode: None,
                 gc_config,
                 signal_guard: None,
+                crash_disabled: false,
             }),
         }
     }
@@ -91,6 +95,15 @@ impl PersistentPlugin {
         if let Some(ref running) = mutable.running {
             // It exists, so just clone the interface
             Ok(running.interface.clone())
+        } else if mutable.crash_disabled {
+            Err(ShellError::PluginFailedToLoad {
+                msg: format!(
+                    "plugin `{}` crashed and will not restart automatically because \
+                        restart_on_crash is disabled for it in $env.config.plugin_gc; run \
+                        `plugin use` or `plugin add` to bring it back",
+                    self.identity.name()
+                ),
+            })
         } else {
             // Try to spawn. On success, `mutable.running` should have been set to the new running
             // plugin by `spawn()` so we just then need to clone the interface from there.
@@ -247,6 +260,34 @@ impl PersistentPlugin {
         // If this is a reset, we should also reset other learned attributes like preferred_mode
         if reset {
             mutable.preferred_mode = None;
+            mutable.crash_disabled = false;
+        }
+        Ok(())
+    }
+
+    /// Called by the plugin's garbage collector when the plugin's process exits unexpectedly
+    /// (rather than being stopped deliberately). Stops the plugin, and if `restart_on_crash` is
+    /// disabled in its GC config, keeps [`.get()`](Self::get) from spawning it again until it's
+    /// explicitly brought back with `plugin use` or `plugin add`.
+    pub(crate) fn crashed(&self) -> Result<(), ShellError> {
+        let restart_on_crash = self
+            .mutable
+            .lock()
+            .map_err(|_| ShellError::NushellFailed {
+                msg: format!(
+                    "plugin `{}` mutex poisoned, probably panic during spawn",
+                    self.identity.name()
+                ),
+            })?
+            .gc_config
+            .restart_on_crash;
+
+        self.stop_internal(false)?;
+
+        if !restart_on_crash {
+            if let Ok(mut mutable) = self.mutable.lock() {
+                mutable.crash_disabled = true;
+            }
         }
         Ok(())
     }
@@ -115,7 +115,7 @@ impl PipelineDataHeader {
 }
 
 /// Additional information about list (value) streams
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct ListStreamInfo {
     pub id: StreamId,
     pub span: Span,
@@ -134,7 +134,7 @@ impl ListStreamInfo {
 }
 
 /// Additional information about byte streams
-#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
 pub struct ByteStreamInfo {
     pub id: StreamId,
     pub span: Span,
@@ -509,6 +509,8 @@ pub enum EngineCall<D> {
     GetEnvVars,
     /// Get current working directory
     GetCurrentDir,
+    /// Get the plugin's managed cache directory, creating it if it doesn't already exist
+    GetPluginCacheDir,
     /// Set an environment variable in the caller's scope
     AddEnvVar(String, Value),
     /// Get help for the current command
@@ -560,6 +562,7 @@ impl<D> EngineCall<D> {
             EngineCall::GetEnvVar(_) => "GetEnv",
             EngineCall::GetEnvVars => "GetEnvs",
             EngineCall::GetCurrentDir => "GetCurrentDir",
+            EngineCall::GetPluginCacheDir => "GetPluginCacheDir",
             EngineCall::AddEnvVar(..) => "AddEnvVar",
             EngineCall::GetHelp => "GetHelp",
             EngineCall::EnterForeground => "EnterForeground",
@@ -583,6 +586,7 @@ impl<D> EngineCall<D> {
             EngineCall::GetEnvVar(name) => EngineCall::GetEnvVar(name),
             EngineCall::GetEnvVars => EngineCall::GetEnvVars,
             EngineCall::GetCurrentDir => EngineCall::GetCurrentDir,
+            EngineCall::GetPluginCacheDir => EngineCall::GetPluginCacheDir,
             EngineCall::AddEnvVar(name, value) => EngineCall::AddEnvVar(name, value),
             EngineCall::GetHelp => EngineCall::GetHelp,
             EngineCall::EnterForeground => EngineCall::EnterForeground,
@@ -75,7 +75,7 @@ mod test_util;
 pub use plugin::{serve_plugin, EngineInterface, Plugin, PluginCommand, SimplePluginCommand};
 
 // Re-exports. Consider semver implications carefully.
-pub use nu_plugin_core::{JsonSerializer, MsgPackSerializer, PluginEncoder};
+pub use nu_plugin_core::{JsonSerializer, MsgPackSerializer, MsgPackZSerializer, PluginEncoder};
 pub use nu_plugin_protocol::EvaluatedCall;
 
 // Required by other internal crates.
@@ -637,6 +637,34 @@ impl EngineInterface {
         }
     }
 
+    /// Get the plugin's managed cache directory from the engine, creating it if it doesn't
+    /// already exist.
+    ///
+    /// This is a directory scoped to the plugin (by its registered name) under the platform
+    /// cache directory, meant for the plugin's own use - e.g. caching downloaded data or
+    /// authentication tokens - instead of each plugin having to invent its own dotfile location.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # use nu_protocol::{Value, ShellError};
+    /// # use nu_plugin::EngineInterface;
+    /// # fn example(engine: &EngineInterface) -> Result<String, ShellError> {
+    /// engine.cache_dir() // => "/home/user/.cache/nushell/plugins/my_plugin"
+    /// # }
+    /// ```
+    pub fn cache_dir(&self) -> Result<String, ShellError> {
+        match self.engine_call(EngineCall::GetPluginCacheDir)? {
+            // Always a string, and the span doesn't matter.
+            EngineCallResponse::PipelineData(PipelineData::Value(Value::String { val, .. }, _)) => {
+                Ok(val)
+            }
+            EngineCallResponse::Error(err) => Err(err),
+            _ => Err(ShellError::PluginFailedToDecode {
+                msg: "Received unexpected response for EngineCall::GetPluginCacheDir".into(),
+            }),
+        }
+    }
+
     /// Get all environment variables from the engine.
     ///
     /// Since this is quite a large map that has to be sent, prefer to use
@@ -1,7 +1,7 @@
 use crate::{
     ast::Expression,
     engine::{Call, Command, CommandType, EngineState, Stack},
-    PipelineData, ShellError, Signature,
+    CustomExample, Example, PipelineData, ShellError, Signature,
 };
 
 /// Command wrapper of an alias.
@@ -17,6 +17,8 @@ pub struct Alias {
     pub wrapped_call: Expression,
     pub description: String,
     pub extra_description: String,
+    /// Examples declared with `@example` attributes on the `alias` definition.
+    pub examples: Vec<CustomExample>,
 }
 
 impl Command for Alias {
@@ -40,6 +42,13 @@ impl Command for Alias {
         &self.extra_description
     }
 
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        self.examples
+            .iter()
+            .map(CustomExample::to_example)
+            .collect()
+    }
+
     fn run(
         &self,
         _engine_state: &EngineState,
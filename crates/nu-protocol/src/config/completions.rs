@@ -1,6 +1,7 @@
 use super::{config_update_string_enum, prelude::*};
 use crate as nu_protocol;
 use crate::engine::Closure;
+use std::collections::HashMap;
 
 #[derive(Clone, Copy, Debug, Default, IntoValue, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CompletionAlgorithm {
@@ -59,6 +60,8 @@ pub struct ExternalCompleterConfig {
     pub enable: bool,
     pub max_results: i64,
     pub completer: Option<Closure>,
+    /// Per-command overrides for `completer`, keyed by external command name.
+    pub completers: HashMap<String, Closure>,
 }
 
 impl Default for ExternalCompleterConfig {
@@ -67,6 +70,7 @@ impl Default for ExternalCompleterConfig {
             enable: true,
             max_results: 100,
             completer: None,
+            completers: HashMap::new(),
         }
     }
 }
@@ -91,6 +95,27 @@ impl UpdateFromValue for ExternalCompleterConfig {
                     Value::Closure { val, .. } => self.completer = Some(val.as_ref().clone()),
                     _ => errors.type_mismatch(path, Type::custom("closure or nothing"), val),
                 },
+                "completers" => match val {
+                    Value::Record { val: record, .. } => {
+                        self.completers = record
+                            .iter()
+                            .filter_map(|(name, val)| match val {
+                                Value::Closure { val, .. } => {
+                                    Some((name.clone(), val.as_ref().clone()))
+                                }
+                                _ => {
+                                    errors.type_mismatch(
+                                        &mut path.push(name),
+                                        Type::custom("closure"),
+                                        val,
+                                    );
+                                    None
+                                }
+                            })
+                            .collect();
+                    }
+                    _ => errors.type_mismatch(path, Type::record(), val),
+                },
                 "max_results" => self.max_results.update(val, path, errors),
                 "enable" => self.enable.update(val, path, errors),
                 _ => errors.unknown_option(path, val),
@@ -108,6 +133,10 @@ pub struct CompletionConfig {
     pub algorithm: CompletionAlgorithm,
     pub external: ExternalCompleterConfig,
     pub use_ls_colors: bool,
+    /// Populate file and directory suggestions with a preview (file contents or directory
+    /// listing) in their `description`, for menus that render descriptions (e.g. an `ide` menu
+    /// with `description_mode` set).
+    pub preview: bool,
 }
 
 impl Default for CompletionConfig {
@@ -120,6 +149,7 @@ impl Default for CompletionConfig {
             algorithm: CompletionAlgorithm::default(),
             external: ExternalCompleterConfig::default(),
             use_ls_colors: true,
+            preview: false,
         }
     }
 }
@@ -146,6 +176,7 @@ impl UpdateFromValue for CompletionConfig {
                 "case_sensitive" => self.case_sensitive.update(val, path, errors),
                 "external" => self.external.update(val, path, errors),
                 "use_ls_colors" => self.use_ls_colors.update(val, path, errors),
+                "preview" => self.preview.update(val, path, errors),
                 _ => errors.unknown_option(path, val),
             }
         }
@@ -84,4 +84,8 @@ impl<'a> ConfigErrors<'a> {
             })
         }
     }
+
+    pub fn into_vec(self) -> Vec<ConfigError> {
+        self.errors
+    }
 }
@@ -10,6 +10,7 @@ pub struct Hooks {
     pub env_change: HashMap<String, Vec<Value>>,
     pub display_output: Option<Value>,
     pub command_not_found: Option<Value>,
+    pub command_done: Vec<Value>,
 }
 
 impl Hooks {
@@ -23,6 +24,7 @@ impl Hooks {
                 Span::unknown(),
             )),
             command_not_found: None,
+            command_done: Vec::new(),
         }
     }
 }
@@ -99,6 +101,13 @@ impl UpdateFromValue for Hooks {
                         Some(val.clone())
                     }
                 }
+                "command_done" => {
+                    if let Ok(hooks) = val.as_list() {
+                        self.command_done = hooks.into()
+                    } else {
+                        errors.type_mismatch(path, Type::list(Type::Any), val);
+                    }
+                }
                 _ => errors.unknown_option(path, val),
             }
         }
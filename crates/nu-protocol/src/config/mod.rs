@@ -1,7 +1,7 @@
 //! Module containing the internal representation of user configuration
 
 use crate as nu_protocol;
-use crate::FromValue;
+use crate::{ConfigError, FromValue};
 use helper::*;
 use prelude::*;
 use std::collections::HashMap;
@@ -19,6 +19,7 @@ pub use hooks::Hooks;
 pub use ls::LsConfig;
 pub use output::ErrorStyle;
 pub use plugin_gc::{PluginGcConfig, PluginGcConfigs};
+pub use prompt::PromptConfig;
 pub use reedline::{CursorShapeConfig, EditBindings, NuCursorShape, ParsedKeybinding, ParsedMenu};
 pub use rm::RmConfig;
 pub use shell_integration::ShellIntegrationConfig;
@@ -37,6 +38,7 @@ mod ls;
 mod output;
 mod plugin_gc;
 mod prelude;
+mod prompt;
 mod reedline;
 mod rm;
 mod shell_integration;
@@ -60,6 +62,7 @@ pub struct Config {
     pub hooks: Hooks,
     pub rm: RmConfig,
     pub shell_integration: ShellIntegrationConfig,
+    pub prompt: PromptConfig,
     pub buffer_editor: Value,
     pub show_banner: Value,
     pub bracketed_paste: bool,
@@ -71,6 +74,10 @@ pub struct Config {
     pub display_errors: DisplayErrors,
     pub use_kitty_protocol: bool,
     pub highlight_resolved_externals: bool,
+    /// Whether the syntax highlighter should check filepaths and directories against the
+    /// filesystem and use `shape_filepath_not_found`/`shape_directory_not_found` for ones that
+    /// don't exist. Off by default since it touches the filesystem on every keystroke.
+    pub highlight_resolved_filepaths: bool,
     /// Configuration for plugins.
     ///
     /// Users can provide configuration for a plugin through this entry.  The entry name must
@@ -79,6 +86,12 @@ pub struct Config {
     pub plugins: HashMap<String, Value>,
     /// Configuration for plugin garbage collection.
     pub plugin_gc: PluginGcConfigs,
+    /// Maps an external command's name (e.g. "ip", "docker") to a closure that decodes its
+    /// output into structured data, so callers get a table back instead of raw text. The
+    /// closure receives the external command's stdout as a string and is responsible for
+    /// deciding how to parse it (e.g. `{|out| $out | from json}` for a command run with a
+    /// `--json`-like flag baked into an alias).
+    pub external_decoders: HashMap<String, Value>,
 }
 
 impl Default for Config {
@@ -113,6 +126,7 @@ impl Default for Config {
             edit_mode: EditBindings::default(),
 
             shell_integration: ShellIntegrationConfig::default(),
+            prompt: PromptConfig::default(),
 
             render_right_prompt_on_last_line: false,
 
@@ -127,9 +141,11 @@ impl Default for Config {
 
             use_kitty_protocol: false,
             highlight_resolved_externals: false,
+            highlight_resolved_filepaths: false,
 
             plugins: HashMap::new(),
             plugin_gc: PluginGcConfigs::default(),
+            external_decoders: HashMap::new(),
         }
     }
 }
@@ -163,6 +179,7 @@ impl UpdateFromValue for Config {
                 "use_ansi_coloring" => self.use_ansi_coloring.update(val, path, errors),
                 "edit_mode" => self.edit_mode.update(val, path, errors),
                 "shell_integration" => self.shell_integration.update(val, path, errors),
+                "prompt" => self.prompt.update(val, path, errors),
                 "buffer_editor" => match val {
                     Value::Nothing { .. } | Value::String { .. } => {
                         self.buffer_editor = val.clone();
@@ -188,8 +205,12 @@ impl UpdateFromValue for Config {
                 "highlight_resolved_externals" => {
                     self.highlight_resolved_externals.update(val, path, errors)
                 }
+                "highlight_resolved_filepaths" => {
+                    self.highlight_resolved_filepaths.update(val, path, errors)
+                }
                 "plugins" => self.plugins.update(val, path, errors),
                 "plugin_gc" => self.plugin_gc.update(val, path, errors),
+                "external_decoders" => self.external_decoders.update(val, path, errors),
                 "menus" => match Vec::from_value(val.clone()) {
                     Ok(menus) => self.menus = menus,
                     Err(err) => errors.error(err.into()),
@@ -230,4 +251,18 @@ impl Config {
 
         errors.into_shell_error()
     }
+
+    /// Validate `value` against the config schema without applying it anywhere, returning every
+    /// problem found (unknown keys, type mismatches, deprecated options, ...) rather than
+    /// stopping at the first one or collapsing them into a single [`ShellError`]. Used by
+    /// `config check` to report on a config record without having to assign it first.
+    pub fn check(value: &Value) -> Vec<ConfigError> {
+        let defaults = Config::default();
+        let mut errors = ConfigErrors::new(&defaults);
+        let mut path = ConfigPath::new();
+
+        defaults.clone().update(value, &mut path, &mut errors);
+
+        errors.into_vec()
+    }
 }
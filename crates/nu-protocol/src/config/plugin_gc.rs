@@ -49,6 +49,11 @@ pub struct PluginGcConfig {
     pub enabled: bool,
     /// When to stop the plugin if not in use for this long (in nanoseconds)
     pub stop_after: i64,
+    /// True if the plugin should be allowed to restart (by being spawned again on next use)
+    /// after its process exits unexpectedly. If false, a crashed plugin is left stopped until
+    /// `plugin use` or `plugin add` brings it back explicitly, rather than being respawned the
+    /// next time one of its commands is called.
+    pub restart_on_crash: bool,
 }
 
 impl Default for PluginGcConfig {
@@ -56,6 +61,7 @@ impl Default for PluginGcConfig {
         PluginGcConfig {
             enabled: true,
             stop_after: 10_000_000_000, // 10sec
+            restart_on_crash: true,
         }
     }
 }
@@ -65,6 +71,7 @@ impl IntoValue for PluginGcConfig {
         record! {
             "enabled" => self.enabled.into_value(span),
             "stop_after" => Value::duration(self.stop_after, span),
+            "restart_on_crash" => self.restart_on_crash.into_value(span),
         }
         .into_value(span)
     }
@@ -97,6 +104,7 @@ impl UpdateFromValue for PluginGcConfig {
                         errors.type_mismatch(path, Type::Duration, val);
                     }
                 }
+                "restart_on_crash" => self.restart_on_crash.update(val, path, errors),
                 _ => errors.unknown_option(path, val),
             }
         }
@@ -114,12 +122,14 @@ mod tests {
                 default: PluginGcConfig {
                     enabled: true,
                     stop_after: 30_000_000_000,
+                    restart_on_crash: true,
                 },
                 plugins: [(
                     "my_plugin".to_owned(),
                     PluginGcConfig {
                         enabled: false,
                         stop_after: 0,
+                        restart_on_crash: false,
                     },
                 )]
                 .into_iter()
@@ -129,11 +139,13 @@ mod tests {
                 "default" => Value::test_record(record! {
                     "enabled" => Value::test_bool(true),
                     "stop_after" => Value::test_duration(30_000_000_000),
+                    "restart_on_crash" => Value::test_bool(true),
                 }),
                 "plugins" => Value::test_record(record! {
                     "my_plugin" => Value::test_record(record! {
                         "enabled" => Value::test_bool(false),
                         "stop_after" => Value::test_duration(0),
+                        "restart_on_crash" => Value::test_bool(false),
                     }),
                 }),
             }),
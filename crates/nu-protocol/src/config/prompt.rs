@@ -0,0 +1,65 @@
+use super::prelude::*;
+use crate as nu_protocol;
+
+/// Configures how prompt segments (`PROMPT_COMMAND`, `PROMPT_COMMAND_RIGHT`, etc.) are computed.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptConfig {
+    /// How long a prompt segment's closure may run before it's cancelled and the segment falls
+    /// back to `async_placeholder` (in nanoseconds). `0` means no timeout.
+    pub async_timeout: i64,
+    /// Shown in place of a prompt segment while its closure is still running past
+    /// `async_timeout`; the segment updates in place once the closure completes.
+    pub async_placeholder: String,
+}
+
+impl Default for PromptConfig {
+    fn default() -> Self {
+        Self {
+            async_timeout: 0,
+            async_placeholder: String::new(),
+        }
+    }
+}
+
+impl IntoValue for PromptConfig {
+    fn into_value(self, span: Span) -> Value {
+        record! {
+            "async_timeout" => Value::duration(self.async_timeout, span),
+            "async_placeholder" => self.async_placeholder.into_value(span),
+        }
+        .into_value(span)
+    }
+}
+
+impl UpdateFromValue for PromptConfig {
+    fn update<'a>(
+        &mut self,
+        value: &'a Value,
+        path: &mut ConfigPath<'a>,
+        errors: &mut ConfigErrors,
+    ) {
+        let Value::Record { val: record, .. } = value else {
+            errors.type_mismatch(path, Type::record(), value);
+            return;
+        };
+
+        for (col, val) in record.iter() {
+            let path = &mut path.push(col);
+            match col.as_str() {
+                "async_timeout" => {
+                    if let Ok(duration) = val.as_duration() {
+                        if duration >= 0 {
+                            self.async_timeout = duration;
+                        } else {
+                            errors.invalid_value(path, "a non-negative duration", val);
+                        }
+                    } else {
+                        errors.type_mismatch(path, Type::Duration, val);
+                    }
+                }
+                "async_placeholder" => self.async_placeholder.update(val, path, errors),
+                _ => errors.unknown_option(path, val),
+            }
+        }
+    }
+}
@@ -81,6 +81,8 @@ pub struct EngineState {
     files: Vec<CachedFile>,
     pub(super) virtual_paths: Vec<(String, VirtualPath)>,
     vars: Vec<Variable>,
+    // Populated eagerly by the `add_*_command_context` calls during startup; there's no
+    // lazy/deferred registration path, so every built-in decl is built and boxed up front.
     decls: Arc<Vec<Box<dyn Command + 'static>>>,
     // The Vec is wrapped in Arc so that if we don't need to modify the list, we can just clone
     // the reference and not have to clone each individual Arc inside. These lists can be
@@ -94,6 +96,10 @@ pub struct EngineState {
     pub signal_handlers: Option<Handlers>,
     pub env_vars: Arc<EnvVars>,
     pub previous_env_vars: Arc<HashMap<String, Value>>,
+    /// A snapshot of the environment as it was inherited from the parent process at startup,
+    /// taken before any user config or script has had a chance to run. Used by `env diff` as the
+    /// default baseline when no explicit snapshot is given.
+    pub initial_env_vars: Arc<HashMap<String, Value>>,
     pub config: Arc<Config>,
     pub pipeline_externals_state: Arc<(AtomicU32, AtomicU32)>,
     pub repl_state: Arc<Mutex<ReplState>>,
@@ -107,7 +113,7 @@ pub struct EngineState {
     pub history_session_id: i64,
     // Path to the file Nushell is currently evaluating, or None if we're in an interactive session.
     pub file: Option<PathBuf>,
-    pub regex_cache: Arc<Mutex<LruCache<String, Regex>>>,
+    pub regex_cache: Arc<Mutex<LruCache<String, Arc<Regex>>>>,
     pub is_interactive: bool,
     pub is_login: bool,
     startup_time: i64,
@@ -172,6 +178,7 @@ impl EngineState {
                     .collect(),
             ),
             previous_env_vars: Arc::new(HashMap::new()),
+            initial_env_vars: Arc::new(HashMap::new()),
             config: Arc::new(Config::default()),
             pipeline_externals_state: Arc::new((AtomicU32::new(0), AtomicU32::new(0))),
             repl_state: Arc::new(Mutex::new(ReplState {
@@ -641,6 +648,33 @@ impl EngineState {
         None
     }
 
+    /// Find every declaration matching `name` across active overlays, in resolution order.
+    ///
+    /// The first entry is the one [`find_decl`](Self::find_decl) would return; later entries are
+    /// the ones it would be shadowing. Meant for introspection tools (e.g. `which -a`) that need
+    /// to explain the whole shadowing chain, not just the winner.
+    pub fn find_decls_with_name<'a>(
+        &'a self,
+        name: &[u8],
+        removed_overlays: &[Vec<u8>],
+    ) -> Vec<(DeclId, &'a [u8])> {
+        let mut visibility: Visibility = Visibility::new();
+        let mut result = vec![];
+
+        for overlay_id in self.active_overlay_ids(removed_overlays).rev() {
+            let overlay_frame = self.get_overlay(*overlay_id);
+            visibility.append(&overlay_frame.visibility);
+
+            if let Some(decl_id) = overlay_frame.get_decl(name) {
+                if visibility.is_decl_id_visible(&decl_id) {
+                    result.push((decl_id, self.get_overlay_name(*overlay_id)));
+                }
+            }
+        }
+
+        result
+    }
+
     /// Find the name of the declaration corresponding to `decl_id`.
     ///
     /// Searches within active overlays, and filtering out overlays in `removed_overlays`.
@@ -1065,6 +1099,25 @@ impl EngineState {
         }
     }
 
+    /// Get a compiled regex for `pattern` from the shared regex cache, compiling and inserting it
+    /// if it isn't already cached. Used by `where =~`, `parse`, `split`, and `find` so that a
+    /// closure or loop that keeps applying the same pattern doesn't pay to recompile it every
+    /// time the command runs.
+    pub fn cached_regex(&self, pattern: &str) -> Result<Arc<Regex>, fancy_regex::Error> {
+        match self.regex_cache.try_lock() {
+            Ok(mut cache) => {
+                if let Some(regex) = cache.get(pattern) {
+                    Ok(regex.clone())
+                } else {
+                    let regex = Arc::new(Regex::new(pattern)?);
+                    cache.put(pattern.to_string(), regex.clone());
+                    Ok(regex)
+                }
+            }
+            Err(_) => Regex::new(pattern).map(Arc::new),
+        }
+    }
+
     /// Add new span and return its ID
     pub fn add_span(&mut self, span: Span) -> SpanId {
         self.spans.push(span);
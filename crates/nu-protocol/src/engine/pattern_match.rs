@@ -1,6 +1,6 @@
 use crate::{
     ast::{Expr, MatchPattern, Pattern, RangeInclusion},
-    Span, Value, VarId,
+    Record, Span, Value, VarId,
 };
 
 pub trait Matcher {
@@ -22,7 +22,18 @@ impl Matcher for Pattern {
             Pattern::Rest(_) => false,    // so we return false here and handle them elsewhere
             Pattern::Record(field_patterns) => match value {
                 Value::Record { val, .. } => {
-                    'top: for field_pattern in field_patterns {
+                    let mut named_fields = Vec::with_capacity(field_patterns.len());
+                    let mut rest = None;
+
+                    for field_pattern in field_patterns {
+                        match &field_pattern.1.pattern {
+                            Pattern::IgnoreRest => {}
+                            Pattern::Rest(var_id) => rest = Some((*var_id, field_pattern.1.span)),
+                            _ => named_fields.push(field_pattern),
+                        }
+                    }
+
+                    'top: for field_pattern in &named_fields {
                         for (col, val) in &**val {
                             if col == &field_pattern.0 {
                                 // We have found the field
@@ -36,6 +47,16 @@ impl Matcher for Pattern {
                         }
                         return false;
                     }
+
+                    if let Some((var_id, span)) = rest {
+                        let leftover: Record = val
+                            .iter()
+                            .filter(|entry| !named_fields.iter().any(|fp| &fp.0 == entry.0))
+                            .map(|(col, val)| (col.clone(), val.clone()))
+                            .collect();
+                        matches.push((var_id, Value::record(leftover, span)));
+                    }
+
                     true
                 }
                 _ => false,
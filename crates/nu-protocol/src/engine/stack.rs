@@ -1,6 +1,6 @@
 use crate::{
     engine::{
-        ArgumentStack, EngineState, ErrorHandlerStack, Redirection, StackCallArgGuard,
+        ArgumentStack, Closure, EngineState, ErrorHandlerStack, Redirection, StackCallArgGuard,
         StackCollectValueGuard, StackIoGuard, StackOutDest, DEFAULT_OVERLAY_NAME,
     },
     Config, IntoValue, OutDest, ShellError, Span, Value, VarId, ENV_VARIABLE_ID, NU_VARIABLE_ID,
@@ -53,6 +53,9 @@ pub struct Stack {
     /// Locally updated config. Use [`.get_config()`](Self::get_config) to access correctly.
     pub config: Option<Arc<Config>>,
     pub(crate) out_dest: StackOutDest,
+    /// Closures registered with `defer` in the current call frame, run in reverse order once
+    /// the frame's block finishes executing (see [`Stack::push_deferred`]).
+    pub(crate) deferred: Vec<Closure>,
 }
 
 impl Default for Stack {
@@ -82,6 +85,7 @@ impl Stack {
             parent_deletions: vec![],
             config: None,
             out_dest: StackOutDest::new(),
+            deferred: Vec::new(),
         }
     }
 
@@ -103,6 +107,7 @@ impl Stack {
             config: parent.config.clone(),
             out_dest: parent.out_dest.clone(),
             parent_stack: Some(parent),
+            deferred: Vec::new(),
         }
     }
 
@@ -250,6 +255,19 @@ impl Stack {
         }
     }
 
+    /// Register a closure to run once the current call frame's block finishes executing,
+    /// regardless of whether it exits normally, by error, or via `break`/`continue`/`return`.
+    pub fn push_deferred(&mut self, closure: Closure) {
+        self.deferred.push(closure);
+    }
+
+    /// Take all closures registered with [`Stack::push_deferred`] on this call frame, clearing
+    /// the list. Callers should run these (typically in reverse registration order) once the
+    /// frame's block has finished executing.
+    pub fn take_deferred(&mut self) -> Vec<Closure> {
+        std::mem::take(&mut self.deferred)
+    }
+
     pub fn add_env_var(&mut self, var: String, value: Value) {
         if let Some(last_overlay) = self.active_overlays.last() {
             if let Some(env_hidden) = Arc::make_mut(&mut self.env_hidden).get_mut(last_overlay) {
@@ -319,6 +337,7 @@ impl Stack {
             parent_deletions: vec![],
             config: self.config.clone(),
             out_dest: self.out_dest.clone(),
+            deferred: Vec::new(),
         }
     }
 
@@ -352,6 +371,7 @@ impl Stack {
             parent_deletions: vec![],
             config: self.config.clone(),
             out_dest: self.out_dest.clone(),
+            deferred: Vec::new(),
         }
     }
 
@@ -54,3 +54,30 @@ pub enum ConfigError {
     #[diagnostic(transparent)]
     ShellError(#[from] ShellError),
 }
+
+impl ConfigError {
+    /// The dotted path into the config record this error concerns, if any (the wrapped
+    /// [`ShellError`] variant doesn't have one).
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            ConfigError::TypeMismatch { path, .. }
+            | ConfigError::InvalidValue { path, .. }
+            | ConfigError::UnknownOption { path, .. }
+            | ConfigError::MissingRequiredColumn { path, .. }
+            | ConfigError::Deprecated { path, .. } => Some(path),
+            ConfigError::ShellError(_) => None,
+        }
+    }
+
+    /// The span in the config value this error points to, if any.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            ConfigError::TypeMismatch { span, .. }
+            | ConfigError::InvalidValue { span, .. }
+            | ConfigError::UnknownOption { span, .. }
+            | ConfigError::MissingRequiredColumn { span, .. }
+            | ConfigError::Deprecated { span, .. } => Some(*span),
+            ConfigError::ShellError(_) => None,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use super::{shell_error::io::IoError, ShellError};
-use crate::Span;
+use crate::{Span, Value};
 use miette::Diagnostic;
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -9,7 +9,7 @@ use std::fmt;
 ///
 /// This generally covers most of the interface of [`miette::Diagnostic`], but with types that are
 /// well-defined for our protocol.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LabeledError {
     /// The main message for the error.
     pub msg: String,
@@ -26,6 +26,10 @@ pub struct LabeledError {
     /// Additional help for the error, usually a hint about what the user might try
     #[serde(default)]
     pub help: Option<String>,
+    /// Arbitrary structured data attached to the error, for tooling that wants to branch on more
+    /// than just `code` (e.g. a CI pipeline reading fields out of a failed step's error).
+    #[serde(default)]
+    pub metadata: Option<Value>,
     /// Errors that are related to or caused this error
     #[serde(default)]
     pub inner: Box<Vec<LabeledError>>,
@@ -51,6 +55,7 @@ impl LabeledError {
             code: None,
             url: None,
             help: None,
+            metadata: None,
             inner: Box::new(vec![]),
         }
     }
@@ -121,6 +126,22 @@ impl LabeledError {
         self
     }
 
+    /// Attach arbitrary structured data to the error, for tooling that wants to branch on more
+    /// than just `code`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use nu_protocol::{LabeledError, Value};
+    /// let error = LabeledError::new("An error")
+    ///     .with_metadata(Value::test_int(1));
+    /// assert_eq!(Some(&Value::test_int(1)), error.metadata.as_ref());
+    /// ```
+    pub fn with_metadata(mut self, metadata: impl Into<Value>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
+
     /// Add an error that is related to or caused this error.
     ///
     /// # Example
@@ -171,6 +192,7 @@ impl LabeledError {
             code: diag.code().map(|s| s.to_string()),
             url: diag.url().map(|s| s.to_string()),
             help: diag.help().map(|s| s.to_string()),
+            metadata: None,
             inner: diag
                 .related()
                 .into_iter()
@@ -249,6 +249,13 @@ pub enum ParseError {
     )]
     ModuleNotFound(#[label = "module {1} not found"] Span, String),
 
+    #[error("Loading modules from a URL is not supported.")]
+    #[diagnostic(
+        code(nu::parser::module_url_not_supported),
+        help("download the module to a local file first, then `use`/`source` that file; module resolution happens at parse time and can't perform network requests")
+    )]
+    ModuleUrlNotSupported(#[label = "can't load a module from {1}"] Span, String),
+
     #[error("Missing mod.nu file.")]
     #[diagnostic(
         code(nu::parser::module_missing_mod_nu_file),
@@ -579,6 +586,7 @@ impl ParseError {
             ParseError::AliasNotValid(s) => *s,
             ParseError::CommandDefNotValid(s) => *s,
             ParseError::ModuleNotFound(s, _) => *s,
+            ParseError::ModuleUrlNotSupported(s, _) => *s,
             ParseError::ModuleMissingModNuFile(_, s) => *s,
             ParseError::NamedAsModule(_, _, _, s) => *s,
             ParseError::ModuleDoubleMain(_, s) => *s,
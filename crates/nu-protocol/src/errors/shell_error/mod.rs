@@ -1067,6 +1067,9 @@ pub enum ShellError {
     Break {
         #[label("used outside of loop")]
         span: Span,
+        /// If set, this break is only meant to be caught by a loop with a matching label,
+        /// rather than the nearest enclosing one.
+        label: Option<String>,
     },
 
     /// Continue event, which may become an error if used outside of a loop
@@ -1074,6 +1077,9 @@ pub enum ShellError {
     Continue {
         #[label("used outside of loop")]
         span: Span,
+        /// If set, this continue is only meant to be caught by a loop with a matching label,
+        /// rather than the nearest enclosing one.
+        label: Option<String>,
     },
 
     /// Return event, which may become an error if used outside of a custom command or closure
@@ -1405,6 +1411,19 @@ impl ShellError {
 
     pub fn into_value(self, working_set: &StateWorkingSet, span: Span) -> Value {
         let exit_code = self.external_exit_code();
+        let code = Diagnostic::code(&self).map(|code| code.to_string());
+        let help = Diagnostic::help(&self).map(|help| help.to_string());
+        let (metadata, inner) = match &self {
+            ShellError::LabeledError(error) => (
+                error.metadata.clone(),
+                error
+                    .inner
+                    .iter()
+                    .map(|error| Value::string(error.to_string(), span))
+                    .collect(),
+            ),
+            _ => (None, Vec::new()),
+        };
 
         let mut record = record! {
             "msg" => Value::string(self.to_string(), span),
@@ -1414,6 +1433,22 @@ impl ShellError {
             "json" => Value::string(serde_json::to_string(&self).expect("Could not serialize error"), span),
         };
 
+        if let Some(code) = code {
+            record.push("code", Value::string(code, span));
+        }
+
+        if let Some(help) = help {
+            record.push("help", Value::string(help, span));
+        }
+
+        if let Some(metadata) = metadata {
+            record.push("metadata", metadata);
+        }
+
+        if !inner.is_empty() {
+            record.push("inner", Value::list(inner, span));
+        }
+
         if let Some(code) = exit_code {
             record.push("exit_code", Value::int(code.item.into(), code.span));
         }
@@ -393,10 +393,12 @@ impl fmt::Display for FmtPattern<'_> {
             Pattern::Record(bindings) => {
                 f.write_str("{")?;
                 for (name, pattern) in bindings {
+                    if !name.is_empty() {
+                        write!(f, "{name}: ")?;
+                    }
                     write!(
                         f,
-                        "{}: {}",
-                        name,
+                        "{}",
                         FmtPattern {
                             engine_state: self.engine_state,
                             pattern: &pattern.pattern,
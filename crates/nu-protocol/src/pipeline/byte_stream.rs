@@ -17,7 +17,7 @@ use std::os::windows::io::OwnedHandle;
 use std::{
     fmt::Debug,
     fs::File,
-    io::{self, BufRead, BufReader, Cursor, ErrorKind, Read, Write},
+    io::{self, BufRead, BufReader, Cursor, ErrorKind, Read, Seek, SeekFrom, Write},
     process::Stdio,
 };
 
@@ -243,20 +243,41 @@ impl ByteStream {
 
     pub fn skip(self, span: Span, n: u64) -> Result<Self, ShellError> {
         let known_size = self.known_size.map(|len| len.saturating_sub(n));
-        if let Some(mut reader) = self.reader() {
-            // Copy the number of skipped bytes into the sink before proceeding
-            io::copy(&mut (&mut reader).take(n), &mut io::sink())
-                .map_err(|err| IoError::new(err.kind(), span, None))?;
-            Ok(
-                ByteStream::read(reader, span, Signals::empty(), ByteStreamType::Binary)
-                    .with_known_size(known_size),
-            )
-        } else {
-            Err(ShellError::TypeMismatch {
-                err_message: "expected readable stream".into(),
-                span,
-            })
-        }
+
+        // A `File` supports seeking, so skip by moving the file's cursor instead of reading and
+        // discarding `n` bytes, which would otherwise mean copying the whole skipped range off
+        // disk for no reason.
+        let mut file = match self.stream {
+            ByteStreamSource::File(file) => file,
+            stream => {
+                let mut reader = ByteStream::new(stream, self.span, self.signals, self.type_)
+                    .reader()
+                    .ok_or_else(|| ShellError::TypeMismatch {
+                        err_message: "expected readable stream".into(),
+                        span,
+                    })?;
+                // Copy the number of skipped bytes into the sink before proceeding
+                io::copy(&mut (&mut reader).take(n), &mut io::sink())
+                    .map_err(|err| IoError::new(err.kind(), span, None))?;
+                return Ok(
+                    ByteStream::read(reader, span, Signals::empty(), ByteStreamType::Binary)
+                        .with_known_size(known_size),
+                );
+            }
+        };
+
+        let offset = n
+            .try_into()
+            .map_err(|_| IoError::new(ErrorKind::InvalidData, span, None))?;
+        file.seek(SeekFrom::Current(offset))
+            .map_err(|err| IoError::new(err.kind(), span, None))?;
+        Ok(ByteStream::new(
+            ByteStreamSource::File(file),
+            span,
+            Signals::empty(),
+            ByteStreamType::Binary,
+        )
+        .with_known_size(known_size))
     }
 
     pub fn take(self, span: Span, n: u64) -> Result<Self, ShellError> {
@@ -479,6 +500,14 @@ impl ByteStream {
         self.known_size
     }
 
+    /// Returns the [`Signals`] used to check for interrupts while reading this [`ByteStream`].
+    ///
+    /// This is a cancellation handle for long-running commands that consume a [`ByteStream`]
+    /// directly rather than through the interrupt-aware helpers like [`reader`](Self::reader).
+    pub fn signals(&self) -> &Signals {
+        &self.signals
+    }
+
     /// Convert the [`ByteStream`] into its [`Reader`] which allows one to [`Read`] the raw bytes of the stream.
     ///
     /// [`Reader`] is buffered and also implements [`BufRead`].
@@ -15,6 +15,7 @@ pub type ValueIterator = Box<dyn Iterator<Item = Value> + Send + 'static>;
 pub struct ListStream {
     stream: ValueIterator,
     span: Span,
+    signals: Signals,
     caller_spans: Vec<Span>,
 }
 
@@ -26,8 +27,9 @@ impl ListStream {
         signals: Signals,
     ) -> Self {
         Self {
-            stream: Box::new(InterruptIter::new(iter, signals)),
+            stream: Box::new(InterruptIter::new(iter, signals.clone())),
             span,
+            signals,
             caller_spans: vec![],
         }
     }
@@ -37,6 +39,14 @@ impl ListStream {
         self.span
     }
 
+    /// Returns the [`Signals`] used to check for interrupts while reading this [`ListStream`].
+    ///
+    /// This is a cancellation handle for long-running commands that consume a [`ListStream`]
+    /// directly rather than through its `Iterator` implementation.
+    pub fn signals(&self) -> &Signals {
+        &self.signals
+    }
+
     /// Push a caller [`Span`] to the bytestream, it's useful to construct a backtrace.
     pub fn push_caller_span(&mut self, span: Span) {
         if span != self.span {
@@ -108,6 +118,7 @@ impl ListStream {
         Self {
             stream: Box::new(f(self.stream)),
             span: self.span,
+            signals: self.signals,
             caller_spans: self.caller_spans,
         }
     }
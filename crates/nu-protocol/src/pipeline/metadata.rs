@@ -2,11 +2,22 @@ use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
 
+use crate::Record;
+
 /// Metadata that is valid for the whole [`PipelineData`](crate::PipelineData)
-#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+///
+/// Note: this does not derive `Eq` because [`Value`](crate::Value), which can appear inside
+/// `custom`, has no total equality (it contains floats).
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
 pub struct PipelineMetadata {
     pub data_source: DataSource,
     pub content_type: Option<String>,
+    /// Free-form metadata set by user pipelines, e.g. via `metadata set --custom`.
+    ///
+    /// Unlike `data_source` and `content_type`, this is never set by nushell itself; it exists
+    /// so that downstream commands (like `save`) can make decisions based on values that
+    /// upstream commands attached to the stream.
+    pub custom: Option<Record>,
 }
 
 impl PipelineMetadata {
@@ -23,6 +34,10 @@ impl PipelineMetadata {
             ..self
         }
     }
+
+    pub fn with_custom(self, custom: Option<Record>) -> Self {
+        Self { custom, ..self }
+    }
 }
 
 /// Describes where the particular [`PipelineMetadata`] originates.
@@ -34,6 +49,8 @@ pub enum DataSource {
     Ls,
     HtmlThemes,
     FilePath(PathBuf),
+    /// The URL the stream was fetched from, e.g. by `http get` or `open <url>`.
+    Url(String),
     #[default]
     None,
 }
@@ -86,6 +86,20 @@ impl PipelineData {
         }
     }
 
+    /// Returns a cancellation handle for interrupting a long-running command that consumes this
+    /// [`PipelineData`], if it carries one.
+    ///
+    /// Only [`ListStream`] and [`ByteStream`] are lazily produced and therefore have a
+    /// [`Signals`] to check; [`Empty`](PipelineData::Empty) and already-materialized
+    /// [`Value`](PipelineData::Value) have nothing left to interrupt.
+    pub fn signals(&self) -> Option<&Signals> {
+        match self {
+            PipelineData::Empty | PipelineData::Value(..) => None,
+            PipelineData::ListStream(stream, ..) => Some(stream.signals()),
+            PipelineData::ByteStream(stream, ..) => Some(stream.signals()),
+        }
+    }
+
     /// Change the span of the [`PipelineData`].
     ///
     /// Returns `Value(Nothing)` with the given span if it was [`PipelineData::Empty`].
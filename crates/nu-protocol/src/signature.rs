@@ -1,4 +1,5 @@
 use crate::{
+    ast::Expression,
     engine::{Call, Command, CommandType, EngineState, Stack},
     BlockId, Example, PipelineData, ShellError, SyntaxShape, Type, Value, VarId,
 };
@@ -19,10 +20,23 @@ pub struct Flag {
     pub arg: Option<SyntaxShape>,
     pub required: bool,
     pub desc: String,
+    /// Additional long-flag names that refer to this same flag (e.g. `--include` aliased as
+    /// `--inc`), for external CLI wrappers that need to mirror another tool's flag names.
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Whether this flag can be given more than once, collecting each occurrence's argument into
+    /// a list instead of only keeping the last one (e.g. `--include a --include b`).
+    #[serde(default)]
+    pub multiple: bool,
 
     // For custom commands
     pub var_id: Option<VarId>,
     pub default_value: Option<Value>,
+    /// A default value that isn't a constant, evaluated fresh in the caller's scope each time the
+    /// flag is omitted (e.g. `--at: datetime = (date now)`). Mutually exclusive with
+    /// `default_value`.
+    #[serde(default)]
+    pub default_value_expr: Option<Box<Expression>>,
 }
 
 /// The signature definition for a positional argument
@@ -35,6 +49,11 @@ pub struct PositionalArg {
     // For custom commands
     pub var_id: Option<VarId>,
     pub default_value: Option<Value>,
+    /// A default value that isn't a constant, evaluated fresh in the caller's scope each time the
+    /// parameter is omitted (e.g. `def f [x = (date now)]`). Mutually exclusive with
+    /// `default_value`.
+    #[serde(default)]
+    pub default_value_expr: Option<Box<Expression>>,
 }
 
 /// Command categories
@@ -252,8 +271,11 @@ impl Signature {
             arg: None,
             desc: "Display the help message for this command".into(),
             required: false,
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         };
         self.named.push(flag);
         self
@@ -318,6 +340,7 @@ impl Signature {
             shape: shape.into(),
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -336,6 +359,7 @@ impl Signature {
             shape: shape.into(),
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -353,6 +377,7 @@ impl Signature {
             shape: shape.into(),
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -390,8 +415,11 @@ impl Signature {
             arg: Some(shape.into()),
             required: false,
             desc: desc.into(),
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -413,8 +441,11 @@ impl Signature {
             arg: Some(shape.into()),
             required: true,
             desc: desc.into(),
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -435,8 +466,11 @@ impl Signature {
             arg: None,
             required: false,
             desc: desc.into(),
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         });
 
         self
@@ -567,10 +601,10 @@ impl Signature {
         total
     }
 
-    /// Find the matching long flag
+    /// Find the matching long flag, by its primary name or one of its aliases
     pub fn get_long_flag(&self, name: &str) -> Option<Flag> {
         for flag in &self.named {
-            if flag.long == name {
+            if flag.long == name || flag.aliases.iter().any(|alias| alias == name) {
                 return Some(flag.clone());
             }
         }
@@ -0,0 +1,198 @@
+use crate::{CustomValue, Record, ShellError, Span, Value};
+use serde::{de::Error as DeError, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A record whose columns are computed on demand.
+///
+/// Wrap a type implementing this trait in a [`LazyRecordValue`] to get a [`Value::Custom`] that
+/// behaves like an ordinary record for cell-path access (`$x.column`), computing only the
+/// column that was actually asked for. This is meant for info commands (`sys`, `ls`, ...) whose
+/// columns are individually cheap to name but some of which are expensive to compute (disk
+/// usage, process counts, ...) and that most callers never look at.
+///
+/// Anything that needs the whole record at once -- `to_base_value`, and everything downstream of
+/// it, like `columns`, `to json`, or table rendering -- still computes every column, since
+/// there's no way to print a table or list column names without knowing all of them.
+pub trait LazyRecord: fmt::Debug + Send + Sync {
+    /// The names of the columns this record would have, without computing their values.
+    fn column_names(&self) -> Vec<&str>;
+
+    /// Compute the value of a single column.
+    fn get_column_value(&self, column: &str) -> Result<Value, ShellError>;
+
+    fn span(&self) -> Span;
+
+    fn clone_box(&self) -> Box<dyn LazyRecord>;
+}
+
+#[derive(Debug)]
+pub struct LazyRecordValue {
+    inner: Box<dyn LazyRecord>,
+}
+
+impl LazyRecordValue {
+    pub fn new(inner: Box<dyn LazyRecord>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Clone for LazyRecordValue {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone_box(),
+        }
+    }
+}
+
+// Custom values are required to be `Serialize`/`Deserialize` (typetag on the `CustomValue`
+// trait), but a lazy record's whole point is to avoid computing columns eagerly, and there's no
+// way to round-trip a `Box<dyn LazyRecord>` through serde anyway. Serialize by forcing every
+// column, matching what `to_base_value` produces; deserialization isn't supported, the same as
+// `SQLiteDatabase`'s `typetag_deserialize`.
+impl Serialize for LazyRecordValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let columns = self.inner.column_names();
+        let mut map = serializer.serialize_map(Some(columns.len()))?;
+        for column in columns {
+            let value = self
+                .inner
+                .get_column_value(column)
+                .map_err(serde::ser::Error::custom)?;
+            map.serialize_entry(column, &value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for LazyRecordValue {
+    fn deserialize<D: Deserializer<'de>>(_deserializer: D) -> Result<Self, D::Error> {
+        Err(DeError::custom("LazyRecordValue does not support deserialization"))
+    }
+}
+
+impl CustomValue for LazyRecordValue {
+    fn clone_value(&self, span: Span) -> Value {
+        Value::custom(Box::new(self.clone()), span)
+    }
+
+    fn type_name(&self) -> String {
+        "lazy record".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let mut record = Record::new();
+        for column in self.inner.column_names() {
+            record.push(column, self.inner.get_column_value(column)?);
+        }
+        Ok(Value::record(record, span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn follow_path_string(
+        &self,
+        _self_span: Span,
+        column_name: String,
+        path_span: Span,
+    ) -> Result<Value, ShellError> {
+        self.inner
+            .get_column_value(&column_name)
+            .map_err(|_| ShellError::CantFindColumn {
+                col_name: column_name,
+                span: Some(path_span),
+                src_span: self.inner.span(),
+            })
+    }
+
+    fn typetag_name(&self) -> &'static str {
+        "LazyRecordValue"
+    }
+
+    fn typetag_deserialize(&self) {
+        unimplemented!("typetag_deserialize")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[derive(Debug)]
+    struct CountingRecord {
+        expensive_calls: std::rc::Rc<Cell<u32>>,
+    }
+
+    impl LazyRecord for CountingRecord {
+        fn column_names(&self) -> Vec<&str> {
+            vec!["cheap", "expensive"]
+        }
+
+        fn get_column_value(&self, column: &str) -> Result<Value, ShellError> {
+            match column {
+                "cheap" => Ok(Value::test_int(1)),
+                "expensive" => {
+                    self.expensive_calls.set(self.expensive_calls.get() + 1);
+                    Ok(Value::test_int(2))
+                }
+                _ => Err(ShellError::CantFindColumn {
+                    col_name: column.into(),
+                    span: None,
+                    src_span: Span::test_data(),
+                }),
+            }
+        }
+
+        fn span(&self) -> Span {
+            Span::test_data()
+        }
+
+        fn clone_box(&self) -> Box<dyn LazyRecord> {
+            Box::new(CountingRecord {
+                expensive_calls: self.expensive_calls.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn follow_path_string_only_computes_requested_column() {
+        let expensive_calls = std::rc::Rc::new(Cell::new(0));
+        let lazy = LazyRecordValue::new(Box::new(CountingRecord {
+            expensive_calls: expensive_calls.clone(),
+        }));
+
+        let value = lazy
+            .follow_path_string(Span::test_data(), "cheap".into(), Span::test_data())
+            .expect("cheap column should resolve");
+
+        assert_eq!(value, Value::test_int(1));
+        assert_eq!(expensive_calls.get(), 0);
+    }
+
+    #[test]
+    fn to_base_value_computes_every_column() {
+        let expensive_calls = std::rc::Rc::new(Cell::new(0));
+        let lazy = LazyRecordValue::new(Box::new(CountingRecord {
+            expensive_calls: expensive_calls.clone(),
+        }));
+
+        let record = lazy
+            .to_base_value(Span::test_data())
+            .expect("should compute the full record");
+
+        assert_eq!(
+            record,
+            Value::test_record(crate::record! {
+                "cheap" => Value::test_int(1),
+                "expensive" => Value::test_int(2),
+            })
+        );
+        assert_eq!(expensive_calls.get(), 1);
+    }
+}
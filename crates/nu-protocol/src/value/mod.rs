@@ -4,6 +4,7 @@ mod filesize;
 mod from_value;
 mod glob;
 mod into_value;
+mod lazy_record;
 mod range;
 #[cfg(test)]
 mod test_derive;
@@ -16,6 +17,7 @@ pub use filesize::*;
 pub use from_value::FromValue;
 pub use glob::*;
 pub use into_value::{IntoValue, TryIntoValue};
+pub use lazy_record::{LazyRecord, LazyRecordValue};
 pub use range::{FloatRange, IntRange, Range};
 pub use record::Record;
 
@@ -27,7 +29,6 @@ use crate::{
 };
 use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, Locale, TimeZone};
 use chrono_humanize::HumanTime;
-use fancy_regex::Regex;
 use nu_utils::{
     contains_emoji,
     locale::{get_system_locale_string, LOCALE_OVERRIDE_ENV_VAR},
@@ -3702,33 +3703,16 @@ impl Value {
         let rhs_span = rhs.span();
         match (self, rhs) {
             (Value::String { val: lhs, .. }, Value::String { val: rhs, .. }) => {
-                let is_match = match engine_state.regex_cache.try_lock() {
-                    Ok(mut cache) => {
-                        if let Some(regex) = cache.get(rhs) {
-                            regex.is_match(lhs)
-                        } else {
-                            let regex =
-                                Regex::new(rhs).map_err(|e| ShellError::UnsupportedInput {
-                                    msg: format!("{e}"),
-                                    input: "value originated from here".into(),
-                                    msg_span: span,
-                                    input_span: rhs_span,
-                                })?;
-                            let ret = regex.is_match(lhs);
-                            cache.put(rhs.clone(), regex);
-                            ret
-                        }
-                    }
-                    Err(_) => {
-                        let regex = Regex::new(rhs).map_err(|e| ShellError::UnsupportedInput {
+                let regex =
+                    engine_state
+                        .cached_regex(rhs)
+                        .map_err(|e| ShellError::UnsupportedInput {
                             msg: format!("{e}"),
                             input: "value originated from here".into(),
                             msg_span: span,
                             input_span: rhs_span,
                         })?;
-                        regex.is_match(lhs)
-                    }
-                };
+                let is_match = regex.is_match(lhs);
 
                 Ok(Value::bool(
                     if invert {
@@ -45,6 +45,7 @@ fn test_signature_chained() {
             shape: SyntaxShape::String,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         })
     );
     assert_eq!(
@@ -55,6 +56,7 @@ fn test_signature_chained() {
             shape: SyntaxShape::String,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         })
     );
     assert_eq!(
@@ -65,6 +67,7 @@ fn test_signature_chained() {
             shape: SyntaxShape::String,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         })
     );
 
@@ -76,8 +79,11 @@ fn test_signature_chained() {
             arg: Some(SyntaxShape::String),
             required: true,
             desc: "required named description".to_string(),
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         })
     );
 
@@ -89,8 +95,11 @@ fn test_signature_chained() {
             arg: Some(SyntaxShape::String),
             required: true,
             desc: "required named description".to_string(),
+            aliases: Vec::new(),
+            multiple: false,
             var_id: None,
             default_value: None,
+            default_value_expr: None,
         })
     );
 }
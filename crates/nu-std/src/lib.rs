@@ -108,6 +108,11 @@ pub fn load_standard_library(
             "std-rfc/conversions",
             include_str!("../std-rfc/conversions/mod.nu"),
         ),
+        (
+            "mod.nu",
+            "std-rfc/dotenv",
+            include_str!("../std-rfc/dotenv/mod.nu"),
+        ),
         #[cfg(feature = "sqlite")]
         ("mod.nu", "std-rfc/kv", include_str!("../std-rfc/kv/mod.nu")),
         (
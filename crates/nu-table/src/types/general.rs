@@ -9,6 +9,7 @@ use crate::{
         get_value_style, nu_value_to_string_colored, NuText, INDEX_COLUMN_NAME,
     },
     types::has_index,
+    util::limit_column_width,
     NuRecordsValue, NuTable, StringResult, TableOpts, TableOutput, TableResult,
 };
 
@@ -32,6 +33,10 @@ fn list_table(input: &[Value], opts: TableOpts<'_>) -> Result<Option<String>, Sh
 
     out.table.set_indent(opts.config.table.padding);
 
+    if let Some(max_col_width) = opts.max_col_width {
+        limit_column_width(out.table.get_records_mut(), max_col_width, opts.wrap_col_content);
+    }
+
     colorize_space(out.table.get_records_mut(), &opts.style_computer);
 
     configure_table(&mut out, opts.config, &opts.style_computer, opts.mode);
@@ -59,6 +64,10 @@ fn kv_table(record: &Record, opts: TableOpts<'_>) -> StringResult {
     table.set_index_style(TextStyle::default_field());
     table.set_indent(opts.config.table.padding);
 
+    if let Some(max_col_width) = opts.max_col_width {
+        limit_column_width(table.get_records_mut(), max_col_width, opts.wrap_col_content);
+    }
+
     let mut out = TableOutput::from_table(table, false, true);
     configure_table(&mut out, opts.config, &opts.style_computer, opts.mode);
     let table = out.table.draw(opts.width);
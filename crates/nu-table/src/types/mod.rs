@@ -48,6 +48,10 @@ pub struct TableOpts<'a> {
     pub mode: TableMode,
     pub index_offset: usize,
     pub index_remove: bool,
+    /// If set, no column's content is allowed to be wider than this many display columns.
+    pub max_col_width: Option<usize>,
+    /// When a cell exceeds `max_col_width`, word-wrap it onto more lines instead of truncating.
+    pub wrap_col_content: bool,
 }
 
 impl<'a> TableOpts<'a> {
@@ -73,8 +77,18 @@ impl<'a> TableOpts<'a> {
             mode,
             index_offset,
             index_remove,
+            max_col_width: None,
+            wrap_col_content: false,
         }
     }
+
+    /// Clamp every column's content to at most `width` display columns, truncating (or
+    /// word-wrapping, if `wrap` is set) any cell that exceeds it.
+    pub fn with_max_col_width(mut self, width: Option<usize>, wrap: bool) -> Self {
+        self.max_col_width = width;
+        self.wrap_col_content = wrap;
+        self
+    }
 }
 
 fn has_index(opts: &TableOpts<'_>, headers: &[String]) -> bool {
@@ -61,6 +61,27 @@ pub fn string_truncate(text: &str, width: usize) -> String {
     Truncate::truncate(line, width).into_owned()
 }
 
+/// Clamp every cell in `data` to at most `max_width` display columns, either by truncating with
+/// an ellipsis or by word-wrapping onto additional lines within the same cell.
+pub fn limit_column_width(data: &mut [Vec<Text<String>>], max_width: usize, wrap: bool) {
+    for row in data.iter_mut() {
+        for cell in row.iter_mut() {
+            let text = cell.as_ref();
+            if string_width(text) <= max_width {
+                continue;
+            }
+
+            let limited = if wrap {
+                string_wrap(text, max_width, true)
+            } else {
+                string_truncate(text, max_width)
+            };
+
+            *cell = Text::new(limited);
+        }
+    }
+}
+
 pub fn clean_charset(text: &str) -> String {
     // TODO: We could make an optimization to take a String and modify it
     //       We could check if there was any changes and if not make no allocations at all and don't change the origin.
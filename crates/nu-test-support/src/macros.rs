@@ -238,6 +238,7 @@ use nu_path::{AbsolutePath, AbsolutePathBuf, Path, PathBuf};
 use nu_utils::escape_quote_string;
 use std::{
     ffi::OsStr,
+    io::Write,
     process::{Command, Stdio},
 };
 use tempfile::tempdir;
@@ -252,6 +253,9 @@ pub struct NuOpts {
     // passing in file contents seems like a better API - consider this when adding new uses of
     // this field.
     pub env_config: Option<PathBuf>,
+    /// Content to write to the spawned `nu` process's stdin, e.g. to test `open -` or other
+    /// commands that read piped input directly rather than through the pipeline.
+    pub stdin: Option<String>,
 }
 
 pub fn nu_run_test(opts: NuOpts, commands: impl AsRef<str>, with_std: bool) -> Outcome {
@@ -298,16 +302,30 @@ pub fn nu_run_test(opts: NuOpts, commands: impl AsRef<str>, with_std: bool) -> O
     command
         .arg(format!("-c {}", escape_quote_string(&commands)))
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
+        .stderr(Stdio::piped())
+        .stdin(if opts.stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        });
 
     // Uncomment to debug the command being run:
     // println!("=== command\n{command:?}\n");
 
-    let process = match command.spawn() {
+    let mut process = match command.spawn() {
         Ok(child) => child,
         Err(why) => panic!("Can't run test {:?} {}", crate::fs::executable_path(), why),
     };
 
+    if let Some(stdin) = opts.stdin {
+        process
+            .stdin
+            .take()
+            .expect("stdin was piped")
+            .write_all(stdin.as_bytes())
+            .expect("couldn't write to stdin");
+    }
+
     let output = process
         .wait_with_output()
         .expect("couldn't read from stdout/stderr");
@@ -7,6 +7,7 @@ pub mod flatten_json;
 pub mod locale;
 mod quoting;
 mod shared_cow;
+pub mod startup_profile;
 pub mod utils;
 
 pub use locale::get_system_locale;
@@ -0,0 +1,34 @@
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+/// Holds the entries recorded by [`record`] once profiling has been turned on with [`enable`].
+///
+/// Left uninitialized (and therefore untouched by `record`) unless something actually asks for
+/// startup profiling, so the `perf!` macro stays free of any locking overhead in the common case.
+static ENTRIES: OnceLock<Mutex<Vec<(String, Duration)>>> = OnceLock::new();
+
+/// Turns on collection of the timings reported through the `perf!` macro.
+///
+/// Meant to be called once, near the very start of the program, before parsing the
+/// `--profile-startup` flag has a chance to matter for any of the phases it should cover.
+pub fn enable() {
+    ENTRIES.get_or_init(|| Mutex::new(Vec::new()));
+}
+
+/// Records one `perf!` timing if [`enable`] has been called; otherwise a no-op.
+pub fn record(label: &str, duration: Duration) {
+    if let Some(entries) = ENTRIES.get() {
+        entries
+            .lock()
+            .expect("startup profile mutex poisoned")
+            .push((label.to_string(), duration));
+    }
+}
+
+/// Returns every timing recorded so far, in the order it was recorded, without clearing it.
+pub fn entries() -> Vec<(String, Duration)> {
+    ENTRIES
+        .get()
+        .map(|entries| entries.lock().expect("startup profile mutex poisoned").clone())
+        .unwrap_or_default()
+}
@@ -453,6 +453,7 @@ pub fn get_ls_colors(lscolors_env_string: Option<String>) -> LsColors {
 #[macro_export]
 macro_rules! perf {
     ($msg:expr, $dur:expr, $use_color:expr) => {
+        let elapsed = $dur.elapsed();
         if $use_color {
             log::info!(
                 "perf: {}:{}:{} \x1b[32m{}\x1b[0m took \x1b[33m{:?}\x1b[0m",
@@ -460,7 +461,7 @@ macro_rules! perf {
                 line!(),
                 column!(),
                 $msg,
-                $dur.elapsed(),
+                elapsed,
             );
         } else {
             log::info!(
@@ -469,9 +470,10 @@ macro_rules! perf {
                 line!(),
                 column!(),
                 $msg,
-                $dur.elapsed(),
+                elapsed,
             );
         }
+        $crate::startup_profile::record($msg, elapsed);
     };
 }
 
@@ -1,3 +1,4 @@
+use chrono::Duration as ChronoDuration;
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
     Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
@@ -24,17 +25,35 @@ impl PluginCommand for CacheRemove {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .rest("keys", SyntaxShape::String, "Keys of objects to remove")
+            .switch(
+                "all",
+                "Remove all stored objects, instead of specific keys",
+                None,
+            )
+            .named(
+                "older-than",
+                SyntaxShape::Duration,
+                "Only remove objects created more than this long ago",
+                None,
+            )
             .input_output_type(Type::Any, Type::List(Box::new(Type::String)))
             .category(Category::Custom("dataframe".into()))
     }
 
     fn examples(&'_ self) -> Vec<Example<'_>> {
-        vec![Example {
-            description: "Removes a stored ",
-            example: r#"let df = ([[a b];[1 2] [3 4]] | polars into-df);
+        vec![
+            Example {
+                description: "Removes a stored ",
+                example: r#"let df = ([[a b];[1 2] [3 4]] | polars into-df);
     polars store-ls | get key | first | polars store-rm $in"#,
-            result: None,
-        }]
+                result: None,
+            },
+            Example {
+                description: "Removes everything from the cache that hasn't been touched in the last 10 minutes",
+                example: "polars store-rm --all --older-than 10min",
+                result: None,
+            },
+        ]
     }
 
     fn run(
@@ -44,11 +63,44 @@ impl PluginCommand for CacheRemove {
         call: &EvaluatedCall,
         _input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
-        let msgs: Vec<Value> = call
-            .rest::<String>(0)?
-            .into_iter()
-            .map(|ref key| remove_cache_entry(plugin, engine, key, call.head))
-            .collect::<Result<Vec<Value>, ShellError>>()?;
+        let keys = call.rest::<String>(0)?;
+        let all = call.has_flag("all")?;
+        let older_than = call.get_flag::<i64>("older-than")?;
+
+        if !keys.is_empty() && (all || older_than.is_some()) {
+            return Err(ShellError::GenericError {
+                error: "Conflicting arguments".into(),
+                msg: "cannot combine explicit keys with --all or --older-than".into(),
+                span: Some(call.head),
+                help: Some("remove either specific keys, or use --all/--older-than alone".into()),
+                inner: vec![],
+            }
+            .into());
+        }
+
+        let msgs: Vec<Value> = if all || older_than.is_some() {
+            let cutoff =
+                older_than.map(|ns| chrono::Local::now() - ChronoDuration::nanoseconds(ns));
+            let matching_keys: Vec<Uuid> = plugin
+                .cache
+                .process_entries(|(key, value)| {
+                    Ok(cutoff
+                        .is_none_or(|cutoff| value.created < cutoff)
+                        .then_some(*key))
+                })?
+                .into_iter()
+                .flatten()
+                .collect();
+
+            matching_keys
+                .iter()
+                .map(|key| remove_cache_entry_by_uuid(plugin, engine, key, call.head))
+                .collect::<Result<Vec<Value>, ShellError>>()?
+        } else {
+            keys.iter()
+                .map(|key| remove_cache_entry(plugin, engine, key, call.head))
+                .collect::<Result<Vec<Value>, ShellError>>()?
+        };
 
         Ok(PipelineData::Value(Value::list(msgs, call.head), None))
     }
@@ -61,9 +113,18 @@ fn remove_cache_entry(
     span: Span,
 ) -> Result<Value, ShellError> {
     let key = as_uuid(key, span)?;
+    remove_cache_entry_by_uuid(plugin, engine, &key, span)
+}
+
+fn remove_cache_entry_by_uuid(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    key: &Uuid,
+    span: Span,
+) -> Result<Value, ShellError> {
     let msg = plugin
         .cache
-        .remove(engine, &key, true)?
+        .remove(engine, key, true)?
         .map(|_| format!("Removed: {key}"))
         .unwrap_or_else(|| format!("No value found for key: {key}"));
     Ok(Value::string(msg, span))
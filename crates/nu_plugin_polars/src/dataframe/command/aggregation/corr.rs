@@ -0,0 +1,126 @@
+use crate::dataframe::values::NuExpression;
+use crate::values::{
+    cant_convert_err, CustomValueSupport, NuDataFrame, PolarsPluginObject, PolarsPluginType,
+};
+use crate::PolarsPlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, ShellError, Signature, SyntaxShape, Type, Value,
+};
+use polars::prelude::pearson_corr;
+
+use super::stat_matrix::pairwise_matrix;
+
+pub struct ExprCorr;
+
+impl PluginCommand for ExprCorr {
+    type Plugin = PolarsPlugin;
+
+    fn name(&self) -> &str {
+        "polars corr"
+    }
+
+    fn description(&self) -> &str {
+        "Creates a Pearson correlation expression between two columns, or a pairwise correlation matrix over all numeric columns of a dataframe."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .optional(
+                "other",
+                SyntaxShape::Any,
+                "the other column expression to correlate against",
+            )
+            .input_output_types(vec![
+                (
+                    Type::Custom("expression".into()),
+                    Type::Custom("expression".into()),
+                ),
+                (
+                    Type::Custom("dataframe".into()),
+                    Type::Custom("dataframe".into()),
+                ),
+            ])
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![
+            Example {
+                description: "Correlation matrix over the numeric columns of a dataframe",
+                example: "[[a b]; [1 2] [2 4] [3 6]] | polars into-df | polars corr",
+                result: None,
+            },
+            Example {
+                description: "Correlation expression between two columns for use inside an aggregation",
+                example: "[[a b]; [1 2] [2 4] [3 6]] | polars into-df | polars select (polars corr (polars col a) (polars col b))",
+                result: None,
+            },
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let value = input.into_value(call.head)?;
+        match PolarsPluginObject::try_from_value(plugin, &value)? {
+            PolarsPluginObject::NuDataFrame(df) => command_eager(plugin, engine, call, df),
+            PolarsPluginObject::NuLazyFrame(lazy) => {
+                command_eager(plugin, engine, call, lazy.collect(call.head)?)
+            }
+            PolarsPluginObject::NuExpression(expr) => command_expr(plugin, engine, call, expr),
+            _ => Err(cant_convert_err(
+                &value,
+                &[
+                    PolarsPluginType::NuDataFrame,
+                    PolarsPluginType::NuLazyFrame,
+                    PolarsPluginType::NuExpression,
+                ],
+            )),
+        }
+        .map_err(LabeledError::from)
+    }
+}
+
+fn command_expr(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    expr: NuExpression,
+) -> Result<PipelineData, ShellError> {
+    let other_value: Option<Value> = call.opt(0)?;
+    let other_value = other_value.ok_or_else(|| ShellError::MissingParameter {
+        param_name: "other".into(),
+        span: call.head,
+    })?;
+    let other = NuExpression::try_from_value(plugin, &other_value)?;
+
+    NuExpression::from(pearson_corr(expr.into_polars(), other.into_polars(), 1))
+        .to_pipeline_data(plugin, engine, call.head)
+}
+
+fn command_eager(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    df: NuDataFrame,
+) -> Result<PipelineData, ShellError> {
+    let matrix = pairwise_matrix(df.as_ref(), 1, call.head, pearson_corr)?;
+    let res = NuDataFrame::new(false, matrix);
+    res.to_pipeline_data(plugin, engine, call.head)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::test_polars_plugin_command;
+
+    #[test]
+    fn test_examples() -> Result<(), ShellError> {
+        test_polars_plugin_command(&ExprCorr)
+    }
+}
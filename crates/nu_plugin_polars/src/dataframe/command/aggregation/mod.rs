@@ -1,6 +1,8 @@
 mod agg_groups;
 mod aggregate;
+mod corr;
 mod count;
+mod cov;
 mod cumulative;
 pub mod groupby;
 mod implode;
@@ -13,6 +15,7 @@ mod n_unique;
 mod over;
 mod quantile;
 mod rolling;
+mod stat_matrix;
 mod std;
 mod sum;
 mod value_counts;
@@ -23,7 +26,9 @@ use agg_groups::ExprAggGroups;
 use nu_plugin::PluginCommand;
 
 pub use aggregate::LazyAggregate;
+pub use corr::ExprCorr;
 use count::ExprCount;
+pub use cov::ExprCov;
 pub use cumulative::Cumulative;
 use implode::ExprImplode;
 use max::ExprMax;
@@ -42,7 +47,9 @@ pub(crate) fn aggregation_commands() -> Vec<Box<dyn PluginCommand<Plugin = Polar
     vec![
         Box::new(Cumulative),
         Box::new(ExprAggGroups),
+        Box::new(ExprCorr),
         Box::new(ExprCount),
+        Box::new(ExprCov),
         Box::new(ExprImplode),
         Box::new(ExprMax),
         Box::new(ExprMin),
@@ -0,0 +1,75 @@
+use nu_protocol::{ShellError, Span};
+use polars::prelude::{col, DataFrame, DataType, Expr, IntoLazy, Series};
+
+/// Builds a pairwise matrix dataframe over all numeric columns of `df` using `stat`
+/// (e.g. `pearson_corr` or `cov`), matching the shape of a `column` label column followed
+/// by one column per numeric column, similar to a typical dataframe library's `corr`/`cov`.
+pub fn pairwise_matrix(
+    df: &DataFrame,
+    ddof: u8,
+    span: Span,
+    stat: fn(Expr, Expr, u8) -> Expr,
+) -> Result<DataFrame, ShellError> {
+    let numeric_cols: Vec<String> = df
+        .get_columns()
+        .iter()
+        .filter(|c| c.dtype().is_numeric())
+        .map(|c| c.name().to_string())
+        .collect();
+
+    if numeric_cols.is_empty() {
+        return Err(ShellError::GenericError {
+            error: "No numeric columns".into(),
+            msg: "expected at least one numeric column to compute a pairwise matrix".into(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        });
+    }
+
+    let cell_name = |this: &str, other: &str| format!("{this}__{other}");
+
+    let exprs: Vec<Expr> = numeric_cols
+        .iter()
+        .flat_map(|other| {
+            numeric_cols.iter().map(move |this| {
+                stat(col(this.as_str()), col(other.as_str()), ddof).alias(cell_name(this, other))
+            })
+        })
+        .collect();
+
+    let row = df
+        .clone()
+        .lazy()
+        .select(exprs)
+        .collect()
+        .map_err(|e| ShellError::GenericError {
+            error: "Error computing pairwise matrix".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+
+    let mut matrix_cols: Vec<Series> = vec![Series::new("column".into(), numeric_cols.clone())];
+    for other in &numeric_cols {
+        let values: Vec<Option<f64>> = numeric_cols
+            .iter()
+            .map(|this| {
+                row.column(&cell_name(this, other))
+                    .ok()
+                    .and_then(|c| c.as_materialized_series().cast(&DataType::Float64).ok())
+                    .and_then(|s| s.f64().ok()?.get(0))
+            })
+            .collect();
+        matrix_cols.push(Series::new(other.as_str().into(), values));
+    }
+
+    DataFrame::new(matrix_cols).map_err(|e| ShellError::GenericError {
+        error: "Error building matrix dataframe".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })
+}
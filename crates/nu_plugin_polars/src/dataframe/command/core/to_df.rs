@@ -43,6 +43,11 @@ impl PluginCommand for ToDataFrame {
                 r#"When input shape is record of lists, treat each list as column values."#,
                 Some('c'),
             )
+            .switch(
+                "strict",
+                "Error instead of silently coercing a column with mixed types to a generic type",
+                None,
+            )
             .input_output_type(Type::Any, Type::Custom("dataframe".into()))
             .category(Category::Custom("dataframe".into()))
     }
@@ -215,6 +220,11 @@ impl PluginCommand for ToDataFrame {
                     .into_value(Span::test_data()),
                 ),
             },
+            Example {
+                description: "Fail instead of coercing a column with mixed types to a generic type",
+                example: r#"[[a]; [1] ["two"]] | polars into-df --strict"#,
+                result: None,
+            },
         ]
     }
 
@@ -233,9 +243,10 @@ impl PluginCommand for ToDataFrame {
         debug!("schema: {:?}", maybe_schema);
 
         let maybe_as_columns = call.has_flag("as-columns")?;
+        let strict = call.has_flag("strict")?;
 
         let df = if !maybe_as_columns {
-            NuDataFrame::try_from_iter(plugin, input.into_iter(), maybe_schema.clone())?
+            NuDataFrame::try_from_iter(plugin, input.into_iter(), maybe_schema.clone(), strict)?
         } else {
             match &input {
                 PipelineData::Value(Value::Record { val, .. }, _) => {
@@ -262,13 +273,19 @@ impl PluginCommand for ToDataFrame {
                                 plugin,
                                 input.into_iter(),
                                 maybe_schema.clone(),
+                                strict,
                             )?
                         }
                     }
                 }
                 _ => {
                     debug!("Other input: {input:?}");
-                    NuDataFrame::try_from_iter(plugin, input.into_iter(), maybe_schema.clone())?
+                    NuDataFrame::try_from_iter(
+                        plugin,
+                        input.into_iter(),
+                        maybe_schema.clone(),
+                        strict,
+                    )?
                 }
             }
         };
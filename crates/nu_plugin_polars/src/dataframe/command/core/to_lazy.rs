@@ -32,6 +32,11 @@ impl PluginCommand for ToLazyFrame {
                 r#"Polars Schema in format [{name: str}]."#,
                 Some('s'),
             )
+            .switch(
+                "strict",
+                "Error instead of silently coercing a column with mixed types to a generic type",
+                None,
+            )
             .input_output_type(Type::Any, Type::Custom("dataframe".into()))
             .category(Category::Custom("lazyframe".into()))
     }
@@ -74,7 +79,8 @@ impl PluginCommand for ToLazyFrame {
             .map(|schema| NuSchema::try_from_value(plugin, &schema))
             .transpose()?;
 
-        let df = NuDataFrame::try_from_iter(plugin, input.into_iter(), maybe_schema)?;
+        let strict = call.has_flag("strict")?;
+        let df = NuDataFrame::try_from_iter(plugin, input.into_iter(), maybe_schema, strict)?;
         let mut lazy = NuLazyFrame::from_dataframe(df);
         // We don't want this converted back to an eager dataframe at some point
         lazy.from_eager = false;
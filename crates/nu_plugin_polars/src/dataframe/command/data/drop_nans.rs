@@ -0,0 +1,109 @@
+use crate::values::{Column, CustomValueSupport, NuDataFrame};
+use crate::PolarsPlugin;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+
+#[derive(Clone)]
+pub struct DropNans;
+
+impl PluginCommand for DropNans {
+    type Plugin = PolarsPlugin;
+
+    fn name(&self) -> &str {
+        "polars drop-nans"
+    }
+
+    fn description(&self) -> &str {
+        "Drops rows that contain a NaN value in any column."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(
+                Type::Custom("dataframe".into()),
+                Type::Custom("dataframe".into()),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "drop NaN values in dataframe",
+            example: "[1 2 NaN 3 NaN] | polars into-df | polars drop-nans",
+            result: Some(
+                NuDataFrame::try_from_columns(
+                    vec![Column::new(
+                        "0".to_string(),
+                        vec![
+                            Value::test_int(1),
+                            Value::test_int(2),
+                            Value::test_int(3),
+                        ],
+                    )],
+                    None,
+                )
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        command(plugin, engine, call, input).map_err(LabeledError::from)
+    }
+}
+
+fn is_nan_value(value: &Value) -> bool {
+    matches!(value, Value::Float { val, .. } if val.is_nan())
+}
+
+fn command(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let df = NuDataFrame::try_from_pipeline_coerce(plugin, input, call.head)?;
+    let columns = df.columns(call.head)?;
+    let height = columns.iter().map(|c| c.len()).max().unwrap_or(0);
+
+    let keep: Vec<bool> = (0..height)
+        .map(|row| !columns.iter().any(|c| c.get(row).is_some_and(is_nan_value)))
+        .collect();
+
+    let filtered = columns
+        .into_iter()
+        .map(|column| {
+            let name = column.name().to_string();
+            let values = column
+                .into_iter()
+                .zip(keep.iter())
+                .filter_map(|(value, keep)| keep.then_some(value))
+                .collect::<Vec<Value>>();
+            Column::new(name, values)
+        })
+        .collect::<Vec<Column>>();
+
+    let df = NuDataFrame::try_from_columns(filtered, None)?;
+    df.to_pipeline_data(plugin, engine, call.head)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::test_polars_plugin_command;
+
+    #[test]
+    fn test_examples() -> Result<(), ShellError> {
+        test_polars_plugin_command(&DropNans)
+    }
+}
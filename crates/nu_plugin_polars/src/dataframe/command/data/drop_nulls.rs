@@ -31,6 +31,12 @@ impl PluginCommand for DropNulls {
                 SyntaxShape::Table(vec![]),
                 "subset of columns to drop nulls",
             )
+            .named(
+                "subset",
+                SyntaxShape::Table(vec![]),
+                "subset of columns to drop nulls, as a flag instead of a positional",
+                None,
+            )
             .input_output_type(
                 Type::Custom("dataframe".into()),
                 Type::Custom("dataframe".into()),
@@ -111,7 +117,10 @@ fn command(
 ) -> Result<PipelineData, ShellError> {
     let df = NuDataFrame::try_from_pipeline_coerce(plugin, input, call.head)?;
 
-    let columns: Option<Vec<Value>> = call.opt(0)?;
+    let columns: Option<Vec<Value>> = match call.get_flag("subset")? {
+        Some(cols) => Some(cols),
+        None => call.opt(0)?,
+    };
 
     let (subset, col_span) = match columns {
         Some(cols) => {
@@ -6,9 +6,10 @@ use crate::{
 
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
-    Value,
+    engine::Closure, Category, Example, LabeledError, PipelineData, ShellError, Signature, Span,
+    Spanned, SyntaxShape, Type, Value,
 };
+use polars::prelude::Series;
 
 #[derive(Clone)]
 pub struct LazyFilter;
@@ -24,12 +25,18 @@ impl PluginCommand for LazyFilter {
         "Filter dataframe based in expression."
     }
 
+    fn extra_description(&self) -> &str {
+        "The filter can also be a closure (e.g. {|row| $row.a >= 4 }), which is run once per \
+        row against a dataframe coerced to eager mode. This is more flexible than the \
+        expression syntax but is not vectorized, so it will be slower on large dataframes."
+    }
+
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .required(
                 "filter expression",
                 SyntaxShape::Any,
-                "Expression that define the column selection",
+                "Expression or closure that define the row selection",
             )
             .input_output_type(
                 Type::Custom("dataframe".into()),
@@ -101,6 +108,28 @@ impl PluginCommand for LazyFilter {
                 .into_value(Span::test_data()),
             ),
         },
+        Example {
+            description: "Filter dataframe using a closure, evaluated once per row",
+            example:
+                "[[a b]; [6 2] [4 2] [2 2]] | polars into-df | polars filter {|row| $row.a >= 4 }",
+            result: Some(
+                NuDataFrame::try_from_columns(
+                    vec![
+                        Column::new(
+                            "a".to_string(),
+                            vec![Value::test_int(6), Value::test_int(4)],
+                        ),
+                        Column::new(
+                            "b".to_string(),
+                            vec![Value::test_int(2), Value::test_int(2)],
+                        ),
+                    ],
+                    None,
+                )
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        },
     ]
     }
 
@@ -112,8 +141,18 @@ impl PluginCommand for LazyFilter {
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         let expr_value: Value = call.req(0)?;
-        let filter_expr = NuExpression::try_from_value(plugin, &expr_value)?;
         let pipeline_value = input.into_value(call.head)?;
+
+        if let Value::Closure { val, .. } = &expr_value {
+            let closure = Spanned {
+                item: (**val).clone(),
+                span: expr_value.span(),
+            };
+            return command_closure(plugin, engine, call, pipeline_value, closure)
+                .map_err(LabeledError::from);
+        }
+
+        let filter_expr = NuExpression::try_from_value(plugin, &expr_value)?;
         let lazy = NuLazyFrame::try_from_value_coerce(plugin, &pipeline_value)?;
         command(plugin, engine, call, lazy, filter_expr).map_err(LabeledError::from)
     }
@@ -133,6 +172,64 @@ fn command(
     lazy.to_pipeline_data(plugin, engine, call.head)
 }
 
+// Plugins only ever see a closure as an opaque block id plus its captured
+// values (see `nu_protocol::engine::Closure`), never an introspectable AST, so
+// there is no way to translate closure syntax into a Polars expression tree
+// here. Instead the closure is evaluated once per row through the engine's
+// `EvalClosure` call, and the resulting booleans are used to build a mask.
+fn command_closure(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    pipeline_value: Value,
+    closure: Spanned<Closure>,
+) -> Result<PipelineData, ShellError> {
+    let df = NuDataFrame::try_from_value_coerce(plugin, &pipeline_value, call.head)?;
+    let height = df.as_ref().height();
+    let rows = df.to_rows(0, height, false, call.head)?;
+
+    let mask: Vec<bool> = rows
+        .into_iter()
+        .map(|row| {
+            engine
+                .eval_closure(&closure, vec![row], None)?
+                .as_bool()
+                .map_err(|_| ShellError::GenericError {
+                    error: "Closure must return a boolean".into(),
+                    msg: "expected the closure to return true or false for each row".into(),
+                    span: Some(closure.span),
+                    help: None,
+                    inner: vec![],
+                })
+        })
+        .collect::<Result<Vec<bool>, ShellError>>()?;
+
+    let mask = Series::new("mask".into(), mask)
+        .bool()
+        .map_err(|e| ShellError::GenericError {
+            error: "Error creating filter mask".into(),
+            msg: e.to_string(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        })?
+        .clone();
+
+    let filtered = df
+        .as_ref()
+        .filter(&mask)
+        .map_err(|e| ShellError::GenericError {
+            error: "Error filtering dataframe".into(),
+            msg: e.to_string(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        })?;
+
+    let res = NuDataFrame::new(false, filtered);
+    res.to_pipeline_data(plugin, engine, call.head)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,14 +1,17 @@
 use crate::{
-    values::{Column, CustomValueSupport, NuLazyFrame, PolarsPluginObject},
+    dataframe::utils::select_columns,
+    values::{
+        Column, CustomValueSupport, NuDataFrame, NuExpression, NuLazyFrame, PolarsPluginObject,
+    },
     PolarsPlugin,
 };
 
-use crate::values::{NuDataFrame, NuExpression};
 use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
     Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
     Value,
 };
+use polars::prelude::col;
 
 #[derive(Clone)]
 pub struct FirstDF;
@@ -31,6 +34,18 @@ impl PluginCommand for FirstDF {
                 SyntaxShape::Int,
                 "starting from the front, the number of rows to return",
             )
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "number of rows to skip from the front before taking rows",
+                None,
+            )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "only return these columns",
+                Some('c'),
+            )
             .input_output_types(vec![
                 (
                     Type::Custom("expression".into()),
@@ -87,6 +102,18 @@ impl PluginCommand for FirstDF {
                 example: "polars col a | polars first",
                 result: None,
             },
+            Example {
+                description: "Return the first row, skipping the first row and projecting only column b",
+                example: "[[a b]; [1 2] [3 4]] | polars into-df | polars first --offset 1 --columns [b]",
+                result: Some(
+                    NuDataFrame::try_from_columns(
+                        vec![Column::new("b".to_string(), vec![Value::test_int(4)])],
+                        None,
+                    )
+                    .expect("should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
         ]
     }
 
@@ -124,8 +151,19 @@ fn command_eager(
 ) -> Result<PipelineData, ShellError> {
     let rows: Option<usize> = call.opt(0)?;
     let rows = rows.unwrap_or(1);
+    let offset: Option<i64> = call.get_flag("offset")?;
+    let columns: Option<Vec<String>> = call.get_flag("columns")?;
+
+    let res = match offset {
+        Some(offset) => df.as_ref().slice(offset, rows),
+        None => df.as_ref().head(Some(rows)),
+    };
+
+    let res = match columns {
+        Some(columns) => select_columns(&res, &columns, call.head)?,
+        None => res,
+    };
 
-    let res = df.as_ref().head(Some(rows));
     let res = NuDataFrame::new(false, res);
 
     res.to_pipeline_data(plugin, engine, call.head)
@@ -139,8 +177,21 @@ fn command_lazy(
 ) -> Result<PipelineData, ShellError> {
     let rows: Option<u64> = call.opt(0)?;
     let rows = rows.unwrap_or(1);
+    let offset: Option<i64> = call.get_flag("offset")?;
+    let columns: Option<Vec<String>> = call.get_flag("columns")?;
+
+    let mut res = lazy.to_polars();
+    res = match offset {
+        Some(offset) => res.slice(offset, rows),
+        None => res.limit(rows),
+    };
+
+    if let Some(columns) = columns {
+        let exprs = columns.iter().map(|c| col(c.as_str())).collect::<Vec<_>>();
+        res = res.select(exprs);
+    }
 
-    let res: NuLazyFrame = lazy.to_polars().limit(rows).into();
+    let res: NuLazyFrame = res.into();
     res.to_pipeline_data(plugin, engine, call.head)
 }
 
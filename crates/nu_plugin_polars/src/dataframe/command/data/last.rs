@@ -1,4 +1,5 @@
 use crate::{
+    dataframe::utils::select_columns,
     values::{Column, CustomValueSupport, NuLazyFrame, PolarsPluginObject},
     PolarsPlugin,
 };
@@ -9,6 +10,7 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
     Value,
 };
+use polars::prelude::col;
 
 #[derive(Clone)]
 pub struct LastDF;
@@ -27,6 +29,18 @@ impl PluginCommand for LastDF {
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .optional("rows", SyntaxShape::Int, "Number of rows for tail")
+            .named(
+                "offset",
+                SyntaxShape::Int,
+                "number of rows to skip from the end before taking rows",
+                None,
+            )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "only return these columns",
+                Some('c'),
+            )
             .input_output_types(vec![
                 (
                     Type::Custom("expression".into()),
@@ -62,6 +76,18 @@ impl PluginCommand for LastDF {
                 example: "polars col a | polars last",
                 result: None,
             },
+            Example {
+                description: "Skip the very last row and return the row before it, projecting only column a",
+                example: "[[a b]; [1 2] [3 4] [5 6]] | polars into-df | polars last 1 --offset 1 --columns [a]",
+                result: Some(
+                    NuDataFrame::try_from_columns(
+                        vec![Column::new("a".to_string(), vec![Value::test_int(3)])],
+                        None,
+                    )
+                    .expect("simple df for test should not fail")
+                    .into_value(Span::test_data()),
+                ),
+            },
         ]
     }
 
@@ -99,8 +125,19 @@ fn command_eager(
 ) -> Result<PipelineData, ShellError> {
     let rows: Option<usize> = call.opt(0)?;
     let rows = rows.unwrap_or(DEFAULT_ROWS);
+    let offset: Option<i64> = call.get_flag("offset")?;
+    let columns: Option<Vec<String>> = call.get_flag("columns")?;
+
+    let res = match offset {
+        Some(offset) => df.as_ref().slice(-(rows as i64 + offset), rows),
+        None => df.as_ref().tail(Some(rows)),
+    };
+
+    let res = match columns {
+        Some(columns) => select_columns(&res, &columns, call.head)?,
+        None => res,
+    };
 
-    let res = df.as_ref().tail(Some(rows));
     let res = NuDataFrame::new(false, res);
     res.to_pipeline_data(plugin, engine, call.head)
 }
@@ -113,9 +150,21 @@ fn command_lazy(
 ) -> Result<PipelineData, ShellError> {
     let rows: Option<u64> = call.opt(0)?;
     let rows = rows.unwrap_or(DEFAULT_ROWS as u64);
+    let offset: Option<i64> = call.get_flag("offset")?;
+    let columns: Option<Vec<String>> = call.get_flag("columns")?;
+
+    let mut res = lazy.to_polars();
+    res = match offset {
+        Some(offset) => res.slice(-(rows as i64 + offset), rows),
+        None => res.tail(rows),
+    };
+
+    if let Some(columns) = columns {
+        let exprs = columns.iter().map(|c| col(c.as_str())).collect::<Vec<_>>();
+        res = res.select(exprs);
+    }
 
-    let res: NuLazyFrame = lazy.to_polars().tail(rows).into();
-
+    let res: NuLazyFrame = res.into();
     res.to_pipeline_data(plugin, engine, call.head)
 }
 
@@ -8,6 +8,7 @@ mod concat;
 mod cut;
 mod drop;
 mod drop_duplicates;
+mod drop_nans;
 mod drop_nulls;
 mod dummies;
 mod explode;
@@ -22,6 +23,7 @@ mod join;
 mod last;
 mod len;
 mod lit;
+mod null_report;
 mod pivot;
 mod qcut;
 mod query_df;
@@ -52,6 +54,7 @@ pub use col::ExprCol;
 pub use collect::LazyCollect;
 pub use drop::DropDF;
 pub use drop_duplicates::DropDuplicates;
+pub use drop_nans::DropNans;
 pub use drop_nulls::DropNulls;
 pub use dummies::Dummies;
 pub use explode::LazyExplode;
@@ -63,6 +66,7 @@ pub use get::GetDF;
 use join::LazyJoin;
 pub use last::LastDF;
 pub use lit::ExprLit;
+pub use null_report::NullReport;
 use query_df::QueryDf;
 pub use rename::RenameDF;
 pub use sample::SampleDF;
@@ -81,6 +85,7 @@ pub(crate) fn data_commands() -> Vec<Box<dyn PluginCommand<Plugin = PolarsPlugin
         Box::new(DropDF),
         Box::new(concat::ConcatDF),
         Box::new(DropDuplicates),
+        Box::new(DropNans),
         Box::new(DropNulls),
         Box::new(Dummies),
         Box::new(filter_with::FilterWith),
@@ -106,6 +111,7 @@ pub(crate) fn data_commands() -> Vec<Box<dyn PluginCommand<Plugin = PolarsPlugin
         Box::new(LazyFillNull),
         Box::new(LazyFlatten),
         Box::new(LazyJoin),
+        Box::new(NullReport),
         Box::new(reverse::LazyReverse),
         Box::new(select::LazySelect),
         Box::new(LazySortBy),
@@ -0,0 +1,157 @@
+use crate::values::{Column, CustomValueSupport, NuDataFrame};
+use crate::PolarsPlugin;
+
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, Type, Value,
+};
+use polars::prelude::DataType;
+
+#[derive(Clone)]
+pub struct NullReport;
+
+impl PluginCommand for NullReport {
+    type Plugin = PolarsPlugin;
+
+    fn name(&self) -> &str {
+        "polars null-report"
+    }
+
+    fn description(&self) -> &str {
+        "Reports null and NaN counts and percentages for each column in a dataframe."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(self.name())
+            .input_output_type(
+                Type::Custom("dataframe".into()),
+                Type::Custom("dataframe".into()),
+            )
+            .category(Category::Custom("dataframe".into()))
+    }
+
+    fn examples(&'_ self) -> Vec<Example<'_>> {
+        vec![Example {
+            description: "Report null and NaN counts per column",
+            example: "[[a b]; [1 2.0] [2 null]] | polars into-df | polars null-report",
+            result: Some(
+                NuDataFrame::try_from_columns(
+                    vec![
+                        Column::new(
+                            "column".to_string(),
+                            vec![Value::test_string("a"), Value::test_string("b")],
+                        ),
+                        Column::new(
+                            "null_count".to_string(),
+                            vec![Value::test_int(0), Value::test_int(1)],
+                        ),
+                        Column::new(
+                            "nan_count".to_string(),
+                            vec![Value::test_int(0), Value::test_int(0)],
+                        ),
+                        Column::new(
+                            "null_percentage".to_string(),
+                            vec![Value::test_float(0.0), Value::test_float(50.0)],
+                        ),
+                    ],
+                    None,
+                )
+                .expect("simple df for test should not fail")
+                .into_value(Span::test_data()),
+            ),
+        }]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        command(plugin, engine, call, input).map_err(LabeledError::from)
+    }
+}
+
+fn nan_count(series: &polars::prelude::Series) -> Result<usize, ShellError> {
+    let count = match series.dtype() {
+        DataType::Float32 => series
+            .f32()
+            .map_err(|e| ShellError::GenericError {
+                error: "Error casting column to f32".into(),
+                msg: e.to_string(),
+                span: None,
+                help: None,
+                inner: vec![],
+            })?
+            .into_iter()
+            .filter(|v| v.is_some_and(|x| x.is_nan()))
+            .count(),
+        DataType::Float64 => series
+            .f64()
+            .map_err(|e| ShellError::GenericError {
+                error: "Error casting column to f64".into(),
+                msg: e.to_string(),
+                span: None,
+                help: None,
+                inner: vec![],
+            })?
+            .into_iter()
+            .filter(|v| v.is_some_and(|x| x.is_nan()))
+            .count(),
+        _ => 0,
+    };
+    Ok(count)
+}
+
+fn command(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let df = NuDataFrame::try_from_pipeline_coerce(plugin, input, call.head)?;
+    let height = df.as_ref().height();
+
+    let mut columns = Vec::new();
+    let mut nulls = Vec::new();
+    let mut nans = Vec::new();
+    let mut percentages = Vec::new();
+
+    for column in df.as_ref().get_columns() {
+        let series = column.as_materialized_series();
+        let null_count = series.null_count();
+        let percentage = if height == 0 {
+            0.0
+        } else {
+            (null_count as f64 / height as f64) * 100.0
+        };
+
+        columns.push(Value::string(series.name().to_string(), call.head));
+        nulls.push(Value::int(null_count as i64, call.head));
+        nans.push(Value::int(nan_count(series)? as i64, call.head));
+        percentages.push(Value::float(percentage, call.head));
+    }
+
+    let df = NuDataFrame::try_from_columns(
+        vec![
+            Column::new("column".to_string(), columns),
+            Column::new("null_count".to_string(), nulls),
+            Column::new("nan_count".to_string(), nans),
+            Column::new("null_percentage".to_string(), percentages),
+        ],
+        None,
+    )?;
+    df.to_pipeline_data(plugin, engine, call.head)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test::test_polars_plugin_command;
+
+    #[test]
+    fn test_examples() -> Result<(), ShellError> {
+        test_polars_plugin_command(&NullReport)
+    }
+}
@@ -9,7 +9,9 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, ShellError, Signature, Span, SyntaxShape, Type,
     Value,
 };
-use polars::prelude::{DataType, Field, IntoSeries, Schema, StringMethods, TimeUnit};
+use polars::prelude::{
+    DataFrame, DataType, Field, IntoSeries, Schema, Series, StringMethods, TimeUnit,
+};
 
 #[derive(Clone)]
 pub struct AsDateTime;
@@ -42,9 +44,26 @@ impl PluginCommand for AsDateTime {
 
     fn signature(&self) -> Signature {
         Signature::build(self.name())
-            .required("format", SyntaxShape::String, "formatting date time string")
+            .optional("format", SyntaxShape::String, "formatting date time string")
             .switch("not-exact", "the format string may be contained in the date (e.g. foo-2021-01-01-bar could match 2021-01-01)", Some('n'))
             .switch("naive", "the input datetimes should be parsed as naive (i.e., not timezone-aware)", None)
+            .switch(
+                "infer",
+                "detect the format automatically instead of specifying one",
+                Some('i'),
+            )
+            .named(
+                "unit",
+                SyntaxShape::String,
+                "time unit of the resulting datetime column: ms, us, or ns (default: ns)",
+                Some('u'),
+            )
+            .named(
+                "columns",
+                SyntaxShape::List(Box::new(SyntaxShape::String)),
+                "convert these columns instead of requiring a single-column dataframe",
+                Some('c'),
+            )
             .input_output_type(
                 Type::Custom("dataframe".into()),
                 Type::Custom("dataframe".into()),
@@ -158,6 +177,16 @@ impl PluginCommand for AsDateTime {
                     .into_value(Span::test_data()),
                 ),
             },
+            Example {
+                description: "Detect the format automatically instead of specifying one",
+                example: r#"["2021-12-30 00:00:00" "2021-12-31 00:00:00"] | polars into-df | polars as-datetime --infer --naive"#,
+                result: None,
+            },
+            Example {
+                description: "Convert several string columns to datetime at once",
+                example: r#"[[a b]; ["2021-12-30 00:00:00" "2021-12-31 00:00:00"]] | polars into-df | polars as-datetime "%Y-%m-%d %H:%M:%S" --naive --columns [a b]"#,
+                result: None,
+            },
         ]
     }
 
@@ -172,18 +201,29 @@ impl PluginCommand for AsDateTime {
     }
 }
 
-fn command(
-    plugin: &PolarsPlugin,
-    engine: &EngineInterface,
-    call: &EvaluatedCall,
-    input: PipelineData,
-) -> Result<PipelineData, ShellError> {
-    let format: String = call.req(0)?;
-    let not_exact = call.has_flag("not-exact")?;
-    let tz_aware = !call.has_flag("naive")?;
+fn parse_time_unit(call: &EvaluatedCall) -> Result<TimeUnit, ShellError> {
+    match call.get_flag::<String>("unit")?.as_deref() {
+        None | Some("ns") => Ok(TimeUnit::Nanoseconds),
+        Some("ms") => Ok(TimeUnit::Milliseconds),
+        Some("us") => Ok(TimeUnit::Microseconds),
+        Some(_) => Err(ShellError::GenericError {
+            error: "Invalid argument value".into(),
+            msg: "`unit` must be one of ms, us, or ns".into(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        }),
+    }
+}
 
-    let df = NuDataFrame::try_from_pipeline_coerce(plugin, input, call.head)?;
-    let series = df.as_series(call.head)?;
+fn cast_column_to_datetime(
+    series: &Series,
+    format: Option<&str>,
+    unit: TimeUnit,
+    not_exact: bool,
+    tz_aware: bool,
+    call: &EvaluatedCall,
+) -> Result<Series, ShellError> {
     let casted = series.str().map_err(|e| ShellError::GenericError {
         error: "Error casting to string".into(),
         msg: e.to_string(),
@@ -193,36 +233,104 @@ fn command(
     })?;
 
     let res = if not_exact {
-        casted.as_datetime_not_exact(
-            Some(format.as_str()),
-            TimeUnit::Nanoseconds,
-            tz_aware,
-            None,
-            &Default::default(),
-        )
+        casted.as_datetime_not_exact(format, unit, tz_aware, None, &Default::default())
     } else {
-        casted.as_datetime(
-            Some(format.as_str()),
-            TimeUnit::Nanoseconds,
-            false,
-            tz_aware,
-            None,
-            &Default::default(),
-        )
+        casted.as_datetime(format, unit, false, tz_aware, None, &Default::default())
     };
 
-    let mut res = res
+    res.map(IntoSeries::into_series)
         .map_err(|e| ShellError::GenericError {
             error: "Error creating datetime".into(),
             msg: e.to_string(),
             span: Some(call.head),
             help: None,
             inner: vec![],
-        })?
-        .into_series();
+        })
+}
+
+fn command(
+    plugin: &PolarsPlugin,
+    engine: &EngineInterface,
+    call: &EvaluatedCall,
+    input: PipelineData,
+) -> Result<PipelineData, ShellError> {
+    let format: Option<String> = call.opt(0)?;
+    let not_exact = call.has_flag("not-exact")?;
+    let tz_aware = !call.has_flag("naive")?;
+    let infer = call.has_flag("infer")?;
+    let unit = parse_time_unit(call)?;
+    let columns: Option<Vec<String>> = call.get_flag("columns")?;
+
+    if format.is_some() && infer {
+        return Err(ShellError::GenericError {
+            error: "Conflicting arguments".into(),
+            msg: "cannot use --infer together with an explicit format".into(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        });
+    }
+    if format.is_none() && !infer {
+        return Err(ShellError::GenericError {
+            error: "Missing format".into(),
+            msg: "either a format or --infer is required".into(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        });
+    }
+
+    let df = NuDataFrame::try_from_pipeline_coerce(plugin, input, call.head)?;
+
+    let df = if let Some(columns) = columns {
+        let mut series: Vec<Series> = df
+            .df
+            .get_columns()
+            .iter()
+            .map(|c| c.as_materialized_series().clone())
+            .collect();
+        for name in &columns {
+            let source = df.column(name, call.head)?.as_series(call.head)?;
+            let converted = cast_column_to_datetime(
+                &source,
+                format.as_deref(),
+                unit,
+                not_exact,
+                tz_aware,
+                call,
+            )?;
+            let idx = df
+                .df
+                .get_column_names()
+                .iter()
+                .position(|n| n.as_str() == name.as_str())
+                .ok_or_else(|| ShellError::GenericError {
+                    error: "Series not found in dataframe".into(),
+                    msg: format!("Unable to find column named '{name}'"),
+                    span: Some(call.head),
+                    help: None,
+                    inner: vec![],
+                })?;
+            let mut converted = converted;
+            converted.rename(source.name().to_owned());
+            series[idx] = converted;
+        }
+        let df = DataFrame::new(series).map_err(|e| ShellError::GenericError {
+            error: "Error creating dataframe".into(),
+            msg: e.to_string(),
+            span: Some(call.head),
+            help: None,
+            inner: vec![],
+        })?;
+        NuDataFrame::new(false, df)
+    } else {
+        let series = df.as_series(call.head)?;
+        let mut res =
+            cast_column_to_datetime(&series, format.as_deref(), unit, not_exact, tz_aware, call)?;
+        res.rename("datetime".into());
+        NuDataFrame::try_from_series_vec(vec![res], call.head)?
+    };
 
-    res.rename("datetime".into());
-    let df = NuDataFrame::try_from_series_vec(vec![res], call.head)?;
     df.to_pipeline_data(plugin, engine, call.head)
 }
 
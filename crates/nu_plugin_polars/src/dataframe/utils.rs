@@ -1,5 +1,5 @@
-use nu_protocol::{FromValue, ShellError, Value};
-use polars::prelude::PlSmallStr;
+use nu_protocol::{FromValue, ShellError, Span, Value};
+use polars::prelude::{DataFrame, PlSmallStr};
 
 pub fn extract_strings(value: Value) -> Result<Vec<String>, ShellError> {
     let span = value.span();
@@ -30,3 +30,33 @@ pub fn extract_sm_strs(value: Value) -> Result<Vec<PlSmallStr>, ShellError> {
         }),
     }
 }
+
+/// Projects `df` down to just `columns`, in the given order.
+pub fn select_columns(
+    df: &DataFrame,
+    columns: &[String],
+    span: Span,
+) -> Result<DataFrame, ShellError> {
+    let series = columns
+        .iter()
+        .map(|name| {
+            df.column(name)
+                .map(|c| c.as_materialized_series().clone())
+                .map_err(|_| ShellError::GenericError {
+                    error: "Column not found".into(),
+                    msg: format!("Unable to find column named '{name}'"),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                })
+        })
+        .collect::<Result<Vec<_>, ShellError>>()?;
+
+    DataFrame::new(series).map_err(|e| ShellError::GenericError {
+        error: "Error selecting columns".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })
+}
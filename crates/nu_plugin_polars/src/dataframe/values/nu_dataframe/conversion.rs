@@ -191,9 +191,10 @@ pub fn insert_record(
     column_values: &mut ColumnMap,
     record: Record,
     maybe_schema: &Option<NuSchema>,
+    strict: bool,
 ) -> Result<(), ShellError> {
     for (col, value) in record {
-        insert_value(value, col.into(), column_values, maybe_schema)?;
+        insert_value(value, col.into(), column_values, maybe_schema, strict)?;
     }
 
     Ok(())
@@ -204,6 +205,7 @@ pub fn insert_value(
     key: PlSmallStr,
     column_values: &mut ColumnMap,
     maybe_schema: &Option<NuSchema>,
+    strict: bool,
 ) -> Result<(), ShellError> {
     // If we have a schema but a key is not provided, do not create that column
     if let Some(schema) = maybe_schema {
@@ -232,6 +234,21 @@ pub fn insert_value(
         col_val.column_type = value_to_data_type(&value);
     } else if let Some(current_data_type) = current_data_type {
         if col_val.column_type.as_ref() != Some(&current_data_type) {
+            if strict {
+                return Err(ShellError::GenericError {
+                    error: format!("Cannot add value to column '{key}': inconsistent types"),
+                    msg: format!(
+                        "expected {:?}, found {current_data_type:?} at row {}",
+                        col_val.column_type,
+                        col_val.values.len()
+                    ),
+                    span: Some(value.span()),
+                    help: Some(
+                        "pass --schema to force a column type, or drop --strict to coerce mixed columns to a generic type".into(),
+                    ),
+                    inner: vec![],
+                });
+            }
             col_val.column_type = Some(DataType::Object("Value", None));
         }
     }
@@ -497,7 +514,7 @@ fn typed_column_to_series(name: PlSmallStr, column: TypedColumn) -> Result<Serie
             for v in column.values.iter() {
                 let mut column_values: ColumnMap = IndexMap::new();
                 let record = v.as_record()?;
-                insert_record(&mut column_values, record.clone(), &schema)?;
+                insert_record(&mut column_values, record.clone(), &schema, false)?;
                 let df = from_parsed_columns(column_values)?;
                 for name in df.df.get_column_names() {
                     let series = df
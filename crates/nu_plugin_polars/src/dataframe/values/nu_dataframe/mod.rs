@@ -160,6 +160,7 @@ impl NuDataFrame {
         plugin: &PolarsPlugin,
         iter: T,
         maybe_schema: Option<NuSchema>,
+        strict: bool,
     ) -> Result<Self, ShellError>
     where
         T: Iterator<Item = Value>,
@@ -181,16 +182,23 @@ impl NuDataFrame {
                         .map(|(i, val)| (format!("{i}"), val))
                         .collect();
 
-                    conversion::insert_record(&mut column_values, record, &maybe_schema)?
+                    conversion::insert_record(&mut column_values, record, &maybe_schema, strict)?
                 }
                 Value::Record { val: record, .. } => conversion::insert_record(
                     &mut column_values,
                     record.into_owned(),
                     &maybe_schema,
+                    strict,
                 )?,
                 _ => {
                     let key = "0".to_string();
-                    conversion::insert_value(value, key.into(), &mut column_values, &maybe_schema)?
+                    conversion::insert_value(
+                        value,
+                        key.into(),
+                        &mut column_values,
+                        &maybe_schema,
+                        strict,
+                    )?
                 }
             }
         }
@@ -223,7 +231,13 @@ impl NuDataFrame {
         for column in columns {
             let name = column.name().clone();
             for value in column {
-                conversion::insert_value(value, name.clone(), &mut column_values, &maybe_schema)?;
+                conversion::insert_value(
+                    value,
+                    name.clone(),
+                    &mut column_values,
+                    &maybe_schema,
+                    false,
+                )?;
             }
         }
 
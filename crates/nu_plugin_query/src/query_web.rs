@@ -18,6 +18,10 @@ impl SimplePluginCommand for QueryWeb {
         "execute selector query on html/web"
     }
 
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["css", "html", "selector"]
+    }
+
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .named("query", SyntaxShape::String, "selector query", Some('q'))
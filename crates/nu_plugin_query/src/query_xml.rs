@@ -19,6 +19,10 @@ impl SimplePluginCommand for QueryXml {
         "execute xpath query on xml"
     }
 
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["xpath", "xml"]
+    }
+
     fn signature(&self) -> Signature {
         Signature::build(self.name())
             .required("query", SyntaxShape::String, "xpath query")
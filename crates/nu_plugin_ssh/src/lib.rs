@@ -0,0 +1,87 @@
+mod run;
+mod session;
+mod sftp_get;
+mod sftp_put;
+
+pub use run::SshRun;
+pub use sftp_get::SftpGet;
+pub use sftp_put::SftpPut;
+
+use nu_plugin::{EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, Signature, Value};
+
+pub struct SshPlugin;
+
+impl Plugin for SshPlugin {
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+
+    fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
+        vec![
+            Box::new(SshCommand),
+            Box::new(SftpCommand),
+            Box::new(SshRun),
+            Box::new(SftpGet),
+            Box::new(SftpPut),
+        ]
+    }
+}
+
+// With no subcommand
+pub struct SshCommand;
+
+impl SimplePluginCommand for SshCommand {
+    type Plugin = SshPlugin;
+
+    fn name(&self) -> &str {
+        "ssh"
+    }
+
+    fn description(&self) -> &str {
+        "Run commands on remote hosts over SSH"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self)).category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        _plugin: &SshPlugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        Ok(Value::string(engine.get_help()?, call.head))
+    }
+}
+
+// With no subcommand
+pub struct SftpCommand;
+
+impl SimplePluginCommand for SftpCommand {
+    type Plugin = SshPlugin;
+
+    fn name(&self) -> &str {
+        "sftp"
+    }
+
+    fn description(&self) -> &str {
+        "Transfer files to and from remote hosts over SFTP"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self)).category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        _plugin: &SshPlugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        Ok(Value::string(engine.get_help()?, call.head))
+    }
+}
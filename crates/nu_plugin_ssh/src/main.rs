@@ -0,0 +1,6 @@
+use nu_plugin::{serve_plugin, JsonSerializer};
+use nu_plugin_ssh::SshPlugin;
+
+fn main() {
+    serve_plugin(&SshPlugin, JsonSerializer {})
+}
@@ -0,0 +1,200 @@
+use crate::session::{connect, split_user_host};
+use crate::SshPlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{
+    Category, LabeledError, Record, ShellError, Signature, Span, SyntaxShape, Type, Value,
+};
+use std::io::Read;
+use std::path::PathBuf;
+
+pub struct SshRun;
+
+impl SimplePluginCommand for SshRun {
+    type Plugin = SshPlugin;
+
+    fn name(&self) -> &str {
+        "ssh run"
+    }
+
+    fn description(&self) -> &str {
+        "Run a shell command on a remote host over SSH"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Authenticates via a running ssh-agent, or a key file passed with --identity. \
+When `host` is omitted, hosts are read from piped input (a list of `user@host` strings, or a \
+table with a `host` column) and the command runs once per host, sequentially, producing one \
+row per host. Pipe the input through `par-each` first for concurrent fan-out."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("command", SyntaxShape::String, "the shell command to run on the remote host")
+            .optional(
+                "host",
+                SyntaxShape::String,
+                "the remote host to connect to, as `user@host` or `host`",
+            )
+            .named("user", SyntaxShape::String, "default user if not given in the host", Some('u'))
+            .named("port", SyntaxShape::Int, "SSH port (default 22)", Some('p'))
+            .named(
+                "identity",
+                SyntaxShape::Filepath,
+                "private key file to authenticate with, instead of ssh-agent",
+                Some('i'),
+            )
+            .input_output_types(vec![
+                (Type::Nothing, Type::record()),
+                (Type::table(), Type::table()),
+                (Type::List(Box::new(Type::String)), Type::table()),
+            ])
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        _plugin: &SshPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let head = call.head;
+        let command: String = call.req(0)?;
+        let host_arg: Option<String> = call.opt(1)?;
+        let default_user: String = call
+            .get_flag("user")?
+            .unwrap_or_else(|| whoami_env());
+        let port: i64 = call.get_flag("port")?.unwrap_or(22);
+        let identity: Option<PathBuf> = call.get_flag("identity")?;
+
+        if let Some(host) = host_arg {
+            let row = run_one(&host, &command, &default_user, port as u16, identity.as_deref(), head)?;
+            return Ok(row);
+        }
+
+        let hosts = hosts_from_input(input, head)?;
+        let mut rows = Vec::with_capacity(hosts.len());
+        for host in hosts {
+            rows.push(run_one(
+                &host,
+                &command,
+                &default_user,
+                port as u16,
+                identity.as_deref(),
+                head,
+            )?);
+        }
+        Ok(Value::list(rows, head))
+    }
+}
+
+fn whoami_env() -> String {
+    std::env::var("USER").unwrap_or_else(|_| "root".into())
+}
+
+fn hosts_from_input(input: &Value, span: Span) -> Result<Vec<String>, ShellError> {
+    match input {
+        Value::List { vals, .. } => vals
+            .iter()
+            .map(|v| match v {
+                Value::String { val, .. } => Ok(val.clone()),
+                Value::Record { val, .. } => val
+                    .get("host")
+                    .and_then(|v| v.as_str().ok())
+                    .map(str::to_string)
+                    .ok_or_else(|| ShellError::GenericError {
+                        error: "Missing host column".into(),
+                        msg: "expected a 'host' column in each row".into(),
+                        span: Some(span),
+                        help: None,
+                        inner: vec![],
+                    }),
+                _ => Err(ShellError::GenericError {
+                    error: "Unsupported input row".into(),
+                    msg: "expected a string or a record with a 'host' column".into(),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                }),
+            })
+            .collect(),
+        Value::Nothing { .. } => Err(ShellError::GenericError {
+            error: "Missing host".into(),
+            msg: "pass a host argument or pipe in a list/table of hosts".into(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        }),
+        _ => Err(ShellError::GenericError {
+            error: "Unsupported input".into(),
+            msg: "expected a list of hosts or a table with a 'host' column".into(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        }),
+    }
+}
+
+fn run_one(
+    host_spec: &str,
+    command: &str,
+    default_user: &str,
+    port: u16,
+    identity: Option<&std::path::Path>,
+    span: Span,
+) -> Result<Value, ShellError> {
+    let (user, host) = split_user_host(host_spec, default_user);
+    let session = connect(host, port, user, identity, span)?;
+
+    let mut channel = session.channel_session().map_err(|e| ShellError::GenericError {
+        error: "Could not open SSH channel".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })?;
+    channel.exec(command).map_err(|e| ShellError::GenericError {
+        error: "Could not execute remote command".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })?;
+
+    let mut stdout = String::new();
+    channel
+        .read_to_string(&mut stdout)
+        .map_err(|e| ShellError::GenericError {
+            error: "Could not read remote stdout".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+    let mut stderr = String::new();
+    channel
+        .stderr()
+        .read_to_string(&mut stderr)
+        .map_err(|e| ShellError::GenericError {
+            error: "Could not read remote stderr".into(),
+            msg: e.to_string(),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        })?;
+    channel.wait_close().map_err(|e| ShellError::GenericError {
+        error: "Could not close SSH channel".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: None,
+        inner: vec![],
+    })?;
+    let exit_code = channel.exit_status().unwrap_or(-1);
+
+    let mut record = Record::new();
+    record.push("host", Value::string(host_spec, span));
+    record.push("exit_code", Value::int(exit_code as i64, span));
+    record.push("stdout", Value::string(stdout, span));
+    record.push("stderr", Value::string(stderr, span));
+    Ok(Value::record(record, span))
+}
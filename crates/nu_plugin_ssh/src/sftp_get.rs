@@ -0,0 +1,83 @@
+use crate::session::{connect, split_user_host};
+use crate::SshPlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, ShellError, Signature, SyntaxShape, Type, Value};
+use std::io::Read;
+use std::path::PathBuf;
+
+pub struct SftpGet;
+
+impl SimplePluginCommand for SftpGet {
+    type Plugin = SshPlugin;
+
+    fn name(&self) -> &str {
+        "sftp get"
+    }
+
+    fn description(&self) -> &str {
+        "Download a file from a remote host over SFTP"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("host", SyntaxShape::String, "the remote host, as `user@host` or `host`")
+            .required("remote_path", SyntaxShape::String, "path of the file to download")
+            .named("user", SyntaxShape::String, "default user if not given in the host", Some('u'))
+            .named("port", SyntaxShape::Int, "SSH port (default 22)", Some('p'))
+            .named(
+                "identity",
+                SyntaxShape::Filepath,
+                "private key file to authenticate with, instead of ssh-agent",
+                Some('i'),
+            )
+            .input_output_type(Type::Nothing, Type::Binary)
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        _plugin: &SshPlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let head = call.head;
+        let host_spec: String = call.req(0)?;
+        let remote_path: String = call.req(1)?;
+        let default_user: String = call
+            .get_flag("user")?
+            .unwrap_or_else(|| std::env::var("USER").unwrap_or_else(|_| "root".into()));
+        let port: i64 = call.get_flag("port")?.unwrap_or(22);
+        let identity: Option<PathBuf> = call.get_flag("identity")?;
+
+        let (user, host) = split_user_host(&host_spec, &default_user);
+        let session = connect(host, port as u16, user, identity.as_deref(), head)?;
+        let sftp = session.sftp().map_err(|e| ShellError::GenericError {
+            error: "Could not start SFTP session".into(),
+            msg: e.to_string(),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        })?;
+
+        let mut file = sftp
+            .open(std::path::Path::new(&remote_path))
+            .map_err(|e| ShellError::GenericError {
+                error: "Could not open remote file".into(),
+                msg: format!("{remote_path}: {e}"),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf).map_err(|e| ShellError::GenericError {
+            error: "Could not read remote file".into(),
+            msg: e.to_string(),
+            span: Some(head),
+            help: None,
+            inner: vec![],
+        })?;
+
+        Ok(Value::binary(buf, head))
+    }
+}
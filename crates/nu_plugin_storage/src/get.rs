@@ -0,0 +1,58 @@
+use crate::store::{build_store, parse_url};
+use crate::StoragePlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, ShellError, Signature, SyntaxShape, Type, Value};
+
+pub struct StorageGet;
+
+impl SimplePluginCommand for StorageGet {
+    type Plugin = StoragePlugin;
+
+    fn name(&self) -> &str {
+        "storage get"
+    }
+
+    fn description(&self) -> &str {
+        "Download an object from cloud storage"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("url", SyntaxShape::String, "the object URL to download, e.g. s3://bucket/key")
+            .input_output_type(Type::Nothing, Type::Binary)
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        plugin: &StoragePlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let url: String = call.req(0)?;
+        let head = call.head;
+        let url = parse_url(&url, head)?;
+        let (store, path) = build_store(&url, head)?;
+
+        let bytes = plugin.runtime.block_on(async move {
+            let result = store.get(&path).await.map_err(|e| ShellError::GenericError {
+                error: "Could not download object".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+            let bytes = result.bytes().await.map_err(|e| ShellError::GenericError {
+                error: "Could not read object body".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })?;
+            Ok::<_, ShellError>(bytes)
+        })?;
+
+        Ok(Value::binary(bytes.to_vec(), head))
+    }
+}
@@ -0,0 +1,81 @@
+mod get;
+mod ls;
+mod put;
+mod rm;
+mod store;
+
+pub use get::StorageGet;
+pub use ls::StorageLs;
+pub use put::StoragePut;
+pub use rm::StorageRm;
+
+use nu_plugin::{EvaluatedCall, Plugin, PluginCommand, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, ShellError, Signature, Value};
+use tokio::runtime::Runtime;
+
+pub struct StoragePlugin {
+    pub(crate) runtime: Runtime,
+}
+
+impl StoragePlugin {
+    pub fn new() -> Result<Self, ShellError> {
+        let runtime = Runtime::new().map_err(|e| ShellError::GenericError {
+            error: "Could not instantiate tokio".into(),
+            msg: e.to_string(),
+            span: None,
+            help: None,
+            inner: vec![],
+        })?;
+        Ok(Self { runtime })
+    }
+}
+
+impl Plugin for StoragePlugin {
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").into()
+    }
+
+    fn commands(&self) -> Vec<Box<dyn PluginCommand<Plugin = Self>>> {
+        vec![
+            Box::new(StorageCommand),
+            Box::new(StorageLs),
+            Box::new(StorageGet),
+            Box::new(StoragePut),
+            Box::new(StorageRm),
+        ]
+    }
+}
+
+// With no subcommand
+pub struct StorageCommand;
+
+impl SimplePluginCommand for StorageCommand {
+    type Plugin = StoragePlugin;
+
+    fn name(&self) -> &str {
+        "storage"
+    }
+
+    fn description(&self) -> &str {
+        "Read and write objects in cloud storage buckets"
+    }
+
+    fn extra_description(&self) -> &str {
+        "Currently only s3:// and s3a:// URLs are supported; gcs:// and azure:// are not yet \
+implemented. Credentials are read from the standard AWS_* environment variables."
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(PluginCommand::name(self)).category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        _plugin: &StoragePlugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        Ok(Value::string(engine.get_help()?, call.head))
+    }
+}
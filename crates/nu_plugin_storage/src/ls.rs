@@ -0,0 +1,64 @@
+use crate::store::{build_store, parse_url};
+use crate::StoragePlugin;
+use futures::StreamExt;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, Record, ShellError, Signature, SyntaxShape, Type, Value};
+
+pub struct StorageLs;
+
+impl SimplePluginCommand for StorageLs {
+    type Plugin = StoragePlugin;
+
+    fn name(&self) -> &str {
+        "storage ls"
+    }
+
+    fn description(&self) -> &str {
+        "List objects under a cloud storage URL"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("url", SyntaxShape::String, "the bucket/prefix URL to list, e.g. s3://bucket/prefix")
+            .input_output_type(Type::Nothing, Type::table())
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        plugin: &StoragePlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let url: String = call.req(0)?;
+        let head = call.head;
+        let url = parse_url(&url, head)?;
+        let (store, prefix) = build_store(&url, head)?;
+
+        let rows = plugin.runtime.block_on(async move {
+            let mut stream = store.list(Some(&prefix));
+            let mut rows = Vec::new();
+            while let Some(meta) = stream.next().await {
+                let meta = meta.map_err(|e| ShellError::GenericError {
+                    error: "Could not list objects".into(),
+                    msg: e.to_string(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                })?;
+                let mut record = Record::new();
+                record.push("path", Value::string(meta.location.to_string(), head));
+                record.push("size", Value::filesize(meta.size as i64, head));
+                record.push(
+                    "last_modified",
+                    Value::date(meta.last_modified.into(), head),
+                );
+                rows.push(Value::record(record, head));
+            }
+            Ok::<_, ShellError>(rows)
+        })?;
+
+        Ok(Value::list(rows, head))
+    }
+}
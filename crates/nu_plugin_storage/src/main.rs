@@ -0,0 +1,12 @@
+use nu_plugin::{serve_plugin, JsonSerializer};
+use nu_plugin_storage::StoragePlugin;
+
+fn main() {
+    match StoragePlugin::new() {
+        Ok(ref plugin) => serve_plugin(plugin, JsonSerializer {}),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use crate::store::{build_store, parse_url};
+use crate::StoragePlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, ShellError, Signature, SyntaxShape, Type, Value};
+use object_store::PutPayload;
+
+pub struct StoragePut;
+
+impl SimplePluginCommand for StoragePut {
+    type Plugin = StoragePlugin;
+
+    fn name(&self) -> &str {
+        "storage put"
+    }
+
+    fn description(&self) -> &str {
+        "Upload piped-in data to an object in cloud storage"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("url", SyntaxShape::String, "the object URL to upload to, e.g. s3://bucket/key")
+            .input_output_type(Type::Binary, Type::Nothing)
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        plugin: &StoragePlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let url: String = call.req(0)?;
+        let head = call.head;
+        let url = parse_url(&url, head)?;
+        let (store, path) = build_store(&url, head)?;
+
+        let bytes = match input {
+            Value::Binary { val, .. } => val.clone(),
+            Value::String { val, .. } => val.clone().into_bytes(),
+            _ => {
+                return Err(ShellError::GenericError {
+                    error: "Unsupported input type".into(),
+                    msg: "expected binary or string input".into(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                }
+                .into())
+            }
+        };
+
+        plugin.runtime.block_on(async move {
+            store
+                .put(&path, PutPayload::from(bytes))
+                .await
+                .map_err(|e| ShellError::GenericError {
+                    error: "Could not upload object".into(),
+                    msg: e.to_string(),
+                    span: Some(head),
+                    help: None,
+                    inner: vec![],
+                })
+        })?;
+
+        Ok(Value::nothing(head))
+    }
+}
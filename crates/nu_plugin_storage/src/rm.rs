@@ -0,0 +1,50 @@
+use crate::store::{build_store, parse_url};
+use crate::StoragePlugin;
+use nu_plugin::{EngineInterface, EvaluatedCall, SimplePluginCommand};
+use nu_protocol::{Category, LabeledError, ShellError, Signature, SyntaxShape, Type, Value};
+
+pub struct StorageRm;
+
+impl SimplePluginCommand for StorageRm {
+    type Plugin = StoragePlugin;
+
+    fn name(&self) -> &str {
+        "storage rm"
+    }
+
+    fn description(&self) -> &str {
+        "Delete an object from cloud storage"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build(nu_plugin::PluginCommand::name(self))
+            .required("url", SyntaxShape::String, "the object URL to delete, e.g. s3://bucket/key")
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .category(Category::Network)
+    }
+
+    fn run(
+        &self,
+        plugin: &StoragePlugin,
+        _engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let url: String = call.req(0)?;
+        let head = call.head;
+        let url = parse_url(&url, head)?;
+        let (store, path) = build_store(&url, head)?;
+
+        plugin.runtime.block_on(async move {
+            store.delete(&path).await.map_err(|e| ShellError::GenericError {
+                error: "Could not delete object".into(),
+                msg: e.to_string(),
+                span: Some(head),
+                help: None,
+                inner: vec![],
+            })
+        })?;
+
+        Ok(Value::nothing(head))
+    }
+}
@@ -0,0 +1,48 @@
+use nu_protocol::{ShellError, Span};
+use object_store::{aws::AmazonS3Builder, path::Path as ObjectPath, ObjectStore};
+use std::sync::Arc;
+use url::Url;
+
+/// Builds an [`ObjectStore`] and the object path within it for a `scheme://bucket/key` URL.
+///
+/// Only `s3`/`s3a` URLs are currently supported - GCS and Azure are not yet implemented.
+/// Credentials are read from the standard `AWS_*` environment variables (see
+/// [`AmazonS3Builder::from_env`]), not the full AWS SDK credential chain (instance profiles,
+/// SSO, etc.), which is a deliberate scope reduction for this first pass.
+pub(crate) fn build_store(url: &Url, span: Span) -> Result<(Arc<dyn ObjectStore>, ObjectPath), ShellError> {
+    match url.scheme() {
+        "s3" | "s3a" => {
+            let store = AmazonS3Builder::from_env()
+                .with_url(url.as_str())
+                .build()
+                .map_err(|e| ShellError::GenericError {
+                    error: "Could not build S3 client".into(),
+                    msg: e.to_string(),
+                    span: Some(span),
+                    help: None,
+                    inner: vec![],
+                })?;
+            let path = ObjectPath::from(url.path().trim_start_matches('/'));
+            Ok((Arc::new(store), path))
+        }
+        other => Err(ShellError::GenericError {
+            error: "Unsupported storage scheme".into(),
+            msg: format!(
+                "'{other}' is not supported; only 's3'/'s3a' URLs are currently supported (gcs/azure are not yet implemented)"
+            ),
+            span: Some(span),
+            help: None,
+            inner: vec![],
+        }),
+    }
+}
+
+pub(crate) fn parse_url(raw: &str, span: Span) -> Result<Url, ShellError> {
+    Url::parse(raw).map_err(|e| ShellError::GenericError {
+        error: "Invalid storage URL".into(),
+        msg: e.to_string(),
+        span: Some(span),
+        help: Some("expected a URL like s3://bucket/key".into()),
+        inner: vec![],
+    })
+}
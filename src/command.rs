@@ -30,9 +30,8 @@ pub(crate) fn gather_commandline_args() -> (Vec<String>, String, Vec<String>) {
 
         let flag_value = match arg.as_ref() {
             "--commands" | "-c" | "--table-mode" | "-m" | "--error-style" | "-e" | "--execute"
-            | "--config" | "--env-config" | "-I" | "ide-ast" => {
-                args.next().map(|a| escape_quote_string(&a))
-            }
+            | "--config" | "--env-config" | "--record" | "--bundle" | "--output" | "-I"
+            | "ide-ast" => args.next().map(|a| escape_quote_string(&a)),
             #[cfg(feature = "plugin")]
             "--plugin-config" => args.next().map(|a| escape_quote_string(&a)),
             "--log-level" | "--log-target" | "--log-include" | "--log-exclude" | "--testbin"
@@ -118,6 +117,10 @@ pub(crate) fn parse_commandline_args(
                 call.get_flag(engine_state, &mut stack, "ide-complete")?;
             let ide_check: Option<Value> = call.get_flag(engine_state, &mut stack, "ide-check")?;
             let ide_ast: Option<Spanned<String>> = call.get_named_arg("ide-ast");
+            let profile_startup = call.has_flag(engine_state, &mut stack, "profile-startup")?;
+            let record_session = call.get_flag_expr("record");
+            let bundle = call.get_flag_expr("bundle");
+            let bundle_output = call.get_flag_expr("output");
 
             fn extract_contents(
                 expression: Option<&Expression>,
@@ -195,6 +198,9 @@ pub(crate) fn parse_commandline_args(
             let plugins = extract_list(plugins, "path", |expr| expr.as_filepath().map(|t| t.0))?;
             let config_file = extract_path(config_file)?;
             let env_file = extract_path(env_file)?;
+            let record_session = extract_path(record_session)?;
+            let bundle = extract_path(bundle)?;
+            let bundle_output = extract_path(bundle_output)?;
             let log_level = extract_contents(log_level)?;
             let log_target = extract_contents(log_target)?;
             let log_include = extract_list(log_include, "string", |expr| expr.as_string())?;
@@ -251,6 +257,10 @@ pub(crate) fn parse_commandline_args(
                 table_mode,
                 error_style,
                 no_newline,
+                profile_startup,
+                record_session,
+                bundle,
+                bundle_output,
             });
         }
     }
@@ -292,6 +302,10 @@ pub(crate) struct NushellCliArgs {
     pub(crate) ide_complete: Option<Value>,
     pub(crate) ide_check: Option<Value>,
     pub(crate) ide_ast: Option<Spanned<String>>,
+    pub(crate) profile_startup: bool,
+    pub(crate) record_session: Option<Spanned<String>>,
+    pub(crate) bundle: Option<Spanned<String>>,
+    pub(crate) bundle_output: Option<Spanned<String>>,
 }
 
 #[derive(Clone)]
@@ -441,6 +455,29 @@ impl Command for Nu {
                 "set the Rust module prefixes to exclude from the log output",
                 None,
             )
+            .switch(
+                "profile-startup",
+                "print a table breaking down how long each startup phase took",
+                None,
+            )
+            .named(
+                "record",
+                SyntaxShape::Filepath,
+                "record a JSON-lines transcript of each REPL command and its timing to the given file, for `session replay`",
+                None,
+            )
+            .named(
+                "bundle",
+                SyntaxShape::Filepath,
+                "bundle a script and its `source`/`use` file dependencies into a single self-contained file, then exit",
+                None,
+            )
+            .named(
+                "output",
+                SyntaxShape::Filepath,
+                "the file to write the bundled script to (used with --bundle)",
+                None,
+            )
             .switch(
                 "stdin",
                 "redirect standard input to a command (with `-c`) or a script file",
@@ -17,7 +17,7 @@ use crate::{
 use command::gather_commandline_args;
 use log::{trace, Level};
 use miette::Result;
-use nu_cli::gather_parent_env_vars;
+use nu_cli::{bundle_script, gather_parent_env_vars};
 use nu_engine::{convert_env_values, exit::cleanup_exit};
 use nu_lsp::LanguageServer;
 use nu_path::canonicalize_with;
@@ -31,6 +31,28 @@ use run::{run_commands, run_file, run_repl};
 use signals::ctrlc_protection;
 use std::{path::PathBuf, str::FromStr, sync::Arc};
 
+/// Prints the startup phases recorded via the `perf!` macro when `--profile-startup` was passed.
+///
+/// A no-op when profiling wasn't turned on, so this is safe to call unconditionally right before
+/// exiting.
+fn print_startup_profile(enabled: bool) {
+    if !enabled {
+        return;
+    }
+
+    let entries = nu_utils::startup_profile::entries();
+    let name_width = entries
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+
+    eprintln!("startup profile:");
+    for (name, duration) in &entries {
+        eprintln!("  {name:name_width$}  {duration:?}");
+    }
+}
+
 /// Get the directory where the Nushell executable is located.
 fn current_exe_directory() -> PathBuf {
     let mut path = std::env::current_exe().expect("current_exe() should succeed");
@@ -202,6 +224,11 @@ fn main() -> Result<()> {
 
     engine_state.history_enabled = parsed_nu_cli_args.no_history.is_none();
 
+    let profile_startup = parsed_nu_cli_args.profile_startup;
+    if profile_startup {
+        nu_utils::startup_profile::enable();
+    }
+
     let use_color = engine_state
         .get_config()
         .use_ansi_coloring
@@ -466,6 +493,27 @@ fn main() -> Result<()> {
         }
 
         LanguageServer::initialize_stdio_connection(engine_state)?.serve_requests()?
+    } else if let Some(bundle) = parsed_nu_cli_args.bundle.clone() {
+        perf!("bundle starting", start_time, use_color);
+
+        let entry_path = std::path::Path::new(&bundle.item);
+        match bundle_script(entry_path) {
+            Ok(bundled) => match parsed_nu_cli_args.bundle_output.clone() {
+                Some(output) => {
+                    if let Err(err) = std::fs::write(&output.item, bundled) {
+                        eprintln!("Error writing bundle to {}: {err}", output.item);
+                        std::process::exit(1);
+                    }
+                }
+                None => print!("{bundled}"),
+            },
+            Err(err) => {
+                eprintln!("Error bundling {}: {err}", bundle.item);
+                std::process::exit(1);
+            }
+        }
+
+        cleanup_exit(0, &engine_state, 0);
     } else if let Some(commands) = parsed_nu_cli_args.commands.clone() {
         run_commands(
             &mut engine_state,
@@ -477,6 +525,7 @@ fn main() -> Result<()> {
             entire_start_time,
         );
 
+        print_startup_profile(profile_startup);
         cleanup_exit(0, &engine_state, 0);
     } else if !script_name.is_empty() {
         run_file(
@@ -489,6 +538,7 @@ fn main() -> Result<()> {
             input,
         );
 
+        print_startup_profile(profile_startup);
         cleanup_exit(0, &engine_state, 0);
     } else {
         // Environment variables that apply only when in REPL
@@ -527,6 +577,7 @@ fn main() -> Result<()> {
             entire_start_time,
         )?;
 
+        print_startup_profile(profile_startup);
         cleanup_exit(0, &engine_state, 0);
     }
 
@@ -209,6 +209,7 @@ pub(crate) fn run_repl(
         parsed_nu_cli_args.execute,
         parsed_nu_cli_args.no_std_lib,
         entire_start_time,
+        parsed_nu_cli_args.record_session,
     );
     perf!("evaluate_repl", start_time, use_color);
 
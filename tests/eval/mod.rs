@@ -459,6 +459,53 @@ fn row_condition() {
     )
 }
 
+#[test]
+fn fold_int_arithmetic() {
+    test_eval("1 + 2 * 3", Eq("7"))
+}
+
+#[test]
+fn fold_nested_literal_arithmetic() {
+    test_eval("(1 + 2) * (10 - 4)", Eq("18"))
+}
+
+#[test]
+fn fold_float_arithmetic() {
+    test_eval("1.5 + 2.5", Eq("4"))
+}
+
+#[test]
+fn fold_string_concat() {
+    test_eval("'foo' + 'bar'", Eq("foobar"))
+}
+
+#[test]
+fn fold_comparison() {
+    test_eval("1 + 1 == 2", Eq("true"))
+}
+
+#[test]
+fn fold_unary_not() {
+    test_eval("not (1 == 1)", Eq("false"))
+}
+
+#[test]
+fn fold_does_not_change_overflow_error() {
+    test_eval("9223372036854775807 + 1", Error("overflow"))
+}
+
+#[test]
+fn fold_does_not_change_divide_by_zero_error() {
+    test_eval("1 / 0", Error("[Dd]ivi.*zero"))
+}
+
+#[test]
+fn dead_literal_pipeline_is_still_evaluated_for_errors() {
+    // The middle pipeline element is a foldable literal whose value is discarded, but it must
+    // still run so that an error in it (rather than its folded replacement) is reported.
+    test_eval("print 'first'; 1 / 0; print 'last'", Error("[Dd]ivi.*zero"))
+}
+
 #[test]
 fn custom_command() {
     test_eval(
@@ -396,9 +396,49 @@ fn default_value_constant3() -> TestResult {
 
 #[test]
 fn default_value_not_constant2() -> TestResult {
-    fail_test(
-        r#"def foo [x = (loop { break })] { $x }; foo"#,
-        "expected a constant",
+    // `loop { break }` isn't a compile-time constant, but default values are allowed to be
+    // arbitrary expressions now, evaluated fresh in the caller's scope whenever the parameter
+    // is omitted.
+    run_test(r#"def foo [x = (loop { break })] { $x == null }; foo"#, "true")
+}
+
+#[test]
+fn default_value_computed_captures_outer_scope() -> TestResult {
+    run_test(
+        r#"let greeting = "hi"; def foo [x = ($greeting + "!")] { $x }; foo"#,
+        "hi!",
+    )
+}
+
+#[test]
+fn default_value_computed_evaluated_per_call() -> TestResult {
+    run_test(
+        r#"def now_str [] { date now | format date "%s%9f" }; def foo [x = (now_str)] { $x }; (foo) != (foo)"#,
+        "true",
+    )
+}
+
+#[test]
+fn flag_alias() -> TestResult {
+    run_test(
+        r#"def foo [--include(-i, --inc): string] { $include }; foo --inc bar"#,
+        "bar",
+    )
+}
+
+#[test]
+fn flag_multiple_collects_into_list() -> TestResult {
+    run_test(
+        r#"def foo [--include...: string] { $include | str join ',' }; foo --include a --include b"#,
+        "a,b",
+    )
+}
+
+#[test]
+fn flag_multiple_defaults_to_empty_list() -> TestResult {
+    run_test(
+        r#"def foo [--include...: string] { $include | length }; foo"#,
+        "0",
     )
 }
 